@@ -5,7 +5,7 @@
 
 use rust_tetris::board::{Board, Cell};
 use rust_tetris::tetromino::{Tetromino, TetrominoType};
-use rust_tetris::rotation::{SRSRotationSystem, RotationSystem, RotationResult};
+use rust_tetris::rotation::{SRSRotationSystem, RotationState, RotationSystem, RotationResult};
 use macroquad::prelude::Color;
 
 fn main() {
@@ -58,14 +58,14 @@ fn test_wall_kicks(srs: &SRSRotationSystem) {
     
     // Create a confined space with walls
     for y in 15..24 {
-        board.set_cell(0, y, Cell::Filled(Color::new(0.5, 0.5, 0.5, 1.0)));
-        board.set_cell(9, y, Cell::Filled(Color::new(0.5, 0.5, 0.5, 1.0)));
+        board.set_cell(0, y, Cell::block(Color::new(0.5, 0.5, 0.5, 1.0)));
+        board.set_cell(9, y, Cell::block(Color::new(0.5, 0.5, 0.5, 1.0)));
     }
     
     // Test I-piece against left wall
     let mut piece = Tetromino::new(TetrominoType::I);
     piece.position = (2, 18);
-    piece.rotation = 1; // Vertical
+    piece.rotation = RotationState::Right; // Vertical
     
     println!("Testing I-piece rotation from vertical to horizontal near left wall...");
     
@@ -95,9 +95,9 @@ fn test_t_spin_detection(srs: &SRSRotationSystem) {
     let t_y = 20;
     
     // Fill 3 corners around the T-piece
-    board.set_cell(t_x - 1, t_y - 1, Cell::Filled(Color::new(0.5, 0.5, 0.5, 1.0))); // Top-left
-    board.set_cell(t_x + 1, t_y - 1, Cell::Filled(Color::new(0.5, 0.5, 0.5, 1.0))); // Top-right  
-    board.set_cell(t_x - 1, t_y + 1, Cell::Filled(Color::new(0.5, 0.5, 0.5, 1.0))); // Bottom-left
+    board.set_cell(t_x - 1, t_y - 1, Cell::block(Color::new(0.5, 0.5, 0.5, 1.0))); // Top-left
+    board.set_cell(t_x + 1, t_y - 1, Cell::block(Color::new(0.5, 0.5, 0.5, 1.0))); // Top-right  
+    board.set_cell(t_x - 1, t_y + 1, Cell::block(Color::new(0.5, 0.5, 0.5, 1.0))); // Bottom-left
     // Bottom-right corner left open
     
     let mut t_piece = Tetromino::new(TetrominoType::T);
@@ -0,0 +1,53 @@
+//! Play in a terminal, without a window or GPU
+//!
+//! This is a turn-based demo over plain line-buffered stdin, not a real-time game: nothing
+//! moves until you press ENTER. Type one or more keys and press ENTER; each line of input is
+//! one tick of gravity.
+//! `a`/`d` move, `s` soft drops, `w`/`z` rotate, `c` holds, `x` hard drops, `q` quits.
+//!
+//! Run with: cargo run --example terminal_tetris
+
+use rust_tetris::board::renderer::render_game_to_writer;
+use rust_tetris::game::GameState;
+use rust_tetris::Game;
+use std::io::{self, BufRead, Write};
+
+fn main() -> io::Result<()> {
+    let mut game = Game::new();
+    game.state = GameState::Playing;
+    
+    let stdin = io::stdin();
+    let mut stdout = io::stdout();
+    render_game_to_writer(&game, &mut stdout)?;
+    
+    for line in stdin.lock().lines() {
+        for key in line?.chars() {
+            match key {
+                'a' => { game.move_piece(-1, 0); },
+                'd' => { game.move_piece(1, 0); },
+                's' => { game.move_piece(0, 1); },
+                'w' => { game.rotate_piece_clockwise(); },
+                'z' => { game.rotate_piece_counterclockwise(); },
+                'c' => { game.hold_piece(); },
+                'x' => game.hard_drop(),
+                'q' => return Ok(()),
+                _ => {},
+            }
+        }
+        
+        // Run out any line clear animation, then let gravity take one step
+        while game.is_clearing_lines() {
+            game.update(1.0 / 60.0);
+        }
+        game.update(game.drop_interval);
+        
+        render_game_to_writer(&game, &mut stdout)?;
+        stdout.flush()?;
+        
+        if game.state.has_ended() {
+            println!("GAME OVER");
+            break;
+        }
+    }
+    Ok(())
+}
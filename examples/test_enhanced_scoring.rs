@@ -28,7 +28,7 @@ fn test_basic_line_clearing() {
     
     // Fill the bottom row except one cell to set up a single line clear
     for x in 1..10 {
-        game.board.set_cell(x, 23, Cell::Filled(macroquad::prelude::Color::new(0.5, 0.5, 0.5, 1.0)));
+        game.board.set_cell(x, 23, Cell::block(macroquad::prelude::Color::new(0.5, 0.5, 0.5, 1.0)));
     }
     
     let initial_score = game.score;
@@ -91,9 +91,9 @@ fn test_t_spin_detection() {
     let center_y = game.current_piece.as_ref().unwrap().position.1;
     
     // Fill 3 corners around the T-piece to simulate T-spin condition
-    game.board.set_cell(center_x - 1, center_y - 1, Cell::Filled(macroquad::prelude::Color::new(0.5, 0.5, 0.5, 1.0)));
-    game.board.set_cell(center_x + 1, center_y - 1, Cell::Filled(macroquad::prelude::Color::new(0.5, 0.5, 0.5, 1.0)));
-    game.board.set_cell(center_x - 1, center_y + 1, Cell::Filled(macroquad::prelude::Color::new(0.5, 0.5, 0.5, 1.0)));
+    game.board.set_cell(center_x - 1, center_y - 1, Cell::block(macroquad::prelude::Color::new(0.5, 0.5, 0.5, 1.0)));
+    game.board.set_cell(center_x + 1, center_y - 1, Cell::block(macroquad::prelude::Color::new(0.5, 0.5, 0.5, 1.0)));
+    game.board.set_cell(center_x - 1, center_y + 1, Cell::block(macroquad::prelude::Color::new(0.5, 0.5, 0.5, 1.0)));
     
     let is_t_spin = game.is_t_spin();
     println!("T-spin detection result: {}", is_t_spin);
@@ -6,6 +6,7 @@
 use rust_tetris::Game;
 use rust_tetris::tetromino::{Tetromino, TetrominoType};
 use rust_tetris::board::Cell;
+use rust_tetris::rotation::RotationState;
 use macroquad::prelude::Color;
 
 const GRAY: Color = Color { r: 0.5, g: 0.5, b: 0.5, a: 1.0 };
@@ -20,16 +21,16 @@ fn main() {
     // Place an I-piece manually in a tight space to test wall kicks
     let mut test_piece = Tetromino::new(TetrominoType::I);
     test_piece.position = (1, 20); // Near left wall
-    test_piece.rotation = 0; // Horizontal
+    test_piece.rotation = RotationState::Spawn; // Horizontal
     
     // Set up the piece
     game.current_piece = Some(test_piece);
     
     // Add some walls to make rotation tight
-    game.board.set_cell(0, 19, Cell::Filled(GRAY));
-    game.board.set_cell(0, 20, Cell::Filled(GRAY));  
-    game.board.set_cell(0, 21, Cell::Filled(GRAY));
-    game.board.set_cell(0, 22, Cell::Filled(GRAY));
+    game.board.set_cell(0, 19, Cell::block(GRAY));
+    game.board.set_cell(0, 20, Cell::block(GRAY));  
+    game.board.set_cell(0, 21, Cell::block(GRAY));
+    game.board.set_cell(0, 22, Cell::block(GRAY));
     
     println!("Setup: I-piece at position (1, 20) with left wall");
     println!("Before rotation: piece rotation = {}", game.current_piece.as_ref().unwrap().rotation);
@@ -64,9 +65,9 @@ fn main() {
     game.current_piece = Some(t_piece);
     
     // Create T-spin setup
-    game.board.set_cell(4, 17, Cell::Filled(GRAY)); // Top-left
-    game.board.set_cell(6, 17, Cell::Filled(GRAY)); // Top-right
-    game.board.set_cell(4, 19, Cell::Filled(GRAY)); // Bottom-left
+    game.board.set_cell(4, 17, Cell::block(GRAY)); // Top-left
+    game.board.set_cell(6, 17, Cell::block(GRAY)); // Top-right
+    game.board.set_cell(4, 19, Cell::block(GRAY)); // Bottom-left
     
     println!("Setup: T-piece in potential T-spin position");
     
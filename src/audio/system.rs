@@ -1,8 +1,17 @@
 //! Audio system for managing game sounds
 
 use macroquad::audio::{Sound, load_sound, play_sound, PlaySoundParams, stop_sound, set_sound_volume};
+use crate::game::GameEvent;
+use crate::scoring::LineClearType;
 use std::collections::HashMap;
 
+/// Music level, relative to the music volume setting, while paused or away from a game
+pub const MUSIC_DUCKED_LEVEL: f32 = 0.3;
+/// Seconds music takes to fade between levels on a state change
+pub const MUSIC_FADE_TIME: f32 = 0.8;
+/// Seconds music takes to fade out before it stops for a pause
+pub const MUSIC_PAUSE_FADE_TIME: f32 = 0.25;
+
 /// Types of sounds in the game
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SoundType {
@@ -16,6 +25,10 @@ pub enum SoundType {
     HoldPiece,
     /// Line clearing effect
     LineClear,
+    /// Four-line clear (TETRIS) impact
+    TetrisClear,
+    /// Line clear that empties the whole board
+    PerfectClear,
     /// Level completion
     LevelComplete,
     /// Pause/unpause
@@ -24,10 +37,56 @@ pub enum SoundType {
     GameOver,
     /// Power-up/special action (for future power-up system)
     PowerAction,
+    /// Consecutive line clear (combo continues)
+    Combo,
+    /// Combo ended by a placement without a line clear
+    ComboBreak,
+    /// Looping low tone while the stack is near the top
+    Danger,
+    /// Beep for each second of the start countdown
+    Countdown,
+    /// Countdown finished and play begins
+    CountdownGo,
     /// Background music
     BackgroundMusic,
 }
 
+impl SoundType {
+    /// Every sound, in the order the sound test lists them
+    pub fn all() -> [SoundType; 17] {
+        [
+            SoundType::UiClick, SoundType::PieceSnap, SoundType::HardDrop, SoundType::HoldPiece,
+            SoundType::LineClear, SoundType::TetrisClear, SoundType::PerfectClear, SoundType::LevelComplete,
+            SoundType::Pause, SoundType::GameOver, SoundType::PowerAction, SoundType::Combo,
+            SoundType::ComboBreak, SoundType::Danger, SoundType::Countdown, SoundType::CountdownGo,
+            SoundType::BackgroundMusic,
+        ]
+    }
+    
+    /// Display name for menus
+    pub fn name(self) -> &'static str {
+        match self {
+            SoundType::UiClick => "UI CLICK",
+            SoundType::PieceSnap => "PIECE SNAP",
+            SoundType::HardDrop => "HARD DROP",
+            SoundType::HoldPiece => "HOLD PIECE",
+            SoundType::LineClear => "LINE CLEAR",
+            SoundType::TetrisClear => "TETRIS CLEAR",
+            SoundType::PerfectClear => "PERFECT CLEAR",
+            SoundType::LevelComplete => "LEVEL COMPLETE",
+            SoundType::Pause => "PAUSE",
+            SoundType::GameOver => "GAME OVER",
+            SoundType::PowerAction => "GHOST BLOCK",
+            SoundType::Combo => "COMBO",
+            SoundType::ComboBreak => "COMBO BREAK",
+            SoundType::Danger => "DANGER",
+            SoundType::Countdown => "COUNTDOWN",
+            SoundType::CountdownGo => "COUNTDOWN GO",
+            SoundType::BackgroundMusic => "BACKGROUND MUSIC",
+        }
+    }
+}
+
 /// Audio system managing all game sounds
 #[derive(Debug)]
 pub struct AudioSystem {
@@ -43,6 +102,16 @@ pub struct AudioSystem {
     audio_enabled: bool,
     /// Whether background music is currently playing
     background_music_playing: bool,
+    /// Whether the danger warning loop is currently playing
+    danger_warning_playing: bool,
+    /// Fade applied on top of the music volume (0.0 to 1.0)
+    music_fade_level: f32,
+    /// Level the fade is heading towards
+    music_fade_target: f32,
+    /// How fast the fade moves, in level per second
+    music_fade_rate: f32,
+    /// Whether music is paused, fading out or stopped until `resume_music`
+    music_paused: bool,
 }
 
 impl AudioSystem {
@@ -55,6 +124,11 @@ impl AudioSystem {
             music_volume: 0.5,
             audio_enabled: true,
             background_music_playing: false,
+            danger_warning_playing: false,
+            music_fade_level: 1.0,
+            music_fade_target: 1.0,
+            music_fade_rate: 0.0,
+            music_paused: false,
         }
     }
     
@@ -69,10 +143,17 @@ impl AudioSystem {
             (SoundType::HardDrop, "assets/sounds/hard-drop.wav"),
             (SoundType::HoldPiece, "assets/sounds/hold-piece.wav"),
             (SoundType::LineClear, "assets/sounds/line-clear.wav"),
+            (SoundType::TetrisClear, "assets/sounds/tetris-clear.wav"),
+            (SoundType::PerfectClear, "assets/sounds/perfect-clear.wav"),
             (SoundType::LevelComplete, "assets/sounds/level-complete.wav"),
             (SoundType::Pause, "assets/sounds/pause.wav"),
             (SoundType::GameOver, "assets/sounds/game-over.wav"),
             (SoundType::PowerAction, "assets/sounds/place-ghost-block.wav"),
+            (SoundType::Combo, "assets/sounds/combo.wav"),
+            (SoundType::ComboBreak, "assets/sounds/combo-break.wav"),
+            (SoundType::Danger, "assets/sounds/danger.wav"),
+            (SoundType::Countdown, "assets/sounds/countdown.wav"),
+            (SoundType::CountdownGo, "assets/sounds/countdown-go.wav"),
             (SoundType::BackgroundMusic, "assets/sounds/tetris-background-music.wav"),
         ];
         
@@ -101,7 +182,7 @@ impl AudioSystem {
         
         if let Some(sound) = self.sounds.get(&sound_type) {
             let volume = match sound_type {
-                SoundType::BackgroundMusic => self.master_volume * self.music_volume,
+                SoundType::BackgroundMusic => self.effective_music_volume(),
                 _ => self.master_volume * self.sfx_volume,
             };
             
@@ -125,7 +206,7 @@ impl AudioSystem {
         
         if let Some(sound) = self.sounds.get(&sound_type) {
             let base_volume = match sound_type {
-                SoundType::BackgroundMusic => self.master_volume * self.music_volume,
+                SoundType::BackgroundMusic => self.effective_music_volume(),
                 _ => self.master_volume * self.sfx_volume,
             };
             
@@ -182,8 +263,9 @@ impl AudioSystem {
                 // When enabled, start background music at current volume
                 self.start_background_music();
             } else {
-                // When disabled, stop background music
+                // When disabled, stop background music and any warning loop
                 self.stop_background_music();
+                self.set_danger_warning(false);
             }
         }
     }
@@ -208,6 +290,57 @@ impl AudioSystem {
         self.audio_enabled
     }
     
+    /// Current music fade level (0.0 to 1.0), applied on top of the music volume
+    pub fn music_fade_level(&self) -> f32 {
+        self.music_fade_level
+    }
+    
+    /// Volume background music actually plays at, after master volume and any fade
+    pub fn effective_music_volume(&self) -> f32 {
+        self.master_volume * self.music_volume * self.music_fade_level
+    }
+    
+    /// Ramp the music fade level to `target_volume` (0.0 to 1.0) over `duration` seconds
+    ///
+    /// Fading towards the level already being approached keeps the fade going rather than
+    /// restarting it, so this can be called every frame. A zero duration jumps straight there.
+    pub fn fade_music_to(&mut self, target_volume: f32, duration: f32) {
+        let target = target_volume.clamp(0.0, 1.0);
+        if target == self.music_fade_target {
+            return;
+        }
+        
+        self.music_fade_target = target;
+        if duration <= 0.0 {
+            self.music_fade_level = target;
+            self.music_fade_rate = 0.0;
+            self.update_background_music_volume();
+        } else {
+            self.music_fade_rate = (target - self.music_fade_level).abs() / duration;
+        }
+        log::debug!("Fading music to {:.2} over {:.2}s", target, duration);
+    }
+    
+    /// Step any music fade in progress by `delta_time` seconds
+    pub fn update(&mut self, delta_time: f32) {
+        if self.music_fade_level == self.music_fade_target {
+            // A pause fade that has run out stops the music (macroquad can't pause a sound)
+            if self.music_paused && self.music_fade_level == 0.0 {
+                self.stop_background_music();
+            }
+            return;
+        }
+        
+        let step = self.music_fade_rate * delta_time;
+        let remaining = self.music_fade_target - self.music_fade_level;
+        self.music_fade_level = if remaining.abs() <= step {
+            self.music_fade_target
+        } else {
+            self.music_fade_level + step.copysign(remaining)
+        };
+        self.update_background_music_volume();
+    }
+    
     /// Start background music
     pub fn start_background_music(&mut self) {
         if !self.background_music_playing {
@@ -228,16 +361,77 @@ impl AudioSystem {
         }
     }
     
+    /// Fade the music out and stop it until `resume_music`
+    ///
+    /// Returns false, changing nothing, if the music is already paused.
+    pub fn pause_music(&mut self) -> bool {
+        if self.music_paused {
+            return false;
+        }
+        log::info!("Pausing background music");
+        self.music_paused = true;
+        self.fade_music_to(0.0, MUSIC_PAUSE_FADE_TIME);
+        true
+    }
+    
+    /// Bring paused music back, restarting it if the pause fade had already stopped it
+    ///
+    /// Returns false, changing nothing, if the music isn't paused. Unpausing before the fade
+    /// finishes leaves the music playing, so quick pause/unpause never stacks restarts.
+    pub fn resume_music(&mut self) -> bool {
+        if !self.music_paused {
+            return false;
+        }
+        log::info!("Resuming background music");
+        self.music_paused = false;
+        if self.audio_enabled {
+            self.start_background_music();
+        }
+        true
+    }
+    
+    /// Check if music is paused
+    pub fn is_music_paused(&self) -> bool {
+        self.music_paused
+    }
+    
     /// Check if background music is playing
     pub fn is_background_music_playing(&self) -> bool {
         self.background_music_playing
     }
     
+    /// Start or stop the looping danger warning
+    pub fn set_danger_warning(&mut self, active: bool) {
+        let active = active && self.audio_enabled;
+        if active == self.danger_warning_playing {
+            return;
+        }
+        
+        if let Some(sound) = self.sounds.get(&SoundType::Danger) {
+            if active {
+                log::info!("Starting danger warning");
+                play_sound(sound, PlaySoundParams {
+                    looped: true,
+                    volume: self.master_volume * self.sfx_volume * 0.6,
+                });
+            } else {
+                log::info!("Stopping danger warning");
+                stop_sound(sound);
+            }
+        }
+        self.danger_warning_playing = active;
+    }
+    
+    /// Check if the danger warning loop is playing
+    pub fn is_danger_warning_playing(&self) -> bool {
+        self.danger_warning_playing
+    }
+    
     /// Update background music volume without restarting
     pub fn update_background_music_volume(&self) {
         if self.background_music_playing && self.audio_enabled {
             if let Some(sound) = self.sounds.get(&SoundType::BackgroundMusic) {
-                let volume = self.master_volume * self.music_volume;
+                let volume = self.effective_music_volume();
                 set_sound_volume(sound, volume);
                 log::debug!("Updated background music volume to {:.2}", volume);
             }
@@ -256,8 +450,141 @@ impl AudioSystem {
     }
 }
 
+/// Sound and volume multiplier to play for a line clear
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClearSound {
+    pub sound: SoundType,
+    pub volume: f32,
+}
+
+/// Map a line clear to its sound - bigger clears play louder, and a Tetris gets its own impact
+pub fn line_clear_sound(clear_type: LineClearType) -> ClearSound {
+    match clear_type.lines_cleared() {
+        1 => ClearSound { sound: SoundType::LineClear, volume: 0.55 },
+        2 => ClearSound { sound: SoundType::LineClear, volume: 0.75 },
+        3 => ClearSound { sound: SoundType::LineClear, volume: 0.9 },
+        _ => ClearSound { sound: SoundType::TetrisClear, volume: 1.0 },
+    }
+}
+
+/// Volume multiplier for the combo sound, rising with combo length
+pub fn combo_volume(combo: u32) -> f32 {
+    (0.5 + combo as f32 * 0.1).min(1.0)
+}
+
+/// Map a frame's game events to the sounds they play, with volume multipliers
+///
+/// Losing or finishing drowns out everything else from the same frame, and a lock that
+/// clears lines is heard as the clear rather than a snap. Each event plays at most one sound.
+pub fn event_sounds(events: &[GameEvent]) -> Vec<(SoundType, f32)> {
+    if events.iter().any(|event| matches!(event, GameEvent::GameOver { .. })) {
+        return vec![(SoundType::GameOver, 1.0)];
+    }
+    if events.contains(&GameEvent::Finished) {
+        return vec![(SoundType::LevelComplete, 1.0)];
+    }
+    
+    let clearing = events.iter().any(|event| matches!(event, GameEvent::LinesCleared { .. }));
+    events.iter().filter_map(|event| match *event {
+        GameEvent::CountdownTick => Some((SoundType::Countdown, 1.0)),
+        GameEvent::CountdownGo => Some((SoundType::CountdownGo, 1.0)),
+        GameEvent::LinesCleared { clear_type, .. } => {
+            let clear_sound = line_clear_sound(clear_type);
+            Some((clear_sound.sound, clear_sound.volume))
+        },
+        GameEvent::PerfectClear => Some((SoundType::PerfectClear, 1.0)),
+        GameEvent::PieceLocked if !clearing => Some((SoundType::PieceSnap, 0.8)),
+        GameEvent::LevelUp => Some((SoundType::LevelComplete, 1.0)),
+        GameEvent::ComboExtended { combo } => Some((SoundType::Combo, combo_volume(combo))),
+        GameEvent::ComboBroken => Some((SoundType::ComboBreak, 1.0)),
+        _ => None,
+    }).collect()
+}
+
 impl Default for AudioSystem {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_line_clear_sound_varies_by_size() {
+        let single = line_clear_sound(LineClearType::Single);
+        let tetris = line_clear_sound(LineClearType::Tetris);
+        assert_ne!(single, tetris);
+        assert_eq!(tetris.sound, SoundType::TetrisClear);
+        assert!(tetris.volume > single.volume);
+        
+        // T-spins sound like the plain clear of the same size
+        assert_eq!(line_clear_sound(LineClearType::TSpinDouble), line_clear_sound(LineClearType::Double));
+    }
+    
+    #[test]
+    fn test_music_fade_ramps_over_its_duration() {
+        let mut audio = AudioSystem::new();
+        assert_eq!(audio.music_fade_level(), 1.0);
+        
+        audio.fade_music_to(0.0, 1.0);
+        audio.update(0.5);
+        assert!((audio.music_fade_level() - 0.5).abs() < 1e-5, "{}", audio.music_fade_level());
+        assert!((audio.effective_music_volume() - audio.music_volume() * 0.5).abs() < 1e-5);
+        
+        // Asking again for the same target doesn't restart the fade
+        audio.fade_music_to(0.0, 1.0);
+        audio.update(0.75);
+        assert_eq!(audio.music_fade_level(), 0.0);
+        
+        // Fading back up stops at the target
+        audio.fade_music_to(1.0, 0.5);
+        audio.update(0.25);
+        assert!((audio.music_fade_level() - 0.5).abs() < 1e-5);
+        audio.update(1.0);
+        assert_eq!(audio.music_fade_level(), 1.0);
+    }
+    
+    #[test]
+    fn test_event_sounds_play_each_event_once() {
+        let tetris = line_clear_sound(LineClearType::Tetris);
+        let events = [
+            GameEvent::PieceLocked,
+            GameEvent::LinesCleared { count: 4, clear_type: LineClearType::Tetris },
+            GameEvent::LevelUp,
+        ];
+        assert_eq!(event_sounds(&events), vec![(tetris.sound, tetris.volume), (SoundType::LevelComplete, 1.0)]);
+        
+        // A lock on its own snaps
+        assert_eq!(event_sounds(&[GameEvent::PieceLocked]), vec![(SoundType::PieceSnap, 0.8)]);
+        
+        // Losing silences the rest of the frame
+        let reason = crate::game::GameOverReason::TopOut;
+        assert_eq!(event_sounds(&[GameEvent::PieceLocked, GameEvent::GameOver { reason }]), vec![(SoundType::GameOver, 1.0)]);
+    }
+    
+    #[test]
+    fn test_pause_and_resume_music() {
+        let mut audio = AudioSystem::new();
+        audio.background_music_playing = true;
+        
+        assert!(audio.pause_music());
+        assert!(audio.is_music_paused());
+        // Pausing again does nothing, so the fade isn't restarted
+        audio.update(MUSIC_PAUSE_FADE_TIME / 2.0);
+        assert!(!audio.pause_music());
+        assert!(audio.music_fade_level() > 0.0);
+        assert!(audio.is_background_music_playing());
+        
+        // Once faded out, the music stops
+        audio.update(MUSIC_PAUSE_FADE_TIME);
+        audio.update(0.0);
+        assert!(!audio.is_background_music_playing());
+        
+        assert!(audio.resume_music());
+        assert!(!audio.is_music_paused());
+        assert!(audio.is_background_music_playing());
+        assert!(!audio.resume_music());
+    }
+}
@@ -1,8 +1,11 @@
 //! Tetris game board data structure
 
 use crate::game::config::*;
+use crate::graphics::colors::GARBAGE_COLOR;
+use crate::tetromino::{Tetromino, TetrominoType};
 use macroquad::prelude::Color;
-use serde::{Serialize, Deserialize};
+use serde::{Serialize, Serializer, Deserialize};
+use serde::ser::SerializeStruct;
 
 // Custom serialization module for macroquad Color
 mod color_serde {
@@ -30,11 +33,23 @@ mod color_serde {
 pub enum Cell {
     /// Empty cell
     Empty,
-    /// Filled cell with a specific color
-    Filled(#[serde(with = "color_serde")] Color),
+    /// Filled cell with a specific color, and the type of the piece that filled it
+    ///
+    /// Garbage and ghost blocks don't come from a piece and carry no type.
+    Filled(#[serde(with = "color_serde")] Color, Option<TetrominoType>),
 }
 
 impl Cell {
+    /// Cell filled by a locked piece of the given type
+    pub fn piece(piece_type: TetrominoType) -> Self {
+        Cell::Filled(piece_type.color(), Some(piece_type))
+    }
+    
+    /// Cell filled with a plain colored block that isn't part of a piece
+    pub fn block(color: Color) -> Self {
+        Cell::Filled(color, None)
+    }
+    
     /// Check if the cell is empty
     pub fn is_empty(self) -> bool {
         matches!(self, Cell::Empty)
@@ -42,20 +57,33 @@ impl Cell {
     
     /// Check if the cell is filled
     pub fn is_filled(self) -> bool {
-        matches!(self, Cell::Filled(_))
+        matches!(self, Cell::Filled(..))
     }
     
     /// Get the color of the cell if it's filled
     pub fn color(self) -> Option<Color> {
         match self {
             Cell::Empty => None,
-            Cell::Filled(color) => Some(color),
+            Cell::Filled(color, _) => Some(color),
+        }
+    }
+    
+    /// Type of the piece that filled the cell
+    ///
+    /// Garbage and ghost blocks don't come from a piece and return `None`.
+    pub fn piece_type(self) -> Option<TetrominoType> {
+        match self {
+            Cell::Empty => None,
+            Cell::Filled(_, piece_type) => piece_type,
         }
     }
 }
 
 /// The main Tetris game board
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Serializes compactly as its filled cells only; see `SavedBoard`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(try_from = "SavedBoard")]
 pub struct Board {
     /// The game grid - includes buffer rows above visible area
     grid: [[Cell; BOARD_WIDTH]; BOARD_HEIGHT + BUFFER_HEIGHT],
@@ -63,6 +91,89 @@ pub struct Board {
     lines_cleared: u32,
     /// Current level
     level: u32,
+    /// Lines cleared per level
+    lines_per_level: u32,
+    /// Game time at which each cell was filled (used for fade effects)
+    lock_times: [[f64; BOARD_WIDTH]; BOARD_HEIGHT + BUFFER_HEIGHT],
+    /// Filled cells in each row, kept in step with `grid` so full rows are found without a scan
+    row_fill_count: Vec<u16>,
+    /// Height of each column's topmost block, kept in step with `grid` for cheap per-frame reads
+    column_heights: [u16; BOARD_WIDTH],
+}
+
+/// One filled cell in a save: column, row, color, the game time it was filled and the piece type
+#[derive(Serialize, Deserialize)]
+struct SavedCell(usize, usize, #[serde(with = "color_serde")] Color, f64, Option<TetrominoType>);
+
+/// The serialized board fields; row counts are rebuilt on load
+///
+/// Boards are written as a list of filled cells, which is far smaller than the mostly empty
+/// grid. Saves from before that still carry the full `grid` and `lock_times` and load as-is.
+#[derive(Deserialize)]
+struct SavedBoard {
+    #[serde(default)]
+    cells: Vec<SavedCell>,
+    #[serde(default)]
+    grid: Option<[[Cell; BOARD_WIDTH]; BOARD_HEIGHT + BUFFER_HEIGHT]>,
+    lines_cleared: u32,
+    level: u32,
+    #[serde(default = "default_lines_per_level")]
+    lines_per_level: u32,
+    #[serde(default)]
+    lock_times: [[f64; BOARD_WIDTH]; BOARD_HEIGHT + BUFFER_HEIGHT],
+}
+
+/// Classic cadence for saves and settings files made before lines per level was configurable
+pub(crate) fn default_lines_per_level() -> u32 {
+    LINES_PER_LEVEL
+}
+
+impl TryFrom<SavedBoard> for Board {
+    type Error = String;
+    
+    fn try_from(saved: SavedBoard) -> Result<Self, Self::Error> {
+        let mut board = Self {
+            grid: saved.grid.unwrap_or([[Cell::Empty; BOARD_WIDTH]; BOARD_HEIGHT + BUFFER_HEIGHT]),
+            lines_cleared: saved.lines_cleared,
+            level: saved.level,
+            lines_per_level: saved.lines_per_level.max(1),
+            lock_times: saved.lock_times,
+            row_fill_count: Vec::new(),
+            column_heights: [0; BOARD_WIDTH],
+        };
+        for SavedCell(x, y, color, lock_time, piece_type) in saved.cells {
+            if x >= BOARD_WIDTH || y >= BOARD_HEIGHT + BUFFER_HEIGHT {
+                return Err(format!("saved cell ({}, {}) is off the board", x, y));
+            }
+            board.grid[y][x] = Cell::Filled(color, piece_type);
+            board.lock_times[y][x] = lock_time;
+        }
+        board.row_fill_count = board.count_rows();
+        board.column_heights = board.measure_columns();
+        Ok(board)
+    }
+}
+
+impl Serialize for Board {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let cells: Vec<SavedCell> = self
+            .iter_filled_cells()
+            .filter_map(|(x, y, cell)| {
+                let (x, y) = (x as usize, y as usize);
+                cell.color().map(|color| SavedCell(x, y, color, self.lock_times[y][x], cell.piece_type()))
+            })
+            .collect();
+        
+        let mut state = serializer.serialize_struct("Board", 4)?;
+        state.serialize_field("cells", &cells)?;
+        state.serialize_field("lines_cleared", &self.lines_cleared)?;
+        state.serialize_field("level", &self.level)?;
+        state.serialize_field("lines_per_level", &self.lines_per_level)?;
+        state.end()
+    }
 }
 
 impl Board {
@@ -72,9 +183,52 @@ impl Board {
             grid: [[Cell::Empty; BOARD_WIDTH]; BOARD_HEIGHT + BUFFER_HEIGHT],
             lines_cleared: 0,
             level: 1,
+            lines_per_level: LINES_PER_LEVEL,
+            lock_times: [[0.0; BOARD_WIDTH]; BOARD_HEIGHT + BUFFER_HEIGHT],
+            row_fill_count: vec![0; BOARD_HEIGHT + BUFFER_HEIGHT],
+            column_heights: [0; BOARD_WIDTH],
         }
     }
     
+    /// Count the filled cells of every row from scratch
+    fn count_rows(&self) -> Vec<u16> {
+        self.grid.iter()
+            .map(|row| row.iter().filter(|cell| cell.is_filled()).count() as u16)
+            .collect()
+    }
+    
+    /// Measure the height of column `x` from scratch
+    fn measure_column(&self, x: usize) -> u16 {
+        let total_rows = BOARD_HEIGHT + BUFFER_HEIGHT;
+        (0..total_rows)
+            .find(|&y| self.grid[y][x].is_filled())
+            .map_or(0, |y| (total_rows - y) as u16)
+    }
+    
+    /// Measure every column's height from scratch
+    fn measure_columns(&self) -> [u16; BOARD_WIDTH] {
+        std::array::from_fn(|x| self.measure_column(x))
+    }
+    
+    /// Filled cells in row `y`, or 0 if out of bounds
+    pub fn row_fill_count(&self, y: usize) -> u16 {
+        self.row_fill_count.get(y).copied().unwrap_or(0)
+    }
+    
+    /// Every filled cell as (x, y, cell), top row first, skipping empty rows without scanning them
+    pub fn iter_filled_cells(&self) -> impl Iterator<Item = (i32, i32, Cell)> + '_ {
+        self.grid
+            .iter()
+            .enumerate()
+            .filter(|&(y, _)| self.row_fill_count[y] > 0)
+            .flat_map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|(_, cell)| cell.is_filled())
+                    .map(move |(x, &cell)| (x as i32, y as i32, cell))
+            })
+    }
+    
     /// Get the cell at the specified position
     /// Returns None if coordinates are out of bounds
     pub fn get_cell(&self, x: i32, y: i32) -> Option<Cell> {
@@ -106,7 +260,41 @@ impl Board {
             return false;
         }
         
+        match (self.grid[y][x].is_filled(), cell.is_filled()) {
+            (false, true) => self.row_fill_count[y] += 1,
+            (true, false) => self.row_fill_count[y] -= 1,
+            _ => {},
+        }
         self.grid[y][x] = cell;
+        // Only filled cells have a lock time worth keeping (or saving)
+        if cell.is_empty() {
+            self.lock_times[y][x] = 0.0;
+        }
+        
+        let height = ((BOARD_HEIGHT + BUFFER_HEIGHT) - y) as u16;
+        if cell.is_filled() {
+            self.column_heights[x] = self.column_heights[x].max(height);
+        } else if self.column_heights[x] == height {
+            // The top block went, so look for the next one down
+            self.column_heights[x] = self.measure_column(x);
+        }
+        true
+    }
+    
+    /// Get the game time at which a cell was filled
+    pub fn lock_time(&self, x: i32, y: i32) -> Option<f64> {
+        if x < 0 || y < 0 || x as usize >= BOARD_WIDTH || y as usize >= (BOARD_HEIGHT + BUFFER_HEIGHT) {
+            return None;
+        }
+        Some(self.lock_times[y as usize][x as usize])
+    }
+    
+    /// Record the game time at which a cell was filled
+    pub fn set_lock_time(&mut self, x: i32, y: i32, time: f64) -> bool {
+        if x < 0 || y < 0 || x as usize >= BOARD_WIDTH || y as usize >= (BOARD_HEIGHT + BUFFER_HEIGHT) {
+            return false;
+        }
+        self.lock_times[y as usize][x as usize] = time;
         true
     }
     
@@ -139,7 +327,7 @@ impl Board {
             return false;
         }
         
-        self.grid[y].iter().all(|cell| cell.is_filled())
+        self.row_fill_count[y] as usize == BOARD_WIDTH
     }
     
     /// Check if a line is completely empty
@@ -148,59 +336,190 @@ impl Board {
             return false;
         }
         
-        self.grid[y].iter().all(|cell| cell.is_empty())
+        self.row_fill_count[y] == 0
     }
     
     /// Find all complete lines that need to be cleared
     pub fn find_complete_lines(&self) -> Vec<usize> {
-        let mut complete_lines = Vec::new();
+        debug_assert_eq!(self.row_fill_count, self.count_rows(), "row fill counts out of step with the grid");
         
-        // Only check visible area and buffer
-        for y in 0..(BOARD_HEIGHT + BUFFER_HEIGHT) {
-            if self.is_line_full(y) {
-                complete_lines.push(y);
+        (0..(BOARD_HEIGHT + BUFFER_HEIGHT))
+            .filter(|&y| self.is_line_full(y))
+            .collect()
+    }
+    
+    /// Check the board is internally consistent and its stack settled
+    ///
+    /// Row fill counts and column heights must match the grid, empty cells must carry no lock time, and no
+    /// completely empty row may sit below a row with blocks in it (a gap line clears should
+    /// have closed). Boards built cell by cell in tests can fail the last check on purpose,
+    /// so this is only asserted around operations on boards that passed it beforehand.
+    pub fn invariants_hold(&self) -> bool {
+        let counts_match = self.row_fill_count == self.count_rows() && self.column_heights == self.measure_columns();
+        let empty_cells_unstamped = self.grid.iter().zip(self.lock_times.iter())
+            .all(|(row, times)| row.iter().zip(times.iter()).all(|(cell, &time)| cell.is_filled() || time == 0.0));
+        let first_filled_row = self.row_fill_count.iter().position(|&count| count > 0);
+        let settled = first_filled_row.is_none_or(|top| self.row_fill_count[top..].iter().all(|&count| count > 0));
+        counts_match && empty_cells_unstamped && settled
+    }
+    
+    /// Rows that would be complete if `cells` were filled, ascending, without touching the board
+    ///
+    /// Works from the per-row fill counts, so lookahead searches can test a placement without
+    /// cloning the board. Cells that are off the board, already filled or listed twice add nothing.
+    pub fn complete_lines_if_placed(&self, cells: &[(i32, i32)]) -> Vec<usize> {
+        let mut new_cells: Vec<(usize, usize)> = cells
+            .iter()
+            .filter(|&&(x, y)| self.get_cell(x, y).is_some_and(Cell::is_empty))
+            .map(|&(x, y)| (y as usize, x as usize))
+            .collect();
+        new_cells.sort_unstable();
+        new_cells.dedup();
+        
+        let mut complete = Vec::new();
+        for row in new_cells.chunk_by(|a, b| a.0 == b.0) {
+            let y = row[0].0;
+            if self.row_fill_count[y] as usize + row.len() == BOARD_WIDTH {
+                complete.push(y);
             }
         }
-        
-        complete_lines
+        complete
+    }
+    
+    /// Rows that are not yet complete but missing at most `n` cells, ascending
+    ///
+    /// Read straight from the per-row fill counts, so it is cheap enough to call every frame.
+    /// Empty rows never count, even when `n` covers the whole width.
+    pub fn rows_missing_at_most(&self, n: usize) -> Vec<usize> {
+        self.row_fill_count
+            .iter()
+            .enumerate()
+            .filter(|&(_, &filled)| filled > 0 && (filled as usize) < BOARD_WIDTH && BOARD_WIDTH - filled as usize <= n)
+            .map(|(y, _)| y)
+            .collect()
     }
     
     /// Clear the specified lines and drop rows above
-    pub fn clear_lines(&mut self, lines_to_clear: &[usize]) -> u32 {
-        if lines_to_clear.is_empty() {
-            return 0;
-        }
-        
-        let lines_cleared_count = lines_to_clear.len() as u32;
-        
+    ///
+    /// Returns how many rows were removed and their indices, ascending. Duplicate and
+    /// out-of-range indices are ignored, so the count always matches what actually changed.
+    pub fn clear_lines(&mut self, lines_to_clear: &[usize]) -> (u32, Vec<usize>) {
         // Sort lines in ascending order
-        let mut sorted_lines = lines_to_clear.to_vec();
+        let mut sorted_lines: Vec<usize> = lines_to_clear.iter().copied()
+            .filter(|&y| y < BOARD_HEIGHT + BUFFER_HEIGHT)
+            .collect();
         sorted_lines.sort();
+        sorted_lines.dedup();
         
-        // Create a new grid by copying non-cleared lines
-        let mut new_grid = [[Cell::Empty; BOARD_WIDTH]; BOARD_HEIGHT + BUFFER_HEIGHT];
-        let mut new_y = (BOARD_HEIGHT + BUFFER_HEIGHT) - 1; // Start from bottom
-        
-        // Copy lines from bottom to top, skipping cleared lines
-        for y in (0..(BOARD_HEIGHT + BUFFER_HEIGHT)).rev() {
-            if !sorted_lines.contains(&y) {
-                // This line is not being cleared, copy it
-                new_grid[new_y] = self.grid[y];
-                if new_y > 0 {
-                    new_y -= 1;
-                }
-            }
-            // If this line is being cleared, skip it (don't copy)
+        if sorted_lines.is_empty() {
+            return (0, sorted_lines);
         }
         
-        // Replace the old grid with the new one
-        self.grid = new_grid;
+        let lines_cleared_count = sorted_lines.len() as u32;
+        // A consistent stack must stay consistent however many rows go, buffer rows included
+        let held_before = cfg!(debug_assertions) && self.invariants_hold();
+        
+        // Highest row first: removing a row only moves the rows above it, so the indices
+        // of the lower rows still waiting to be removed stay valid
+        for &y in &sorted_lines {
+            self.shift_down_above(y);
+        }
+        debug_assert!(!held_before || self.invariants_hold(), "clearing rows {:?} broke the board", sorted_lines);
         
         // Update statistics
         self.lines_cleared += lines_cleared_count;
-        self.level = (self.lines_cleared / LINES_PER_LEVEL) + 1;
+        self.level = self.level_from(1);
         
-        lines_cleared_count
+        (lines_cleared_count, sorted_lines)
+    }
+    
+    /// Remove `row` by moving every row above it down by one, leaving an empty top row
+    ///
+    /// Rows below `row` are untouched. Does not count towards `lines_cleared`; out-of-range
+    /// rows are ignored.
+    pub fn shift_down_above(&mut self, row: usize) {
+        if row >= BOARD_HEIGHT + BUFFER_HEIGHT {
+            return;
+        }
+        
+        self.grid[..=row].rotate_right(1);
+        self.lock_times[..=row].rotate_right(1);
+        self.row_fill_count[..=row].rotate_right(1);
+        
+        self.grid[0] = [Cell::Empty; BOARD_WIDTH];
+        self.lock_times[0] = [0.0; BOARD_WIDTH];
+        self.row_fill_count[0] = 0;
+        
+        // Columns topping out above the row just drop by one; one topping out at it lost its top block
+        let removed_height = ((BOARD_HEIGHT + BUFFER_HEIGHT) - row) as u16;
+        for x in 0..BOARD_WIDTH {
+            if self.column_heights[x] > removed_height {
+                self.column_heights[x] -= 1;
+            } else if self.column_heights[x] == removed_height {
+                self.column_heights[x] = self.measure_column(x);
+            }
+        }
+    }
+    
+    /// Push the stack up and insert garbage rows at the bottom, one per entry in
+    /// `hole_columns`, each filled except for its hole (in order, the last entry ends up
+    /// as the bottom row)
+    ///
+    /// Returns false if filled cells were pushed off the top of the board.
+    pub fn add_garbage_lines(&mut self, hole_columns: &[usize]) -> bool {
+        let total_rows = BOARD_HEIGHT + BUFFER_HEIGHT;
+        let count = hole_columns.len().min(total_rows);
+        if count == 0 {
+            return true;
+        }
+        
+        let overflowed = self.row_fill_count[..count].iter().any(|&filled| filled > 0);
+        
+        self.grid.rotate_left(count);
+        self.lock_times.rotate_left(count);
+        self.row_fill_count.rotate_left(count);
+        
+        let first_garbage_row = total_rows - count;
+        for (offset, &hole) in hole_columns[hole_columns.len() - count..].iter().enumerate() {
+            let y = first_garbage_row + offset;
+            for x in 0..BOARD_WIDTH {
+                self.grid[y][x] = if x == hole { Cell::Empty } else { Cell::block(GARBAGE_COLOR) };
+                self.lock_times[y][x] = 0.0;
+            }
+            self.row_fill_count[y] = self.grid[y].iter().filter(|cell| cell.is_filled()).count() as u16;
+        }
+        self.column_heights = self.measure_columns();
+        
+        !overflowed
+    }
+    
+    /// Fill the bottom `height` cells of column `x` with `cell`, leaving the cells above alone
+    ///
+    /// Returns false, changing nothing, if `x` is off the board or `height` is taller than it.
+    pub fn fill_column(&mut self, x: usize, height: usize, cell: Cell) -> bool {
+        let total_rows = BOARD_HEIGHT + BUFFER_HEIGHT;
+        if x >= BOARD_WIDTH || height > total_rows {
+            return false;
+        }
+        
+        for y in total_rows - height..total_rows {
+            self.set_cell(x as i32, y as i32, cell);
+        }
+        true
+    }
+    
+    /// Empty every cell of column `x`
+    ///
+    /// Returns false if `x` is off the board.
+    pub fn clear_column(&mut self, x: usize) -> bool {
+        if x >= BOARD_WIDTH {
+            return false;
+        }
+        
+        for y in 0..BOARD_HEIGHT + BUFFER_HEIGHT {
+            self.set_cell(x as i32, y as i32, Cell::Empty);
+        }
+        true
     }
     
     /// Get the current level
@@ -213,6 +532,37 @@ impl Board {
         self.lines_cleared
     }
     
+    /// Get how many lines each level takes
+    pub fn lines_per_level(&self) -> u32 {
+        self.lines_per_level
+    }
+    
+    /// Set how many lines each level takes (at least 1), recomputing the level
+    pub fn set_lines_per_level(&mut self, lines: u32) {
+        self.lines_per_level = lines.max(1);
+        self.level = self.level_from(1);
+    }
+    
+    /// Level reached by the lines cleared so far, counting up from `start_level`
+    pub fn level_from(&self, start_level: u32) -> u32 {
+        start_level.max(1) + self.lines_cleared / self.lines_per_level
+    }
+    
+    /// Lines left to clear before the next level, counting up from `start_level`
+    ///
+    /// Returns `None` once the level has reached `max_level`, since there is no next level.
+    pub fn lines_until_next_level(&self, start_level: u32, max_level: Option<u32>) -> Option<u32> {
+        if max_level.is_some_and(|cap| self.level_from(start_level) >= cap.max(1)) {
+            return None;
+        }
+        Some(self.lines_per_level - self.lines_cleared % self.lines_per_level)
+    }
+    
+    /// Check if a piece sits entirely in the buffer zone above the visible playfield
+    pub fn is_lock_out(&self, piece: &Tetromino) -> bool {
+        piece.absolute_blocks().iter().all(|&(_, y)| y < BUFFER_HEIGHT as i32)
+    }
+    
     /// Check if the game is over (pieces have reached the top)
     pub fn is_game_over(&self) -> bool {
         // Check if any cells in the spawn area (buffer zone) are filled
@@ -229,23 +579,56 @@ impl Board {
     /// Clear the entire board
     pub fn clear(&mut self) {
         self.grid = [[Cell::Empty; BOARD_WIDTH]; BOARD_HEIGHT + BUFFER_HEIGHT];
+        self.lock_times = [[0.0; BOARD_WIDTH]; BOARD_HEIGHT + BUFFER_HEIGHT];
+        self.row_fill_count = vec![0; BOARD_HEIGHT + BUFFER_HEIGHT];
+        self.column_heights = [0; BOARD_WIDTH];
         self.lines_cleared = 0;
         self.level = 1;
     }
     
     /// Get the height of the highest filled cell in a column
     pub fn column_height(&self, x: usize) -> usize {
-        if x >= BOARD_WIDTH {
-            return 0;
-        }
-        
-        for y in 0..(BOARD_HEIGHT + BUFFER_HEIGHT) {
-            if self.grid[y][x].is_filled() {
-                return (BOARD_HEIGHT + BUFFER_HEIGHT) - y;
-            }
-        }
-        
-        0 // Column is empty
+        self.column_heights.get(x).map_or(0, |&height| height as usize)
+    }
+    
+    /// Get every column's height, counted in rows up from the floor
+    /// Kept up to date as cells change, so the renderer can read it every frame
+    pub fn column_heights(&self) -> [u16; BOARD_WIDTH] {
+        self.column_heights
+    }
+    
+    /// Get the height of the tallest column, counted in rows up from the floor
+    /// Blocks in the buffer zone give heights above the visible board height
+    pub fn stack_height(&self) -> usize {
+        (0..BOARD_WIDTH).map(|x| self.column_height(x)).max().unwrap_or(0)
+    }
+    
+    /// Sum of every column's height
+    ///
+    /// Like `column_height`, heights are counted up from the floor, so blocks in the buffer
+    /// zone count too. Together with `holes` and `bumpiness` this is a standard feature for
+    /// scoring boards in bots.
+    pub fn aggregate_height(&self) -> u32 {
+        (0..BOARD_WIDTH).map(|x| self.column_height(x) as u32).sum()
+    }
+    
+    /// Number of empty cells with at least one filled cell above them in the same column
+    pub fn holes(&self) -> u32 {
+        let total_rows = BOARD_HEIGHT + BUFFER_HEIGHT;
+        (0..BOARD_WIDTH)
+            .map(|x| {
+                // Everything below the column's top block is covered
+                let top = total_rows - self.column_height(x);
+                (top..total_rows).filter(|&y| self.grid[y][x].is_empty()).count() as u32
+            })
+            .sum()
+    }
+    
+    /// Sum of the absolute height differences between neighbouring columns
+    pub fn bumpiness(&self) -> u32 {
+        (1..BOARD_WIDTH)
+            .map(|x| self.column_height(x).abs_diff(self.column_height(x - 1)) as u32)
+            .sum()
     }
     
     /// Get the total number of filled cells
@@ -271,7 +654,7 @@ impl Board {
             for x in 0..BOARD_WIDTH {
                 match self.grid[y][x] {
                     Cell::Empty => result.push(' '),
-                    Cell::Filled(_) => result.push('#'),
+                    Cell::Filled(..) => result.push('#'),
                 }
             }
             result.push_str("|\n");
@@ -286,6 +669,54 @@ impl Board {
         
         result
     }
+    
+    /// Render the visible area as text: `#` for filled, `.` for empty, one line per row
+    pub fn to_ascii(&self) -> String {
+        let rows: Vec<String> = (BUFFER_HEIGHT..(BOARD_HEIGHT + BUFFER_HEIGHT))
+            .map(|y| {
+                self.grid[y]
+                    .iter()
+                    .map(|cell| if cell.is_filled() { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        rows.join("\n")
+    }
+    
+    /// Build a board from the `to_ascii` format, filling cells with `GARBAGE_COLOR`
+    /// Fewer rows than the visible height are aligned to the bottom of the board,
+    /// so tests only need to spell out the rows they care about
+    pub fn from_ascii(text: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let rows: Vec<&str> = text
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .collect();
+        
+        if rows.len() > VISIBLE_HEIGHT {
+            return Err(format!("expected at most {} rows, got {}", VISIBLE_HEIGHT, rows.len()).into());
+        }
+        
+        let mut board = Self::new();
+        let first_y = BOARD_HEIGHT + BUFFER_HEIGHT - rows.len();
+        
+        for (row_index, row) in rows.iter().enumerate() {
+            if row.chars().count() != BOARD_WIDTH {
+                return Err(format!("row {} has {} columns, expected {}", row_index, row.chars().count(), BOARD_WIDTH).into());
+            }
+            
+            for (x, c) in row.chars().enumerate() {
+                let cell = match c {
+                    '#' => Cell::block(GARBAGE_COLOR),
+                    '.' => Cell::Empty,
+                    other => return Err(format!("unexpected character '{}' in row {}", other, row_index).into()),
+                };
+                board.set_cell(x as i32, (first_y + row_index) as i32, cell);
+            }
+        }
+        
+        Ok(board)
+    }
 }
 
 impl Default for Board {
@@ -314,17 +745,17 @@ mod tests {
         let test_color = TETROMINO_I;
         
         // Test setting and getting cells
-        assert!(board.set_cell(5, 10, Cell::Filled(test_color)));
+        assert!(board.set_cell(5, 10, Cell::block(test_color)));
         
         let cell = board.get_cell(5, 10).unwrap();
-        assert_eq!(cell, Cell::Filled(test_color));
+        assert_eq!(cell, Cell::block(test_color));
         assert!(cell.is_filled());
         assert!(!cell.is_empty());
         assert_eq!(cell.color(), Some(test_color));
         
         // Test bounds checking
-        assert!(!board.set_cell(-1, 10, Cell::Filled(test_color)));
-        assert!(!board.set_cell(10, 10, Cell::Filled(test_color)));
+        assert!(!board.set_cell(-1, 10, Cell::block(test_color)));
+        assert!(!board.set_cell(10, 10, Cell::block(test_color)));
         assert_eq!(board.get_cell(-1, 10), None);
         assert_eq!(board.get_cell(10, 10), None);
     }
@@ -338,7 +769,7 @@ mod tests {
         assert!(board.is_position_valid(5, 10));
         
         // Fill a cell
-        board.set_cell(5, 10, Cell::Filled(test_color));
+        board.set_cell(5, 10, Cell::block(test_color));
         
         // Filled position should not be valid
         assert!(!board.is_position_valid(5, 10));
@@ -362,7 +793,7 @@ mod tests {
         
         // Fill some cells in line 23
         for x in 0..5 {
-            board.set_cell(x, 23, Cell::Filled(test_color));
+            board.set_cell(x, 23, Cell::block(test_color));
         }
         
         // Partially filled line
@@ -371,7 +802,7 @@ mod tests {
         
         // Fill the entire line
         for x in 5..10 {
-            board.set_cell(x, 23, Cell::Filled(test_color));
+            board.set_cell(x, 23, Cell::block(test_color));
         }
         
         // Full line
@@ -386,12 +817,12 @@ mod tests {
         
         // Fill two complete lines
         for x in 0..10 {
-            board.set_cell(x, 22, Cell::Filled(test_color));
-            board.set_cell(x, 23, Cell::Filled(test_color));
+            board.set_cell(x, 22, Cell::block(test_color));
+            board.set_cell(x, 23, Cell::block(test_color));
         }
         
         // Add a block above the complete lines
-        board.set_cell(0, 21, Cell::Filled(test_color));
+        board.set_cell(0, 21, Cell::block(test_color));
         
         assert_eq!(board.filled_cells_count(), 21); // 20 + 1
         
@@ -401,13 +832,14 @@ mod tests {
         assert!(complete_lines.contains(&22));
         assert!(complete_lines.contains(&23));
         
-        let lines_cleared = board.clear_lines(&complete_lines);
+        let (lines_cleared, removed) = board.clear_lines(&complete_lines);
         assert_eq!(lines_cleared, 2);
+        assert_eq!(removed, vec![22, 23]);
         assert_eq!(board.lines_cleared(), 2);
         assert_eq!(board.level(), 1); // Still level 1 (need 10 lines for level 2)
         
         // The block that was at (0, 21) should now be at (0, 23)
-        assert_eq!(board.get_cell(0, 23).unwrap(), Cell::Filled(test_color));
+        assert_eq!(board.get_cell(0, 23).unwrap(), Cell::block(test_color));
         
         // After clearing 2 complete lines (20 blocks), we should have 1 block remaining
         assert_eq!(board.filled_cells_count(), 1);
@@ -422,9 +854,9 @@ mod tests {
         assert_eq!(board.column_height(5), 0);
         
         // Add blocks to column 5
-        board.set_cell(5, 23, Cell::Filled(test_color)); // Bottom
-        board.set_cell(5, 22, Cell::Filled(test_color)); // Middle
-        board.set_cell(5, 20, Cell::Filled(test_color)); // Top (with gap)
+        board.set_cell(5, 23, Cell::block(test_color)); // Bottom
+        board.set_cell(5, 22, Cell::block(test_color)); // Middle
+        board.set_cell(5, 20, Cell::block(test_color)); // Top (with gap)
         
         // Height should be from top filled cell to bottom
         let expected_height = (BOARD_HEIGHT + BUFFER_HEIGHT) - 20; // 24 - 20 = 4
@@ -440,7 +872,7 @@ mod tests {
         assert!(!board.is_game_over());
         
         // Fill a cell in the buffer area (spawn area)
-        board.set_cell(5, 2, Cell::Filled(test_color)); // Buffer area
+        board.set_cell(5, 2, Cell::block(test_color)); // Buffer area
         
         // Game should now be over
         assert!(board.is_game_over());
@@ -452,12 +884,12 @@ mod tests {
         let test_color = TETROMINO_O;
         
         // Add some blocks and statistics
-        board.set_cell(0, 23, Cell::Filled(test_color));
-        board.set_cell(1, 23, Cell::Filled(test_color));
+        board.set_cell(0, 23, Cell::block(test_color));
+        board.set_cell(1, 23, Cell::block(test_color));
         
         // Simulate some lines cleared
         for x in 0..10 {
-            board.set_cell(x, 22, Cell::Filled(test_color));
+            board.set_cell(x, 22, Cell::block(test_color));
         }
         let complete_lines = board.find_complete_lines();
         board.clear_lines(&complete_lines);
@@ -475,4 +907,366 @@ mod tests {
         assert_eq!(board.level(), 1);
         assert!(!board.is_game_over());
     }
+
+    #[test]
+    fn test_ascii_round_trip() {
+        let patterns = [
+            "",
+            "#.........\n##########",
+            "....##....\n...####...\n#.#.#.#.#.\n.#.#.#.#.#",
+        ];
+        
+        for pattern in patterns {
+            let board = Board::from_ascii(pattern).unwrap();
+            assert_eq!(Board::from_ascii(&board.to_ascii()).unwrap(), board);
+        }
+        
+        // Boards built by hand round-trip too when using the same fill color
+        let mut board = Board::new();
+        board.set_cell(3, 23, Cell::block(GARBAGE_COLOR));
+        board.set_cell(9, 10, Cell::block(GARBAGE_COLOR));
+        assert_eq!(Board::from_ascii(&board.to_ascii()).unwrap(), board);
+        
+        // Short inputs sit at the bottom of the board
+        let board = Board::from_ascii("#.........").unwrap();
+        assert!(board.get_cell(0, 23).unwrap().is_filled());
+        assert_eq!(board.to_ascii().lines().count(), VISIBLE_HEIGHT);
+        
+        assert!(Board::from_ascii("###").is_err());
+        assert!(Board::from_ascii("x.........").is_err());
+    }
+
+    #[test]
+    fn test_stack_height_uneven_surface() {
+        assert_eq!(Board::new().stack_height(), 0);
+        
+        let board = Board::from_ascii(
+            "...#......\n\
+             ...#....#.\n\
+             #..##...#.\n\
+             ##.###.###",
+        ).unwrap();
+        assert_eq!(board.stack_height(), 4);
+        assert_eq!(board.column_height(0), 2);
+        assert_eq!(board.column_height(2), 0);
+        
+        let mut board = Board::new();
+        board.set_cell(7, 5, Cell::block(GARBAGE_COLOR));
+        assert_eq!(board.stack_height(), BOARD_HEIGHT + BUFFER_HEIGHT - 5);
+    }
+    
+    #[test]
+    fn test_holes_bumpiness_and_aggregate_height() {
+        let empty = Board::new();
+        assert_eq!((empty.holes(), empty.bumpiness(), empty.aggregate_height()), (0, 0, 0));
+        
+        // A flat surface has no bumpiness
+        let flat = Board::from_ascii(
+            "##########\n\
+             #########.",
+        ).unwrap();
+        assert_eq!(flat.bumpiness(), 0);
+        assert_eq!(flat.aggregate_height(), 20);
+        // The gap in the bottom row is covered from above
+        assert_eq!(flat.holes(), 1);
+        
+        // An open well is not a hole; an overhang is
+        let board = Board::from_ascii(
+            "#.........\n\
+             .......#..\n\
+             #.#....#..\n\
+             ##.#...#..",
+        ).unwrap();
+        assert_eq!(board.holes(), 2);
+        // Heights are 4, 1, 2, 1, 0, 0, 0, 3, 0, 0
+        assert_eq!(board.aggregate_height(), 11);
+        // Neighbouring columns differ by 3, 1, 1, 1, 0, 0, 3, 3, 0
+        assert_eq!(board.bumpiness(), 12);
+    }
+
+    #[test]
+    fn test_row_fill_counts_track_fills_clears_and_garbage() {
+        let mut board = Board::new();
+        let bottom = BOARD_HEIGHT + BUFFER_HEIGHT - 1;
+        
+        for x in 0..BOARD_WIDTH as i32 {
+            board.set_cell(x, bottom as i32, Cell::block(TETROMINO_T));
+        }
+        board.set_cell(0, bottom as i32 - 1, Cell::block(TETROMINO_T));
+        // Refilling a filled cell or emptying an empty one changes nothing
+        board.set_cell(0, bottom as i32 - 1, Cell::block(TETROMINO_I));
+        board.set_cell(5, bottom as i32 - 1, Cell::Empty);
+        assert_eq!(board.row_fill_count(bottom), BOARD_WIDTH as u16);
+        assert_eq!(board.row_fill_count(bottom - 1), 1);
+        assert_eq!(board.find_complete_lines(), vec![bottom]);
+        
+        board.clear_lines(&[bottom]);
+        assert_eq!(board.row_fill_count(bottom), 1);
+        assert_eq!(board.row_fill_count(bottom - 1), 0);
+        
+        // Two garbage rows push the single block up two rows
+        assert!(board.add_garbage_lines(&[3, 7]));
+        assert_eq!(board.row_fill_count(bottom), BOARD_WIDTH as u16 - 1);
+        assert_eq!(board.row_fill_count(bottom - 1), BOARD_WIDTH as u16 - 1);
+        assert_eq!(board.row_fill_count(bottom - 2), 1);
+        assert!(board.get_cell(7, bottom as i32).unwrap().is_empty());
+        assert!(board.get_cell(3, bottom as i32 - 1).unwrap().is_empty());
+        assert_eq!(board.row_fill_count, board.count_rows());
+        
+        // Counts are rebuilt when a board is loaded
+        let loaded: Board = serde_json::from_str(&serde_json::to_string(&board).unwrap()).unwrap();
+        assert_eq!(loaded, board);
+    }
+
+    #[test]
+    fn test_complete_lines_if_placed() {
+        let board = Board::from_ascii(
+            "......#...
+             ####..####
+             ######.###",
+        ).unwrap();
+        let bottom = BOARD_HEIGHT + BUFFER_HEIGHT - 1;
+        let before = board.clone();
+        
+        // A horizontal I across the gap in the middle row completes it
+        let i_piece = [(3, bottom as i32 - 1), (4, bottom as i32 - 1), (5, bottom as i32 - 1), (6, bottom as i32 - 1)];
+        assert_eq!(board.complete_lines_if_placed(&[(4, bottom as i32 - 1), (5, bottom as i32 - 1)]), vec![bottom - 1]);
+        // Cells already filled don't count twice
+        assert_eq!(board.complete_lines_if_placed(&i_piece), vec![bottom - 1]);
+        
+        // Filling both gaps completes both rows
+        let both = [(4, bottom as i32 - 1), (5, bottom as i32 - 1), (6, bottom as i32), (6, bottom as i32)];
+        assert_eq!(board.complete_lines_if_placed(&both), vec![bottom - 1, bottom]);
+        
+        // A piece up in empty space completes nothing
+        assert!(board.complete_lines_if_placed(&[(0, 5), (1, 5), (2, 5), (3, 5)]).is_empty());
+        assert!(board.complete_lines_if_placed(&[(-1, bottom as i32), (10, bottom as i32)]).is_empty());
+        
+        // Cells above the board, as a freshly spawned or kicked piece can have, add nothing
+        assert!(board.complete_lines_if_placed(&[(4, -1), (5, -2)]).is_empty());
+        assert_eq!(board.complete_lines_if_placed(&[(4, -1), (4, bottom as i32 - 1), (5, bottom as i32 - 1)]), vec![bottom - 1]);
+        
+        assert_eq!(board, before);
+    }
+    
+    #[test]
+    fn test_rows_missing_at_most() {
+        let board = Board::from_ascii(
+            "#.........
+             ##.##.####
+             #####.####
+             ##########",
+        ).unwrap();
+        let bottom = BOARD_HEIGHT + BUFFER_HEIGHT - 1;
+        
+        // Only the row one cell short; the full row and sparse ones don't qualify
+        assert_eq!(board.rows_missing_at_most(1), vec![bottom - 1]);
+        assert_eq!(board.rows_missing_at_most(2), vec![bottom - 2, bottom - 1]);
+        assert!(board.rows_missing_at_most(0).is_empty());
+        assert_eq!(board.rows_missing_at_most(BOARD_WIDTH).len(), 3);
+    }
+    
+    #[test]
+    fn test_board_serializes_only_filled_cells() {
+        let mut board = Board::from_ascii(
+            "....#.....
+             ###.######",
+        ).unwrap();
+        let bottom = (BOARD_HEIGHT + BUFFER_HEIGHT - 1) as i32;
+        board.set_lock_time(0, bottom, 12.5);
+        board.lines_cleared = 3;
+        
+        let filled: Vec<(i32, i32)> = board.iter_filled_cells().map(|(x, y, _)| (x, y)).collect();
+        assert_eq!(filled.len(), 10);
+        assert_eq!(filled[0], (4, bottom - 1));
+        board.set_cell(0, bottom - 1, Cell::piece(TetrominoType::S));
+        
+        let json = serde_json::to_string(&board).unwrap();
+        let dense = serde_json::to_string(&serde_json::json!({
+            "grid": board.grid,
+            "lines_cleared": board.lines_cleared,
+            "level": board.level,
+            "lock_times": board.lock_times,
+        })).unwrap();
+        assert!(json.len() * 5 < dense.len(), "compact {} bytes vs dense {}", json.len(), dense.len());
+        
+        let reloaded: Board = serde_json::from_str(&json).unwrap();
+        assert_eq!(reloaded, board);
+        assert_eq!(reloaded.lock_time(0, bottom), Some(12.5));
+        assert_eq!(reloaded.row_fill_count(bottom as usize), 9);
+        assert_eq!(reloaded.get_cell(0, bottom - 1).unwrap().piece_type(), Some(TetrominoType::S));
+        assert_eq!(reloaded.get_cell(4, bottom - 1).unwrap().piece_type(), None);
+        
+        // Saves written before the compact format still load
+        let legacy: Board = serde_json::from_str(&dense).unwrap();
+        assert_eq!(legacy, board);
+        
+        assert!(serde_json::from_str::<Board>(r#"{"cells":[[10,0,[1,1,1,1],0.0,null]],"lines_cleared":0,"level":1}"#).is_err());
+    }
+    
+    #[test]
+    fn test_clearing_rows_one_at_a_time_matches_expected_boards() {
+        // Two rows apart: each block above a cleared row drops by one per cleared row below it
+        let mut board = Board::from_ascii("#.........\n##########\n.#........\n##########\n..#.......").unwrap();
+        let bottom = BOARD_HEIGHT + BUFFER_HEIGHT - 1;
+        assert_eq!(board.clear_lines(&[bottom - 1, bottom - 3]), (2, vec![bottom - 3, bottom - 1]));
+        assert_eq!(board.to_ascii(), Board::from_ascii("#.........\n.#........\n..#.......").unwrap().to_ascii());
+        
+        // Two adjacent rows
+        let mut board = Board::from_ascii("...#......\n##########\n##########\n#.#.#.#.#.").unwrap();
+        assert_eq!(board.clear_lines(&[bottom - 1, bottom - 2]), (2, vec![bottom - 2, bottom - 1]));
+        assert_eq!(board.to_ascii(), Board::from_ascii("...#......\n#.#.#.#.#.").unwrap().to_ascii());
+        
+        // A single shift moves only what is above the row and empties the top
+        let mut board = Board::from_ascii("#.........\n.#........\n..#.......").unwrap();
+        board.set_cell(5, 0, Cell::block(GARBAGE_COLOR));
+        board.shift_down_above(bottom - 1);
+        assert_eq!(board.to_ascii(), Board::from_ascii("#.........\n..#.......").unwrap().to_ascii());
+        assert_eq!(board.get_cell(5, 0), Some(Cell::Empty));
+        assert_eq!(board.get_cell(5, 1).map(|cell| cell.is_filled()), Some(true));
+        assert_eq!(board.row_fill_count(0), 0);
+        assert_eq!(board.row_fill_count(1), 1);
+        assert_eq!(board.lines_cleared(), 0);
+    }
+    
+    #[test]
+    fn test_invariants_hold_through_random_stacking_clears_and_garbage() {
+        use ::rand::{Rng, SeedableRng};
+        use ::rand::rngs::StdRng;
+        
+        let total_rows = BOARD_HEIGHT + BUFFER_HEIGHT;
+        let mut rng = StdRng::seed_from_u64(0x7e7215);
+        
+        for round in 0..50 {
+            let mut board = Board::new();
+            for step in 0..400 {
+                match rng.gen_range(0..10) {
+                    // Stack a block on top of a column, as a piece landing would
+                    0..=5 => {
+                        let x = rng.gen_range(0..BOARD_WIDTH);
+                        let height = board.column_height(x);
+                        if height < total_rows {
+                            board.set_cell(x as i32, (total_rows - 1 - height) as i32, Cell::block(TETROMINO_T));
+                            board.set_lock_time(x as i32, (total_rows - 1 - height) as i32, step as f64);
+                        }
+                    },
+                    // Clear whatever rows are complete
+                    6 => {
+                        let complete = board.find_complete_lines();
+                        let filled_before = board.filled_cells_count();
+                        let (cleared, _) = board.clear_lines(&complete);
+                        assert_eq!(board.filled_cells_count(), filled_before - cleared as usize * BOARD_WIDTH);
+                    },
+                    // Remove rows outright, favouring the buffer/visible boundary
+                    7 => {
+                        let rows: Vec<usize> = (0..rng.gen_range(1..4))
+                            .map(|_| if rng.gen_bool(0.5) { rng.gen_range(BUFFER_HEIGHT - 2..BUFFER_HEIGHT + 2) } else { rng.gen_range(0..total_rows) })
+                            .collect();
+                        let expected: usize = {
+                            let mut unique = rows.clone();
+                            unique.sort();
+                            unique.dedup();
+                            unique.iter().map(|&y| board.row_fill_count(y) as usize).sum()
+                        };
+                        let filled_before = board.filled_cells_count();
+                        board.clear_lines(&rows);
+                        assert_eq!(board.filled_cells_count(), filled_before - expected);
+                    },
+                    // Garbage pushes the stack up
+                    _ => {
+                        let holes: Vec<usize> = (0..rng.gen_range(1..4)).map(|_| rng.gen_range(0..BOARD_WIDTH)).collect();
+                        board.add_garbage_lines(&holes);
+                    },
+                }
+                assert!(board.invariants_hold(), "round {} step {} broke the board:\n{}", round, step, board.debug_string());
+            }
+        }
+        
+        // A gap under the stack is caught
+        let mut board = Board::new();
+        board.set_cell(0, (total_rows - 2) as i32, Cell::block(TETROMINO_T));
+        assert!(!board.invariants_hold());
+    }
+    
+    #[test]
+    fn test_column_heights_follow_fills_and_clears() {
+        let mut board = Board::from_ascii(
+            "#.........\n\
+             ##........\n\
+             ###.......\n\
+             ####......\n\
+             ##########",
+        ).unwrap();
+        assert_eq!(board.column_heights(), [5, 4, 3, 2, 1, 1, 1, 1, 1, 1]);
+        
+        let bottom = BOARD_HEIGHT + BUFFER_HEIGHT - 1;
+        board.clear_lines(&[bottom]);
+        assert_eq!(board.column_heights(), [4, 3, 2, 1, 0, 0, 0, 0, 0, 0]);
+        
+        // Emptying a column's top block drops it to the next block down
+        board.set_cell(0, (bottom - 3) as i32, Cell::Empty);
+        assert_eq!(board.column_height(0), 3);
+        
+        board.add_garbage_lines(&[9]);
+        assert_eq!(board.column_heights(), [4, 4, 3, 2, 1, 1, 1, 1, 1, 0]);
+        assert!(board.invariants_hold());
+    }
+    
+    #[test]
+    fn test_fill_and_clear_column() {
+        let mut board = Board::from_ascii(
+            "..........\n\
+             .........#",
+        ).unwrap();
+        let total_rows = BOARD_HEIGHT + BUFFER_HEIGHT;
+        
+        assert!(board.fill_column(2, 4, Cell::block(TETROMINO_T)));
+        let filled: Vec<usize> = (0..total_rows).filter(|&y| board.get_cell(2, y as i32).unwrap().is_filled()).collect();
+        assert_eq!(filled, (total_rows - 4..total_rows).collect::<Vec<_>>());
+        assert_eq!(board.column_height(2), 4);
+        assert_eq!(board.filled_cells_count(), 5);
+        
+        // Out of bounds is refused without touching the board
+        assert!(!board.fill_column(BOARD_WIDTH, 1, Cell::block(TETROMINO_T)));
+        assert!(!board.fill_column(0, total_rows + 1, Cell::block(TETROMINO_T)));
+        assert!(!board.clear_column(BOARD_WIDTH));
+        assert_eq!(board.filled_cells_count(), 5);
+        
+        assert!(board.clear_column(2));
+        assert_eq!(board.column_height(2), 0);
+        assert_eq!(board.column_height(9), 1);
+        assert_eq!(board.filled_cells_count(), 1);
+        assert!(board.invariants_hold());
+    }
+    
+    #[test]
+    fn test_lines_per_level() {
+        let mut board = Board::new();
+        board.set_lines_per_level(5);
+        let bottom = BOARD_HEIGHT + BUFFER_HEIGHT - 1;
+        assert_eq!(board.lines_until_next_level(1, None), Some(5));
+        
+        board.clear_lines(&[bottom, bottom - 1]);
+        assert_eq!(board.level(), 1);
+        assert_eq!(board.lines_until_next_level(1, None), Some(3));
+        
+        board.clear_lines(&[bottom, bottom - 1, bottom - 2]);
+        assert_eq!(board.lines_cleared(), 5);
+        assert_eq!(board.level(), 2);
+        assert_eq!(board.lines_until_next_level(1, None), Some(5));
+        
+        // Counting stops at the level cap
+        assert_eq!(board.lines_until_next_level(1, Some(3)), Some(5));
+        assert_eq!(board.lines_until_next_level(1, Some(2)), None);
+        assert_eq!(board.lines_until_next_level(7, Some(5)), None);
+        
+        // A higher starting level adds on top, and the cadence survives a save
+        assert_eq!(board.level_from(7), 8);
+        let reloaded: Board = serde_json::from_str(&serde_json::to_string(&board).unwrap()).unwrap();
+        assert_eq!(reloaded.lines_per_level(), 5);
+        
+        board.set_lines_per_level(10);
+        assert_eq!(board.level(), 1);
+        assert_eq!(board.lines_until_next_level(1, None), Some(5));
+    }
 }
@@ -1,4 +1,82 @@
-//! Board rendering functionality
+//! Plain-text rendering of the board, for terminals and anything else that takes a writer
+//!
+//! Uses the same `#`/`.` format as `Board::to_ascii`, so it needs no window or GPU.
 
-// Board rendering functions (placeholder for Phase 1)
-// Will be implemented in next todo item
+use crate::board::Board;
+use crate::game::config::{BOARD_HEIGHT, BUFFER_HEIGHT, BOARD_WIDTH};
+use crate::Game;
+use std::io::{self, Write};
+
+/// Write the visible board in the `to_ascii` format, one line per row
+pub fn render_board_to_writer(board: &Board, writer: &mut impl Write) -> io::Result<()> {
+    writeln!(writer, "{}", board.to_ascii())
+}
+
+/// Write a game's board with the falling piece as `@` and its landing spot as `+`, framed
+/// in the legacy style, followed by the score line
+///
+/// The buffer rows are drawn unframed above the board, since that is where pieces spawn.
+pub fn render_game_to_writer(game: &Game, writer: &mut impl Write) -> io::Result<()> {
+    let mut rows: Vec<Vec<char>> = vec![vec![' '; BOARD_WIDTH]; BUFFER_HEIGHT];
+    rows.extend(game.board.to_ascii().lines().map(|line| line.chars().collect::<Vec<char>>()));
+    let mut mark = |cells: Vec<(i32, i32)>, symbol: char| {
+        for (x, y) in cells {
+            if (0..(BOARD_HEIGHT + BUFFER_HEIGHT) as i32).contains(&y) && (0..BOARD_WIDTH as i32).contains(&x) {
+                rows[y as usize][x as usize] = symbol;
+            }
+        }
+    };
+    if let Some(ghost) = game.calculate_ghost_piece() {
+        mark(ghost.absolute_blocks(), '+');
+    }
+    if let Some(piece) = &game.current_piece {
+        mark(piece.absolute_blocks(), '@');
+    }
+    
+    for (y, row) in rows.into_iter().enumerate() {
+        let cells: String = row.into_iter().collect();
+        if y < BUFFER_HEIGHT {
+            writeln!(writer, "{}", format!("  {}", cells).trim_end())?;
+        } else {
+            writeln!(writer, "<!{}!>", cells)?;
+        }
+    }
+    writeln!(writer, "<!{}!>", "=".repeat(BOARD_WIDTH))?;
+    writeln!(
+        writer,
+        "SCORE {}  LEVEL {}  LINES {}  NEXT {}",
+        game.score,
+        game.level(),
+        game.lines_cleared(),
+        game.next_piece.name()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::GameState;
+
+    #[test]
+    fn test_board_renders_in_ascii_format() {
+        let board = Board::from_ascii("....##....\n#.#.#.#.#.\n##########").unwrap();
+        let mut output = Vec::new();
+        render_board_to_writer(&board, &mut output).unwrap();
+        
+        let text = String::from_utf8(output).unwrap();
+        assert_eq!(text, format!("{}\n", board.to_ascii()));
+        assert!(text.ends_with("....##....\n#.#.#.#.#.\n##########\n"));
+    }
+
+    #[test]
+    fn test_game_render_shows_the_falling_piece() {
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        let mut output = Vec::new();
+        render_game_to_writer(&game, &mut output).unwrap();
+        
+        let text = String::from_utf8(output).unwrap();
+        assert_eq!(text.matches('@').count(), 4);
+        assert!(text.lines().last().unwrap().starts_with("SCORE 0"));
+    }
+}
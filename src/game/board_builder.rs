@@ -0,0 +1,102 @@
+//! Builds games in a given position for tests, from ASCII rows and a piece
+
+use crate::board::Board;
+use crate::game::state::{Game, GameState};
+use crate::tetromino::{Tetromino, TetrominoType};
+
+/// A game set up from ASCII board rows and a current piece, already past the countdown
+///
+/// Rows use the `Board::from_ascii` format and sit at the bottom of the board.
+///
+/// ```ignore
+/// let game = BoardBuilder::new()
+///     .rows(&["..........", "#########."])
+///     .current_piece(TetrominoType::I, (4, 10))
+///     .build();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct BoardBuilder {
+    rows: Vec<String>,
+    current_piece: Option<(TetrominoType, (i32, i32))>,
+    next_piece: Option<TetrominoType>,
+}
+
+impl BoardBuilder {
+    /// An empty board with whatever piece a new game spawns
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Board rows from top to bottom, bottom-aligned like `Board::from_ascii`
+    pub fn rows(mut self, rows: &[&str]) -> Self {
+        self.rows = rows.iter().map(|row| row.to_string()).collect();
+        self
+    }
+
+    /// Put a spawn-rotation `piece_type` at `position` as the current piece
+    pub fn current_piece(mut self, piece_type: TetrominoType, position: (i32, i32)) -> Self {
+        self.current_piece = Some((piece_type, position));
+        self
+    }
+
+    /// Make `piece_type` the next piece
+    pub fn next_piece(mut self, piece_type: TetrominoType) -> Self {
+        self.next_piece = Some(piece_type);
+        self
+    }
+
+    /// The board on its own
+    ///
+    /// Panics if the rows aren't valid `Board::from_ascii` input.
+    pub fn build_board(&self) -> Board {
+        Board::from_ascii(&self.rows.join("\n"))
+            .unwrap_or_else(|error| panic!("invalid board rows {:?}: {}", self.rows, error))
+    }
+
+    /// A playing game with the board, current piece and next piece set
+    ///
+    /// Panics if the rows aren't valid `Board::from_ascii` input.
+    pub fn build(self) -> Game {
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        game.board = self.build_board();
+        if let Some((piece_type, position)) = self.current_piece {
+            let mut piece = Tetromino::new(piece_type);
+            piece.position = position;
+            game.current_piece = Some(piece);
+        }
+        if let Some(piece_type) = self.next_piece {
+            game.next_piece = piece_type;
+        }
+        game
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::config::{BOARD_HEIGHT, BOARD_WIDTH, BUFFER_HEIGHT};
+
+    #[test]
+    fn test_built_game_matches_rows_and_piece() {
+        let game = BoardBuilder::new()
+            .rows(&["#.........", "#########."])
+            .current_piece(TetrominoType::I, (4, 10))
+            .next_piece(TetrominoType::O)
+            .build();
+        let bottom = (BOARD_HEIGHT + BUFFER_HEIGHT - 1) as i32;
+
+        assert_eq!(game.state, GameState::Playing);
+        for x in 0..BOARD_WIDTH as i32 {
+            assert_eq!(game.board.get_cell(x, bottom).unwrap().is_filled(), x < 9, "bottom row, column {}", x);
+            assert_eq!(game.board.get_cell(x, bottom - 1).unwrap().is_filled(), x == 0, "second row, column {}", x);
+        }
+        assert_eq!(game.board.filled_cells_count(), 10);
+
+        let piece = game.current_piece.as_ref().unwrap();
+        assert_eq!(piece.piece_type, TetrominoType::I);
+        assert_eq!(piece.position, (4, 10));
+        assert_eq!(piece.absolute_blocks(), vec![(3, 10), (4, 10), (5, 10), (6, 10)]);
+        assert_eq!(game.next_piece, TetrominoType::O);
+    }
+}
@@ -27,10 +27,46 @@ pub const MAX_PIECE_LIFETIME: f64 = 20.0; // Maximum time a piece can exist befo
 pub const INPUT_REPEAT_DELAY: f64 = 0.167; // Initial delay before key repeat
 pub const INPUT_REPEAT_RATE: f64 = 0.033; // Time between repeated inputs
 pub const SOFT_DROP_INTERVAL: f64 = 0.05; // Time between soft drop steps when held
+pub const INSTANT_GRAVITY_INTERVAL: f64 = 0.001; // Drop intervals at or below this drop straight to the floor (20G)
+pub const LINEAR_GRAVITY_START: f64 = 1.0; // Level 1 drop interval of the linear gravity curve
+pub const LINEAR_GRAVITY_STEP: f64 = 0.06; // Seconds the linear gravity curve speeds up by each level
+pub const LINEAR_GRAVITY_MIN: f64 = 0.08; // Fastest drop interval the linear gravity curve reaches
 pub const HORIZONTAL_MOVE_INTERVAL: f64 = 0.16; // Time between horizontal moves when held (reduced sensitivity)
 pub const LINE_CLEAR_ANIMATION_TIME: f64 = 0.5; // Duration of line clearing animation
+pub const REDUCED_LINE_CLEAR_TIME: f64 = 0.1; // Plain flash that stands in for the line clear animation when reduce motion is on
+const _: () = assert!(REDUCED_LINE_CLEAR_TIME < LINE_CLEAR_ANIMATION_TIME); // The reduced flash must be the shorter one
 pub const TETRIS_CELEBRATION_TIME: f64 = 2.0; // Duration of TETRIS celebration message
+pub const PERFECT_CLEAR_CELEBRATION_TIME: f64 = 2.5; // Duration of PERFECT CLEAR celebration message
+pub const LEVEL_UP_BANNER_TIME: f64 = 1.5; // Duration of the LEVEL N banner after a level up
+pub const DROP_INTERVAL_EASE_TIME: f64 = 0.5; // Time gravity takes to reach a new level's speed
+pub const SAVE_TOAST_TIME: f64 = 1.5; // How long the "SAVED" toast stays before it has faded
+pub const DEFAULT_AUTO_SAVE_INTERVAL: f64 = 30.0; // Seconds between auto-saves unless settings say otherwise
 pub const GHOST_THROW_ANIMATION_TIME: f64 = 1.0; // Duration of ghost block throwing animation
+pub const GHOST_BLOCK_EVERY_N_LINES: u32 = 4; // Default lines cleared per ghost block awarded
+pub const GHOST_BLOCK_MAX: u32 = 5; // Default cap on ghost blocks stored at once
+pub const INVISIBLE_FADE_TIME: f64 = 2.0; // Time for locked blocks to fade out in invisible mode
+pub const COMBO_FADE_TIME: f64 = 1.0; // Time for the combo indicator to fade out after a combo ends
+pub const NOTIFICATION_DURATION: f64 = 1.6; // How long a B2B / T-spin / REN callout stays on screen
+pub const MAX_NOTIFICATIONS: usize = 4; // Callouts stacked at once; the oldest drop off first
+pub const NOTIFICATION_MIN_COMBO: u32 = 2; // Shortest combo (clears after the first) called out as REN
+pub const LOCK_FLASH_TIME: f64 = 0.1; // How long just-locked cells flash bright
+pub const HARD_DROP_DOUBLE_TAP_WINDOW: f64 = 0.25; // Default time the confirming second tap of a double-tap hard drop may follow the first
+pub const HARD_DROP_TRAIL_TIME: f64 = 0.15; // How long the afterimage behind a hard drop lingers
+pub const HARD_DROP_SHAKE: (f32, f64) = (3.0, 0.15); // Screen shake (pixels, seconds) for a hard drop
+pub const TETRIS_SHAKE: (f32, f64) = (8.0, 0.4); // Screen shake (pixels, seconds) for a four-line clear
+pub const SPAWN_FADE_TIME: f64 = 0.1; // Fade-in time for a newly spawned piece
+pub const COUNTDOWN_TIME: f64 = 3.0; // "Ready? Go!" countdown before a new game starts
+pub const TOPOUT_ANIMATION_TIME: f64 = 1.0; // How long the stack takes to fill in after a loss
+pub const GO_DISPLAY_TIME: f64 = 0.6; // How long "GO!" stays on screen once play starts
+pub const SCORE_ATTACK_PIECES: u32 = 100; // Pieces provided in score attack mode
+pub const SPRINT_LINES: u32 = 40; // Lines to clear to finish a sprint
+pub const CHEESE_ROWS: u32 = 10; // Garbage rows a cheese game starts with
+pub const REPLAY_SNAPSHOT_INTERVAL: f64 = 0.1; // Seconds between board snapshots kept for the death-cam
+pub const REPLAY_SNAPSHOT_HISTORY: f64 = 10.0; // Seconds of snapshots kept in memory
+pub const DEATH_CAM_SECONDS: f64 = 3.0; // Final seconds replayed on the game over screen
+pub const DANGER_STACK_HEIGHT: usize = 16; // Stack height (of 20 visible rows) above which the danger warning plays
+pub const LINE_HINT_MAX_GAPS: usize = 2; // Rows missing at most this many cells get line hints
+pub const FOCUS_LOSS_FRAME_GAP: f64 = 0.5; // Frame gap treated as focus loss; a heuristic, since any long stall (e.g. a slow load) also trips it
 
 /// Scoring constants
 pub const SCORE_SINGLE_LINE: u32 = 100;
@@ -43,6 +79,7 @@ pub const SCORE_HARD_DROP: u32 = 2;
 /// Level progression
 pub const LINES_PER_LEVEL: u32 = 10;
 pub const LEVEL_SPEED_MULTIPLIER: f64 = 0.85; // Speed increase per level
+pub const MAX_START_LEVEL: u32 = 20; // Highest level selectable from the menu
 
 /// UI Constants
 pub const UI_MARGIN: f32 = 20.0;
@@ -57,15 +94,7 @@ pub const BOARD_HEIGHT_PX: f32 = VISIBLE_HEIGHT as f32 * CELL_SIZE;
 pub const BOARD_OFFSET_X: f32 = (WINDOW_WIDTH as f32 - BOARD_WIDTH_PX) / 2.0;
 pub const BOARD_OFFSET_Y: f32 = (WINDOW_HEIGHT as f32 - BOARD_HEIGHT_PX) / 2.0 + 20.0; // Slightly above center
 
-/// Next piece preview area (with extra spacing for legacy mode consistency)
-pub const PREVIEW_OFFSET_X: f32 = BOARD_OFFSET_X + BOARD_WIDTH_PX + UI_MARGIN + 35.0;
-pub const PREVIEW_OFFSET_Y: f32 = BOARD_OFFSET_Y;
-pub const PREVIEW_SIZE: f32 = 4.0 * CELL_SIZE;
-
-/// Hold piece area
-pub const HOLD_OFFSET_X: f32 = UI_MARGIN;
-pub const HOLD_OFFSET_Y: f32 = BOARD_OFFSET_Y;
-pub const HOLD_SIZE: f32 = 4.0 * CELL_SIZE;
+// Preview, hold and stats panels are placed by `graphics::layout::Layout`
 
 /// Game window title
 pub const WINDOW_TITLE: &str = "Rust Tetris";
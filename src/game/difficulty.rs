@@ -0,0 +1,85 @@
+//! Difficulty presets bundling speed and handling values
+
+use crate::game::config::*;
+use serde::{Serialize, Deserialize};
+
+/// Difficulty preset chosen when a game starts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Difficulty {
+    /// Slower gravity and generous lock delay
+    Relaxed,
+    /// Standard timing
+    #[default]
+    Normal,
+    /// Faster gravity with tighter lock rules
+    Hard,
+    /// Very fast gravity and snappy handling
+    Expert,
+}
+
+impl Difficulty {
+    /// All difficulties from easiest to hardest
+    pub fn all() -> [Difficulty; 4] {
+        [Difficulty::Relaxed, Difficulty::Normal, Difficulty::Hard, Difficulty::Expert]
+    }
+    
+    /// Display name for menus and the leaderboard
+    pub fn name(self) -> &'static str {
+        match self {
+            Difficulty::Relaxed => "RELAXED",
+            Difficulty::Normal => "NORMAL",
+            Difficulty::Hard => "HARD",
+            Difficulty::Expert => "EXPERT",
+        }
+    }
+    
+    /// Multiplier applied to the level drop-interval table (lower is faster)
+    pub fn drop_interval_multiplier(self) -> f64 {
+        match self {
+            Difficulty::Relaxed => 1.5,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 0.75,
+            Difficulty::Expert => 0.5,
+        }
+    }
+    
+    /// Time a grounded piece waits before locking
+    pub fn lock_delay(self) -> f64 {
+        match self {
+            Difficulty::Relaxed => 0.75,
+            Difficulty::Normal => LOCK_DELAY,
+            Difficulty::Hard => 0.4,
+            Difficulty::Expert => 0.3,
+        }
+    }
+    
+    /// Maximum number of lock delay resets per piece
+    pub fn max_lock_resets(self) -> u32 {
+        match self {
+            Difficulty::Relaxed => 20,
+            Difficulty::Normal => MAX_LOCK_RESETS,
+            Difficulty::Hard => 10,
+            Difficulty::Expert => 8,
+        }
+    }
+    
+    /// Delayed auto shift: how long a direction is held before it starts repeating
+    pub fn das(self) -> f64 {
+        match self {
+            Difficulty::Relaxed => 0.2,
+            Difficulty::Normal => HORIZONTAL_MOVE_INTERVAL,
+            Difficulty::Hard => 0.13,
+            Difficulty::Expert => 0.1,
+        }
+    }
+    
+    /// Auto repeat rate: time between repeated moves once DAS has charged
+    pub fn arr(self) -> f64 {
+        match self {
+            Difficulty::Relaxed => HORIZONTAL_MOVE_INTERVAL,
+            Difficulty::Normal => HORIZONTAL_MOVE_INTERVAL,
+            Difficulty::Hard => 0.08,
+            Difficulty::Expert => 0.05,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+//! Things that happen during play, for sound, overlays and other integrations to react to
+
+use crate::game::state::GameOverReason;
+use crate::scoring::LineClearType;
+
+/// Something that happened during play, queued on the game until `Game::poll_events` drains it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GameEvent {
+    /// The countdown reached a new whole second (or began)
+    CountdownTick,
+    /// The countdown ended and play began
+    CountdownGo,
+    /// A piece locked into the stack
+    PieceLocked,
+    /// Full rows started clearing
+    LinesCleared { count: u32, clear_type: LineClearType },
+    /// A clear raised the level
+    LevelUp,
+    /// The game was lost
+    GameOver { reason: GameOverReason },
+    /// A clear emptied the board
+    PerfectClear,
+    /// A thrown ghost block landed in the stack
+    GhostBlockUsed,
+    /// A clear extended the combo to `combo` (1 for the second clear in a row)
+    ComboExtended { combo: u32 },
+    /// A placement without a clear ended a combo
+    ComboBroken,
+    /// The mode's goal was reached
+    Finished,
+}
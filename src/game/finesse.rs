@@ -0,0 +1,100 @@
+//! Finesse checking: the fewest inputs needed to reach a placement
+
+use crate::board::Board;
+use crate::tetromino::Tetromino;
+use std::collections::{HashSet, VecDeque};
+
+/// Shape and columns of a placement, ignoring how far down it landed
+fn placement_footprint(piece: &Tetromino) -> Vec<(i32, i32)> {
+    let blocks = piece.absolute_blocks();
+    let top = blocks.iter().map(|(_, y)| *y).min().unwrap_or(0);
+    let mut footprint: Vec<(i32, i32)> = blocks.iter().map(|(x, y)| (*x, y - top)).collect();
+    footprint.sort_unstable();
+    footprint
+}
+
+fn fits(board: &Board, piece: &Tetromino) -> bool {
+    piece.absolute_blocks().iter().all(|(x, y)| board.is_position_valid(*x, *y))
+}
+
+/// Fewest inputs that take a freshly spawned piece to the column and orientation of `placed`
+///
+/// Each tap left/right, each rotation, and each DAS charge to a wall counts as one input.
+/// Moves are searched at spawn height, so placements that need a tuck or spin return `None`.
+pub fn minimum_inputs(board: &Board, placed: &Tetromino) -> Option<u32> {
+    let target = placement_footprint(placed);
+    let start = Tetromino::new_with_rotation_system(placed.piece_type, placed.rotation_system);
+
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::new();
+    seen.insert((start.position.0, start.rotation));
+    queue.push_back((start, 0));
+
+    while let Some((piece, inputs)) = queue.pop_front() {
+        if placement_footprint(&piece) == target {
+            return Some(inputs);
+        }
+
+        let mut next = Vec::new();
+        for dx in [-1, 1] {
+            // Single tap
+            let mut tapped = piece.clone();
+            tapped.move_by(dx, 0);
+            if fits(board, &tapped) {
+                // Holding the direction slides until something blocks it
+                let mut charged = tapped.clone();
+                loop {
+                    let mut further = charged.clone();
+                    further.move_by(dx, 0);
+                    if !fits(board, &further) {
+                        break;
+                    }
+                    charged = further;
+                }
+                next.push(tapped);
+                next.push(charged);
+            }
+        }
+
+        let mut clockwise = piece.clone();
+        clockwise.rotate_clockwise();
+        let mut counterclockwise = piece.clone();
+        counterclockwise.rotate_counterclockwise();
+        next.extend([clockwise, counterclockwise].into_iter().filter(|rotated| fits(board, rotated)));
+
+        for candidate in next {
+            if seen.insert((candidate.position.0, candidate.rotation)) {
+                queue.push_back((candidate, inputs + 1));
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tetromino::TetrominoType;
+
+    #[test]
+    fn test_i_piece_to_far_wall_is_one_input() {
+        let board = Board::new();
+
+        let spawned = Tetromino::new(TetrominoType::I);
+        assert_eq!(minimum_inputs(&board, &spawned), Some(0));
+
+        // Flat I-piece resting against the left wall, lower down the board
+        let mut at_wall = spawned.clone();
+        let (left_edge, _) = at_wall.occupied_columns();
+        at_wall.move_by(-left_edge, 15);
+        assert_eq!(minimum_inputs(&board, &at_wall), Some(1));
+
+        // Standing up against the wall adds a rotation
+        let mut vertical = Tetromino::new(TetrominoType::I);
+        vertical.rotate_clockwise();
+        let (left_edge, _) = vertical.occupied_columns();
+        vertical.move_by(-left_edge, 10);
+        assert_eq!(minimum_inputs(&board, &vertical), Some(2));
+    }
+}
@@ -0,0 +1,31 @@
+//! How the landing preview of the falling piece is shown
+
+use serde::{Serialize, Deserialize};
+
+/// Look of the ghost piece
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum GhostStyle {
+    /// Bordered, lightly filled copy of the piece
+    #[default]
+    Full,
+    /// Thin outline only
+    Outline,
+    /// Not drawn at all
+    Off,
+}
+
+impl GhostStyle {
+    /// All styles in settings order
+    pub fn all() -> Vec<GhostStyle> {
+        vec![GhostStyle::Full, GhostStyle::Outline, GhostStyle::Off]
+    }
+
+    /// Display name for menus
+    pub fn name(self) -> &'static str {
+        match self {
+            GhostStyle::Full => "FULL",
+            GhostStyle::Outline => "OUTLINE",
+            GhostStyle::Off => "OFF",
+        }
+    }
+}
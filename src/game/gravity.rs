@@ -0,0 +1,120 @@
+//! How fast pieces fall at each level
+
+use crate::game::config::{LINEAR_GRAVITY_MIN, LINEAR_GRAVITY_START, LINEAR_GRAVITY_STEP};
+use serde::{Serialize, Deserialize};
+
+/// Frames per row on the NES at levels 0-29 and beyond (the last entry repeats)
+const NES_FRAMES_PER_ROW: [u32; 30] = [
+    48, 43, 38, 33, 28, 23, 18, 13, 8, 6,
+    5, 5, 5, 4, 4, 4, 3, 3, 3, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 1,
+];
+
+/// NES frame rate, for turning frame counts into seconds
+const NES_FRAME_RATE: f64 = 60.0988;
+
+/// Level past which the guideline formula stops speeding up (it is already near 20G)
+const GUIDELINE_MAX_LEVEL: u32 = 20;
+
+/// Seconds per row as a function of level, before the difficulty multiplier
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum GravityCurve {
+    /// The game's own gentle table, bottoming out at 80ms from level 16
+    #[default]
+    Table,
+    /// Guideline formula `(0.8 - (level - 1) * 0.007) ^ (level - 1)`
+    Guideline,
+    /// The NES frame table, level 1 here being NES level 0
+    Nes,
+    /// Starts at `start` seconds and drops `step` seconds a level, never below `min`
+    Linear { start: f64, step: f64, min: f64 },
+}
+
+impl GravityCurve {
+    /// All curves in settings order, the linear one with its default shape
+    pub fn all() -> Vec<GravityCurve> {
+        vec![GravityCurve::Table, GravityCurve::Guideline, GravityCurve::Nes, GravityCurve::linear()]
+    }
+
+    /// The linear curve with its default shape
+    pub fn linear() -> GravityCurve {
+        GravityCurve::Linear { start: LINEAR_GRAVITY_START, step: LINEAR_GRAVITY_STEP, min: LINEAR_GRAVITY_MIN }
+    }
+
+    /// Display name for menus
+    pub fn name(self) -> &'static str {
+        match self {
+            GravityCurve::Table => "CLASSIC TABLE",
+            GravityCurve::Guideline => "GUIDELINE",
+            GravityCurve::Nes => "NES",
+            GravityCurve::Linear { .. } => "LINEAR",
+        }
+    }
+
+    /// Seconds between drops at `level` (1-based)
+    pub fn drop_interval(self, level: u32) -> f64 {
+        let level = level.max(1);
+        match self {
+            GravityCurve::Table => match level {
+                1 => 1.0,      // 1 second (slow start)
+                2 => 0.85,     // 850ms
+                3 => 0.72,     // 720ms
+                4 => 0.61,     // 610ms
+                5 => 0.52,     // 520ms
+                6 => 0.44,     // 440ms
+                7 => 0.37,     // 370ms
+                8 => 0.31,     // 310ms
+                9 => 0.26,     // 260ms
+                10 => 0.22,    // 220ms
+                11 => 0.19,    // 190ms
+                12 => 0.16,    // 160ms
+                13 => 0.13,    // 130ms
+                14 => 0.11,    // 110ms
+                15 => 0.09,    // 90ms
+                _ => 0.08,     // 80ms minimum (very fast but still playable)
+            },
+            GravityCurve::Guideline => {
+                let n = (level.min(GUIDELINE_MAX_LEVEL) - 1) as f64;
+                (0.8 - n * 0.007).powf(n)
+            },
+            GravityCurve::Nes => {
+                let index = ((level - 1) as usize).min(NES_FRAMES_PER_ROW.len() - 1);
+                NES_FRAMES_PER_ROW[index] as f64 / NES_FRAME_RATE
+            },
+            GravityCurve::Linear { start, step, min } => (start - (level - 1) as f64 * step).max(min),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guideline_curve_starts_at_a_second_and_speeds_up() {
+        assert!((GravityCurve::Guideline.drop_interval(1) - 1.0).abs() < 1e-9);
+        let level_15 = GravityCurve::Guideline.drop_interval(15);
+        assert!((level_15 - 0.702_f64.powi(14)).abs() < 1e-9, "{}", level_15);
+        assert!(level_15 < 0.01);
+        assert!(level_15 > GravityCurve::Guideline.drop_interval(16));
+    }
+
+    #[test]
+    fn test_curves_differ_at_the_same_level() {
+        let level = 10;
+        let intervals: Vec<f64> = GravityCurve::all().into_iter().map(|curve| curve.drop_interval(level)).collect();
+        for (i, a) in intervals.iter().enumerate() {
+            for b in &intervals[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+
+        assert_eq!(GravityCurve::Table.drop_interval(1), 1.0);
+        assert_eq!(GravityCurve::Table.drop_interval(40), 0.08);
+        assert!((GravityCurve::Nes.drop_interval(1) - 48.0 / NES_FRAME_RATE).abs() < 1e-9);
+        assert!((GravityCurve::Nes.drop_interval(99) - 1.0 / NES_FRAME_RATE).abs() < 1e-9);
+        let linear = GravityCurve::Linear { start: 1.0, step: 0.1, min: 0.25 };
+        assert!((linear.drop_interval(3) - 0.8).abs() < 1e-9);
+        assert_eq!(linear.drop_interval(50), 0.25);
+    }
+}
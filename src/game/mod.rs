@@ -1,9 +1,35 @@
 //! Game module containing core game logic and state management
 
+#[cfg(test)]
+pub(crate) mod board_builder;
 pub mod config;
+pub mod difficulty;
+pub mod event;
+pub mod finesse;
+pub mod ghost_style;
+pub mod gravity;
+pub mod mode;
+pub mod notification;
+pub mod replay;
+pub mod save;
+pub mod screen_shake;
 pub mod state;
+pub mod summary;
+pub mod template;
+pub mod time_format;
 
 #[cfg(test)]
 mod movement_tests;
 
-pub use state::{Game, GameState};
+pub use difficulty::Difficulty;
+pub use event::GameEvent;
+pub use ghost_style::GhostStyle;
+pub use gravity::GravityCurve;
+pub use mode::GameMode;
+pub use notification::{Notification, NotificationKind};
+pub use replay::{Replay, ReplaySnapshot};
+pub use save::{SaveMeta, SaveResult, SAVE_SLOT_COUNT};
+pub use state::{Game, GameOverReason, GameState, TopOutBehavior};
+pub use summary::{GameSummary, LineClearEvent};
+pub use template::TemplateOverlay;
+pub use time_format::{format_game_time, TimeFormat};
@@ -0,0 +1,94 @@
+//! Game mode definitions
+
+use crate::game::config::CHEESE_ROWS;
+use crate::game::gravity::GravityCurve;
+use crate::rotation::RotationSystemKind;
+use serde::{Serialize, Deserialize};
+
+/// Rule variants a game can be played under
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum GameMode {
+    /// Standard endless play
+    #[default]
+    Marathon,
+    /// Locked blocks fade out after placement; collision is unaffected
+    Invisible,
+    /// A fixed number of pieces to score as much as possible with
+    ScoreAttack,
+    /// Old-school rules without the hold queue
+    Classic,
+    /// Clear a fixed number of lines as fast as possible
+    Sprint,
+    /// Dig through garbage rows that each have a single hole
+    Cheese {
+        /// Garbage rows the board starts with
+        rows: u32,
+    },
+    /// Cheese practice: every cleared cheese row is replaced by a new one underneath
+    CheeseDrill {
+        /// Garbage rows kept on the board
+        rows: u32,
+    },
+    /// Every piece is flipped left to right as it spawns
+    Mirror,
+}
+
+impl GameMode {
+    /// All selectable modes in menu order
+    pub fn all() -> Vec<GameMode> {
+        vec![GameMode::Marathon, GameMode::Invisible, GameMode::ScoreAttack, GameMode::Classic, GameMode::Sprint, GameMode::Cheese { rows: CHEESE_ROWS }, GameMode::CheeseDrill { rows: CHEESE_ROWS }, GameMode::Mirror]
+    }
+    
+    /// Display name for menus and the HUD
+    pub fn name(self) -> &'static str {
+        match self {
+            GameMode::Marathon => "MARATHON",
+            GameMode::Invisible => "INVISIBLE",
+            GameMode::ScoreAttack => "SCORE ATTACK",
+            GameMode::Classic => "CLASSIC",
+            GameMode::Sprint => "SPRINT",
+            GameMode::Cheese { .. } => "CHEESE",
+            GameMode::CheeseDrill { .. } => "CHEESE DRILL",
+            GameMode::Mirror => "MIRROR",
+        }
+    }
+    
+    /// Cheese rows the board starts with, or `None` for modes without cheese
+    pub fn cheese_rows(self) -> Option<u32> {
+        match self {
+            GameMode::Cheese { rows } | GameMode::CheeseDrill { rows } => Some(rows),
+            _ => None,
+        }
+    }
+    
+    /// Whether cleared cheese rows are replaced so the board never runs out
+    pub fn refills_cheese(self) -> bool {
+        matches!(self, GameMode::CheeseDrill { .. })
+    }
+    
+    /// Whether pieces are mirrored as they spawn
+    pub fn mirrors_pieces(self) -> bool {
+        self == GameMode::Mirror
+    }
+    
+    /// Whether this ruleset allows the hold queue
+    pub fn hold_enabled(self) -> bool {
+        self != GameMode::Classic
+    }
+    
+    /// Gravity curve this ruleset insists on, or `None` to use the player's choice
+    pub fn gravity_curve(self) -> Option<GravityCurve> {
+        match self {
+            GameMode::Classic => Some(GravityCurve::Nes),
+            _ => None,
+        }
+    }
+    
+    /// Rotation system this ruleset plays with
+    pub fn rotation_system(self) -> RotationSystemKind {
+        match self {
+            GameMode::Classic => RotationSystemKind::Ars,
+            _ => RotationSystemKind::Srs,
+        }
+    }
+}
@@ -2,6 +2,7 @@
 //! These tests are designed to prevent the locking bugs identified in the game
 
 use super::*;
+use crate::game::board_builder::BoardBuilder;
 use crate::tetromino::{Tetromino, TetrominoType};
 use crate::board::Cell;
 use crate::game::config::*;
@@ -13,6 +14,7 @@ mod movement_tests {
     /// Helper function to create a game with a specific piece type
     fn create_game_with_piece(piece_type: TetrominoType) -> Game {
         let mut game = Game::new();
+        game.state = GameState::Playing; // Skip the start countdown
         game.current_piece = Some(Tetromino::new(piece_type));
         game.next_piece = TetrominoType::I; // Set predictable next piece
         game
@@ -26,7 +28,7 @@ mod movement_tests {
                 game.board.set_cell(
                     x as i32, 
                     (board_bottom - y) as i32, 
-                    Cell::Filled(macroquad::prelude::RED)
+                    Cell::block(macroquad::prelude::RED)
                 );
             }
         }
@@ -34,19 +36,13 @@ mod movement_tests {
 
     #[test]
     fn test_piece_continues_falling_after_line_clear() {
-        let mut game = create_game_with_piece(TetrominoType::I);
-        
-        // Create a setup where line clearing will happen
         // Fill bottom row except for one space, then place I-piece above
         let board_bottom = BOARD_HEIGHT + BUFFER_HEIGHT - 1;
-        for x in 0..(BOARD_WIDTH - 1) {
-            game.board.set_cell(x as i32, board_bottom as i32, Cell::Filled(macroquad::prelude::RED));
-        }
-        
-        // Position I-piece above the incomplete line
-        if let Some(ref mut piece) = game.current_piece {
-            piece.position = (((BOARD_WIDTH - 1) as i32), (board_bottom - 5) as i32);
-        }
+        let mut game = BoardBuilder::new()
+            .rows(&["#########."])
+            .current_piece(TetrominoType::I, ((BOARD_WIDTH - 1) as i32, (board_bottom - 5) as i32))
+            .next_piece(TetrominoType::I)
+            .build();
         
         let original_y = game.current_piece.as_ref().unwrap().position.1;
         
@@ -115,18 +111,13 @@ mod movement_tests {
 
     #[test]
     fn test_horizontal_movement_doesnt_cause_premature_lock() {
-        let mut game = create_game_with_piece(TetrominoType::I);
-        
-        // Create a partial surface with a gap for the piece to fall into
+        // A partial surface with a gap for the piece to fall into, and the piece above it
         let board_bottom = BOARD_HEIGHT + BUFFER_HEIGHT - 1;
-        for x in 0..(BOARD_WIDTH - 1) {
-            game.board.set_cell(x as i32, board_bottom as i32, Cell::Filled(macroquad::prelude::RED));
-        }
-        
-        // Position piece above the gap
-        if let Some(ref mut piece) = game.current_piece {
-            piece.position = (2, (board_bottom - 8) as i32);
-        }
+        let mut game = BoardBuilder::new()
+            .rows(&["#########."])
+            .current_piece(TetrominoType::I, (2, (board_bottom - 8) as i32))
+            .next_piece(TetrominoType::I)
+            .build();
         
         let initial_y = game.current_piece.as_ref().unwrap().position.1;
         
@@ -256,7 +247,7 @@ mod movement_tests {
         let board_bottom = BOARD_HEIGHT + BUFFER_HEIGHT - 1;
         for x in 0..BOARD_WIDTH {
             if x != BOARD_WIDTH / 2 { // Leave center empty
-                game.board.set_cell(x as i32, board_bottom as i32, Cell::Filled(macroquad::prelude::RED));
+                game.board.set_cell(x as i32, board_bottom as i32, Cell::block(macroquad::prelude::RED));
             }
         }
         
@@ -308,7 +299,7 @@ mod movement_tests {
         // Create a surface with gaps
         let board_bottom = BOARD_HEIGHT + BUFFER_HEIGHT - 1;
         for x in (0..BOARD_WIDTH).step_by(2) {
-            game.board.set_cell(x as i32, board_bottom as i32, Cell::Filled(macroquad::prelude::RED));
+            game.board.set_cell(x as i32, board_bottom as i32, Cell::block(macroquad::prelude::RED));
         }
         
         // Position piece above a gap
@@ -344,7 +335,7 @@ mod movement_tests {
         // Create a vertical wall on the right side
         let board_bottom = BOARD_HEIGHT + BUFFER_HEIGHT - 1;
         for y in 0..10 {
-            game.board.set_cell((BOARD_WIDTH - 1) as i32, (board_bottom - y) as i32, Cell::Filled(macroquad::prelude::RED));
+            game.board.set_cell((BOARD_WIDTH - 1) as i32, (board_bottom - y) as i32, Cell::block(macroquad::prelude::RED));
         }
         
         // Position I-piece next to the wall but with space below
@@ -386,9 +377,9 @@ mod movement_tests {
         // Create walls with gap in middle
         for y in 0..5 {
             // Left wall
-            game.board.set_cell(1, (board_bottom - y) as i32, Cell::Filled(macroquad::prelude::RED));
+            game.board.set_cell(1, (board_bottom - y) as i32, Cell::block(macroquad::prelude::RED));
             // Right wall  
-            game.board.set_cell((BOARD_WIDTH - 2) as i32, (board_bottom - y) as i32, Cell::Filled(macroquad::prelude::RED));
+            game.board.set_cell((BOARD_WIDTH - 2) as i32, (board_bottom - y) as i32, Cell::block(macroquad::prelude::RED));
         }
         
         // Position piece in the middle, high up
@@ -422,7 +413,7 @@ mod movement_tests {
         // Create a surface for the piece to eventually land on
         let board_bottom = BOARD_HEIGHT + BUFFER_HEIGHT - 1;
         for x in 0..BOARD_WIDTH {
-            game.board.set_cell(x as i32, (board_bottom - 2) as i32, Cell::Filled(macroquad::prelude::RED));
+            game.board.set_cell(x as i32, (board_bottom - 2) as i32, Cell::block(macroquad::prelude::RED));
         }
         
         // Position I-piece several rows above the surface
@@ -486,7 +477,7 @@ mod movement_tests {
         
         // Fill only the left side of a row, leaving the right side empty
         for x in 0..(BOARD_WIDTH / 2) {
-            game.board.set_cell(x as i32, surface_y as i32, Cell::Filled(macroquad::prelude::RED));
+            game.board.set_cell(x as i32, surface_y as i32, Cell::block(macroquad::prelude::RED));
         }
         
         // Position T-piece so it will land on the filled part (left side)
@@ -559,7 +550,7 @@ mod movement_tests {
         let ledge_y = board_bottom - 3;
         
         // Create a single-block platform for the T-piece to initially rest on partially
-        game.board.set_cell(4, ledge_y as i32, Cell::Filled(macroquad::prelude::BLUE));
+        game.board.set_cell(4, ledge_y as i32, Cell::block(macroquad::prelude::BLUE));
         
         // Position T-piece to land mostly on this single block
         if let Some(ref mut piece) = game.current_piece {
@@ -0,0 +1,84 @@
+//! Short-lived callouts for big clears, shown stacked beside the board
+
+use crate::game::config::{NOTIFICATION_DURATION, NOTIFICATION_MIN_COMBO};
+use crate::scoring::LineClearType;
+
+/// What a callout is celebrating, which decides how it is drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationKind {
+    /// A Tetris or T-spin on its own
+    Clear,
+    /// A Tetris or T-spin continuing a back-to-back chain
+    BackToBack,
+    /// A combo of consecutive clears
+    Combo,
+}
+
+/// One callout, such as "B2B TETRIS" or "4 REN"
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    /// What the callout is for
+    pub kind: NotificationKind,
+    /// What the callout says
+    pub text: String,
+    /// Seconds since it appeared
+    pub age: f64,
+}
+
+impl Notification {
+    /// A callout that has just appeared
+    pub fn new(kind: NotificationKind, text: impl Into<String>) -> Self {
+        Self { kind, text: text.into(), age: 0.0 }
+    }
+
+    /// How far through its lifetime the callout is, from 0.0 to 1.0
+    pub fn progress(&self) -> f64 {
+        (self.age / NOTIFICATION_DURATION).min(1.0)
+    }
+
+    /// Whether the callout has run its course
+    pub fn is_expired(&self) -> bool {
+        self.age >= NOTIFICATION_DURATION
+    }
+}
+
+/// Callouts earned by a line clear
+///
+/// Only difficult clears (Tetrises and T-spins) are called out, prefixed with B2B when they
+/// continue a back-to-back chain. `combo` counts clears after the first in a row, so a
+/// callout like "4 REN" appears once it reaches `NOTIFICATION_MIN_COMBO`.
+pub fn notifications_for(clear_type: LineClearType, back_to_back: bool, combo: u32) -> Vec<Notification> {
+    let mut notifications = Vec::new();
+    if clear_type.is_difficult() {
+        let name = clear_type.name().to_uppercase();
+        notifications.push(if back_to_back {
+            Notification::new(NotificationKind::BackToBack, format!("B2B {}", name))
+        } else {
+            Notification::new(NotificationKind::Clear, name)
+        });
+    }
+    if combo >= NOTIFICATION_MIN_COMBO {
+        notifications.push(Notification::new(NotificationKind::Combo, format!("{} REN", combo)));
+    }
+    notifications
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_notable_clears_are_called_out() {
+        assert!(notifications_for(LineClearType::Double, false, 0).is_empty());
+        assert!(notifications_for(LineClearType::Triple, true, 1).is_empty());
+
+        let texts = |notifications: Vec<Notification>| notifications.into_iter().map(|n| n.text).collect::<Vec<_>>();
+        assert_eq!(texts(notifications_for(LineClearType::TSpinDouble, false, 0)), vec!["T-SPIN DOUBLE"]);
+        assert_eq!(texts(notifications_for(LineClearType::Tetris, true, 4)), vec!["B2B TETRIS", "4 REN"]);
+        assert_eq!(texts(notifications_for(LineClearType::Single, false, NOTIFICATION_MIN_COMBO)), vec![format!("{} REN", NOTIFICATION_MIN_COMBO)]);
+
+        let kinds = |notifications: Vec<Notification>| notifications.into_iter().map(|n| n.kind).collect::<Vec<_>>();
+        assert_eq!(kinds(notifications_for(LineClearType::Tetris, true, 4)), vec![NotificationKind::BackToBack, NotificationKind::Combo]);
+        assert_eq!(kinds(notifications_for(LineClearType::TSpinSingle, false, 0)), vec![NotificationKind::Clear]);
+    }
+}
@@ -0,0 +1,213 @@
+//! Recorded runs, used to race against a previous best and to show a run's final moments
+
+use crate::board::Board;
+use crate::game::config::{REPLAY_SNAPSHOT_HISTORY, REPLAY_SNAPSHOT_INTERVAL};
+use crate::game::mode::GameMode;
+use crate::tetromino::Tetromino;
+use serde::{Serialize, Deserialize};
+use std::collections::VecDeque;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The board and falling piece at one moment of a run
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplaySnapshot {
+    /// Game time the snapshot was taken at
+    pub time: f64,
+    /// Locked cells at that moment
+    pub board: Board,
+    /// Piece that was falling, if any
+    pub piece: Option<Tetromino>,
+}
+
+/// Progress recorded over a run: when each line was cleared
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Replay {
+    /// Mode the run was played in
+    pub mode: GameMode,
+    /// Game time at which each line was cleared, in order (a Tetris adds four equal times)
+    pub line_clear_times: Vec<f64>,
+    /// Game time the run finished at, if it reached its goal
+    #[serde(default)]
+    pub finish_time: Option<f64>,
+    /// Periodic snapshots of the last `REPLAY_SNAPSHOT_HISTORY` seconds, oldest first
+    ///
+    /// Only kept in memory: they exist to replay the end of the current run, not to be saved.
+    #[serde(skip)]
+    pub snapshots: VecDeque<ReplaySnapshot>,
+}
+
+impl Replay {
+    /// Start an empty recording for a mode
+    pub fn new(mode: GameMode) -> Self {
+        Self {
+            mode,
+            ..Self::default()
+        }
+    }
+
+    /// Record `lines` lines cleared at game time `time`
+    pub fn record_lines(&mut self, time: f64, lines: u32) {
+        self.line_clear_times.extend(std::iter::repeat_n(time, lines as usize));
+    }
+
+    /// Take a snapshot if `REPLAY_SNAPSHOT_INTERVAL` has passed since the last one
+    pub fn record_snapshot(&mut self, time: f64, board: &Board, piece: Option<&Tetromino>) {
+        let due = self.snapshots.back().is_none_or(|last| time - last.time >= REPLAY_SNAPSHOT_INTERVAL);
+        if due {
+            self.push_snapshot(time, board, piece);
+        }
+    }
+
+    /// Take a snapshot now, regardless of when the last one was taken
+    pub fn push_snapshot(&mut self, time: f64, board: &Board, piece: Option<&Tetromino>) {
+        self.snapshots.push_back(ReplaySnapshot {
+            time,
+            board: board.clone(),
+            piece: piece.cloned(),
+        });
+        while self.snapshots.front().is_some_and(|first| time - first.time > REPLAY_SNAPSHOT_HISTORY) {
+            self.snapshots.pop_front();
+        }
+    }
+
+    /// Latest game time anything was recorded at
+    pub fn last_time(&self) -> f64 {
+        let last_clear = self.line_clear_times.last().copied().unwrap_or(0.0);
+        let last_snapshot = self.snapshots.back().map_or(0.0, |snapshot| snapshot.time);
+        last_clear.max(last_snapshot).max(self.finish_time.unwrap_or(0.0))
+    }
+
+    /// The part of the run within the last `seconds` of recorded time
+    ///
+    /// Snapshots are only kept for `REPLAY_SNAPSHOT_HISTORY` seconds, so longer tails start
+    /// at the oldest snapshot still held.
+    pub fn tail(&self, seconds: f64) -> Replay {
+        let start = self.last_time() - seconds;
+        Replay {
+            mode: self.mode,
+            line_clear_times: self.line_clear_times.iter().copied().filter(|&time| time >= start).collect(),
+            finish_time: self.finish_time.filter(|&time| time >= start),
+            snapshots: self.snapshots.iter().filter(|snapshot| snapshot.time >= start).cloned().collect(),
+        }
+    }
+
+    /// Game time of the first snapshot held
+    pub fn start_time(&self) -> Option<f64> {
+        self.snapshots.front().map(|snapshot| snapshot.time)
+    }
+
+    /// Seek to `time`: the latest snapshot taken at or before it, or the first one if `time`
+    /// is before them all
+    pub fn snapshot_at(&self, time: f64) -> Option<&ReplaySnapshot> {
+        let after = self.snapshots.partition_point(|snapshot| snapshot.time <= time);
+        self.snapshots.get(after.saturating_sub(1))
+    }
+
+    /// Lines the run had cleared by game time `time`
+    ///
+    /// Progress is a step function: a clear counts from the moment it happened, with no
+    /// interpolation between clears.
+    pub fn lines_at_time(&self, time: f64) -> u32 {
+        // Times are recorded in order, so everything before the partition point has happened
+        self.line_clear_times.partition_point(|&cleared_at| cleared_at <= time) as u32
+    }
+
+    /// Total lines the run cleared
+    pub fn total_lines(&self) -> u32 {
+        self.line_clear_times.len() as u32
+    }
+
+    /// Whether this finished run beat another (or there is nothing to beat)
+    pub fn is_better_than(&self, other: Option<&Replay>) -> bool {
+        match (self.finish_time, other.and_then(|other| other.finish_time)) {
+            (Some(time), Some(best)) => time < best,
+            (Some(_), None) => true,
+            (None, _) => false,
+        }
+    }
+
+    /// Where the best Sprint run is kept
+    pub fn best_sprint_path() -> PathBuf {
+        std::env::current_dir()
+            .unwrap_or_else(|_| PathBuf::from("."))
+            .join("tetris_sprint_best.json")
+    }
+
+    /// Save the replay to a file
+    pub fn save_to_file<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn std::error::Error>> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json)?;
+        log::info!("Replay saved successfully");
+        Ok(())
+    }
+
+    /// Load a replay from a file
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        let json = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines_at_time_steps_at_each_clear() {
+        let mut replay = Replay::new(GameMode::Sprint);
+        replay.record_lines(2.0, 1);
+        replay.record_lines(5.0, 4);
+
+        assert_eq!(replay.lines_at_time(0.0), 0);
+        assert_eq!(replay.lines_at_time(1.99), 0);
+        // A clear counts from the exact moment it happened
+        assert_eq!(replay.lines_at_time(2.0), 1);
+        // No interpolation towards the next clear
+        assert_eq!(replay.lines_at_time(4.9), 1);
+        assert_eq!(replay.lines_at_time(5.0), 5);
+        assert_eq!(replay.lines_at_time(100.0), 5);
+        assert_eq!(replay.total_lines(), 5);
+    }
+
+    #[test]
+    fn test_tail_keeps_only_the_last_seconds() {
+        let mut replay = Replay::new(GameMode::Marathon);
+        let board = Board::new();
+        for step in 0..=20 {
+            let time = step as f64 * 0.25;
+            replay.record_snapshot(time, &board, None);
+            // Too soon after the last snapshot to take another
+            replay.record_snapshot(time + 0.05, &board, None);
+        }
+        replay.record_lines(1.0, 2);
+        replay.record_lines(3.5, 1);
+        assert_eq!(replay.snapshots.len(), 21);
+        assert_eq!(replay.last_time(), 5.0);
+
+        let tail = replay.tail(2.0);
+        assert!(tail.snapshots.iter().all(|snapshot| snapshot.time >= 3.0 && snapshot.time <= 5.0));
+        assert_eq!(tail.start_time(), Some(3.0));
+        assert_eq!(tail.line_clear_times, vec![3.5]);
+
+        // Seeking lands on the snapshot at or before the requested time
+        assert_eq!(tail.snapshots.len(), 9);
+        assert_eq!(tail.snapshot_at(3.4).unwrap().time, 3.25);
+        assert_eq!(tail.snapshot_at(0.0).unwrap().time, 3.0);
+        assert_eq!(tail.snapshot_at(9.0).unwrap().time, 5.0);
+    }
+
+    #[test]
+    fn test_faster_finish_is_better() {
+        let mut best = Replay::new(GameMode::Sprint);
+        best.finish_time = Some(80.0);
+        let mut run = Replay::new(GameMode::Sprint);
+        assert!(!run.is_better_than(Some(&best)));
+
+        run.finish_time = Some(75.0);
+        assert!(run.is_better_than(Some(&best)));
+        assert!(run.is_better_than(None));
+        best.finish_time = Some(70.0);
+        assert!(!run.is_better_than(Some(&best)));
+    }
+}
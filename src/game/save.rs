@@ -0,0 +1,264 @@
+//! Save file versioning and migration
+//!
+//! Saves are plain serialized `Game`s tagged with a `version`. Loading goes through a
+//! `serde_json::Value` first so older layouts can be upgraded before deserializing.
+
+use crate::board::board::default_lines_per_level;
+use crate::tetromino::TetrominoType;
+use chrono::{DateTime, Local};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::fs;
+use std::path::Path;
+
+/// Current save format version; bump it and add a migration step whenever the layout changes
+pub const SAVE_VERSION: u32 = 6;
+
+/// Number of save slots offered on the continue screen
+pub const SAVE_SLOT_COUNT: usize = 3;
+
+/// Summary of a save shown on the slot selection screen
+#[derive(Debug, Clone, PartialEq)]
+pub struct SaveMeta {
+    /// Score at the time of saving
+    pub score: u32,
+    /// Effective level at the time of saving
+    pub level: u32,
+    /// Lines cleared at the time of saving
+    pub lines_cleared: u32,
+    /// When the save file was last written
+    pub saved_at: Option<DateTime<Local>>,
+}
+
+/// Outcome of the most recent save, kept so the UI can flash a toast
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SaveResult {
+    /// Nothing saved yet this session
+    #[default]
+    None,
+    /// Saved successfully at this game time
+    Saved(f64),
+    /// Saving failed at this game time
+    Failed(f64),
+}
+
+impl SaveResult {
+    /// Game time the save was attempted, if any
+    pub fn time(self) -> Option<f64> {
+        match self {
+            SaveResult::None => None,
+            SaveResult::Saved(time) | SaveResult::Failed(time) => Some(time),
+        }
+    }
+}
+
+/// The few save fields the slot screen needs; everything else in the file is skipped
+#[derive(Deserialize)]
+struct SaveHeader {
+    #[serde(default)]
+    score: u32,
+    #[serde(default)]
+    start_level: Option<u32>,
+    #[serde(default)]
+    max_level: Option<u32>,
+    board: BoardHeader,
+}
+
+#[derive(Deserialize)]
+struct BoardHeader {
+    #[serde(default)]
+    lines_cleared: u32,
+    #[serde(default = "default_lines_per_level")]
+    lines_per_level: u32,
+}
+
+/// Read a save's summary without building a full `Game`
+pub fn read_metadata(path: &Path) -> Option<SaveMeta> {
+    let json = fs::read_to_string(path).ok()?;
+    let header: SaveHeader = serde_json::from_str(&json).ok()?;
+    let saved_at = fs::metadata(path).and_then(|metadata| metadata.modified()).ok().map(DateTime::<Local>::from);
+
+    Some(SaveMeta {
+        score: header.score,
+        level: super::state::effective_level(header.start_level.unwrap_or(1), header.board.lines_cleared, header.board.lines_per_level, header.max_level),
+        lines_cleared: header.board.lines_cleared,
+        saved_at,
+    })
+}
+
+/// Version assumed for saves written before versioning existed
+const UNVERSIONED: u32 = 1;
+
+/// Upgrade a save to the current format, one version at a time
+pub fn migrate(mut save: Value) -> Result<Value, Box<dyn std::error::Error>> {
+    if !save.is_object() {
+        return Err("save file is not a JSON object".into());
+    }
+
+    let mut version = save.get("version")
+        .and_then(Value::as_u64)
+        .map_or(UNVERSIONED, |version| version as u32);
+
+    if version > SAVE_VERSION {
+        return Err(format!("save file version {} is newer than supported version {}", version, SAVE_VERSION).into());
+    }
+
+    while version < SAVE_VERSION {
+        match version {
+            1 => migrate_v1_to_v2(&mut save),
+            2 => migrate_v2_to_v3(&mut save),
+            3 => migrate_v3_to_v4(&mut save),
+            4 => migrate_v4_to_v5(&mut save),
+            5 => migrate_v5_to_v6(&mut save),
+            _ => return Err(format!("no migration from save version {}", version).into()),
+        }
+        version += 1;
+        log::info!("Migrated save file to version {}", version);
+    }
+
+    save["version"] = json!(SAVE_VERSION);
+    Ok(save)
+}
+
+/// v1 saves had no randomizer, or a bare 7-bag under `piece_bag`; fields added since
+/// then are filled in by their serde defaults.
+fn migrate_v1_to_v2(save: &mut Value) {
+    let Some(fields) = save.as_object_mut() else {
+        return;
+    };
+
+    if let Some(bag) = fields.remove("piece_bag") {
+        fields.entry("randomizer").or_insert_with(|| json!({ "SevenBag": bag }));
+    }
+}
+
+/// v2 saves stored the SRS settings struct; v3 stores which rotation system is in use
+fn migrate_v2_to_v3(save: &mut Value) {
+    if let Some(fields) = save.as_object_mut() {
+        if fields.get("rotation_system").is_some_and(Value::is_object) {
+            fields.insert("rotation_system".to_string(), json!("Srs"));
+        }
+    }
+}
+
+/// v3 saves stored the whole board grid and its lock times; v4 stores only the filled cells
+/// as `[x, y, color, lock_time]`
+fn migrate_v3_to_v4(save: &mut Value) {
+    let Some(board) = save.get_mut("board").and_then(Value::as_object_mut) else {
+        return;
+    };
+    let Some(grid) = board.remove("grid") else {
+        return;
+    };
+    let lock_times = board.remove("lock_times").unwrap_or(Value::Null);
+
+    let mut cells = Vec::new();
+    for (y, row) in grid.as_array().into_iter().flatten().enumerate() {
+        for (x, cell) in row.as_array().into_iter().flatten().enumerate() {
+            if let Some(color) = cell.get("Filled") {
+                let lock_time = lock_times[y][x].as_f64().unwrap_or(0.0);
+                cells.push(json!([x, y, color, lock_time]));
+            }
+        }
+    }
+    board.insert("cells".to_string(), Value::Array(cells));
+}
+
+/// v4 saves kept piece rotation as a free number; v5 stores a `RotationState` index (0-3)
+fn migrate_v4_to_v5(save: &mut Value) {
+    if let Some(rotation) = save.pointer_mut("/current_piece/rotation") {
+        if let Some(index) = rotation.as_u64() {
+            *rotation = json!(index % 4);
+        }
+    }
+}
+
+/// v5 cells only carried a color; v6 adds the piece type, `[x, y, color, lock_time, piece]`
+///
+/// Older saves have nothing better to go on than the color, so cells in a stock piece color
+/// become that piece and everything else (garbage, ghost blocks) gets no type.
+fn migrate_v5_to_v6(save: &mut Value) {
+    let Some(cells) = save.pointer_mut("/board/cells").and_then(Value::as_array_mut) else {
+        return;
+    };
+    for cell in cells.iter_mut().filter_map(Value::as_array_mut) {
+        let rgb: Vec<f32> = cell.get(2)
+            .and_then(Value::as_array)
+            .map(|color| color.iter().take(3).filter_map(Value::as_f64).map(|channel| channel as f32).collect())
+            .unwrap_or_default();
+        let piece_type = TetrominoType::all().into_iter().find(|piece_type| {
+            let color = piece_type.color();
+            rgb == [color.r, color.g, color.b]
+        });
+        cell.truncate(4);
+        cell.push(json!(piece_type));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_newer_saves() {
+        let save = json!({ "version": SAVE_VERSION + 1 });
+        assert!(migrate(save).is_err());
+    }
+
+    #[test]
+    fn test_v1_piece_bag_becomes_randomizer() {
+        let save = json!({ "piece_bag": { "seed": 7, "bags_drawn": 1, "queue": ["T"] } });
+        let migrated = migrate(save).unwrap();
+        assert_eq!(migrated["version"], json!(SAVE_VERSION));
+        assert_eq!(migrated["randomizer"]["SevenBag"]["seed"], json!(7));
+        assert!(migrated.get("piece_bag").is_none());
+    }
+
+    #[test]
+    fn test_v2_srs_settings_become_rotation_kind() {
+        let save = json!({ "version": 2, "rotation_system": { "enable_t_spin_detection": true } });
+        let migrated = migrate(save).unwrap();
+        assert_eq!(migrated["rotation_system"], json!("Srs"));
+    }
+
+    #[test]
+    fn test_v3_dense_board_becomes_filled_cells() {
+        let mut grid = vec![vec![json!("Empty"); 2]; 2];
+        grid[1][0] = json!({ "Filled": [1.0, 0.0, 0.0, 1.0] });
+        let save = json!({
+            "version": 3,
+            "board": { "grid": grid, "lock_times": [[0.0, 0.0], [4.5, 0.0]], "lines_cleared": 0, "level": 1 },
+        });
+        let migrated = migrate(save).unwrap();
+        assert_eq!(migrated["board"]["cells"], json!([[0, 1, [1.0, 0.0, 0.0, 1.0], 4.5, "Z"]]));
+        assert!(migrated["board"].get("grid").is_none());
+        assert!(migrated["board"].get("lock_times").is_none());
+    }
+
+    #[test]
+    fn test_v4_rotation_becomes_state_index() {
+        let save = json!({ "version": 4, "current_piece": { "rotation": 6 } });
+        let migrated = migrate(save).unwrap();
+        assert_eq!(migrated["current_piece"]["rotation"], json!(2));
+    }
+
+    #[test]
+    fn test_v5_cells_gain_a_piece_type_from_stock_colors() {
+        let t = TetrominoType::T.color();
+        let garbage = crate::graphics::colors::GARBAGE_COLOR;
+        let save = json!({
+            "version": 5,
+            "board": { "cells": [
+                [0, 1, [t.r, t.g, t.b, t.a], 2.0],
+                [1, 1, [garbage.r, garbage.g, garbage.b, garbage.a], 3.0],
+            ], "lines_cleared": 0, "level": 1 },
+        });
+        let migrated = migrate(save).unwrap();
+        assert_eq!(migrated["board"]["cells"][0][4], json!("T"));
+        assert_eq!(migrated["board"]["cells"][1][4], Value::Null);
+
+        let board: crate::board::Board = serde_json::from_value(migrated["board"].clone()).unwrap();
+        assert_eq!(board.get_cell(0, 1).unwrap().piece_type(), Some(TetrominoType::T));
+        assert_eq!(board.get_cell(1, 1).unwrap().piece_type(), None);
+    }
+}
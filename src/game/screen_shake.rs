@@ -0,0 +1,120 @@
+//! Screen shake for impactful events
+
+use serde::{Serialize, Deserialize};
+
+/// A decaying shake applied to the board when rendering
+///
+/// The jitter direction comes from a hash of the seed and the elapsed time rather than a
+/// global RNG, so a shake replays identically. It is purely cosmetic and never affects play.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScreenShake {
+    /// Peak offset in pixels
+    intensity: f32,
+    /// Total length of the shake in seconds
+    duration: f64,
+    /// Time since the shake started
+    elapsed: f64,
+    /// Seed the jitter directions are derived from
+    seed: u64,
+}
+
+impl ScreenShake {
+    /// Create a shake driver whose jitter is derived from `seed`
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            seed,
+            ..Self::default()
+        }
+    }
+
+    /// Start a shake, unless a stronger one is already running
+    pub fn trigger(&mut self, intensity: f32, duration: f64) {
+        if intensity >= self.strength() && duration > 0.0 {
+            self.intensity = intensity;
+            self.duration = duration;
+            self.elapsed = 0.0;
+        }
+    }
+
+    /// Advance the shake, ending it once its duration has passed
+    pub fn update(&mut self, delta_time: f64) {
+        if !self.is_active() {
+            return;
+        }
+        self.elapsed += delta_time;
+        if self.elapsed >= self.duration {
+            self.intensity = 0.0;
+            self.elapsed = 0.0;
+        }
+    }
+
+    /// Whether a shake is running
+    pub fn is_active(&self) -> bool {
+        self.intensity > 0.0
+    }
+
+    /// Current maximum offset, easing out towards zero
+    pub fn strength(&self) -> f32 {
+        if !self.is_active() {
+            return 0.0;
+        }
+        let remaining = (1.0 - self.elapsed / self.duration).clamp(0.0, 1.0) as f32;
+        self.intensity * remaining * remaining
+    }
+
+    /// Offset to draw the board at this moment
+    pub fn offset(&self) -> (f32, f32) {
+        let strength = self.strength();
+        if strength <= 0.0 {
+            return (0.0, 0.0);
+        }
+
+        // New direction every 1/60s, hashed so it jumps around instead of orbiting
+        let step = (self.elapsed * 60.0) as u64;
+        let hash = splitmix64(self.seed ^ step.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+        let angle = (hash >> 11) as f64 / (1u64 << 53) as f64 * std::f64::consts::TAU;
+        (strength * angle.cos() as f32, strength * angle.sin() as f32)
+    }
+}
+
+/// SplitMix64 finalizer, a cheap well-mixed hash of a single value
+fn splitmix64(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shake_decays_to_zero() {
+        let mut shake = ScreenShake::with_seed(42);
+        assert_eq!(shake.offset(), (0.0, 0.0));
+
+        shake.trigger(8.0, 0.4);
+        let (x, y) = shake.offset();
+        let start = (x * x + y * y).sqrt();
+        assert!((start - 8.0).abs() < 1e-3);
+
+        shake.update(0.2);
+        let (x, y) = shake.offset();
+        let middle = (x * x + y * y).sqrt();
+        assert!(middle > 0.0 && middle < start);
+
+        shake.update(0.25);
+        assert!(!shake.is_active());
+        assert_eq!(shake.offset(), (0.0, 0.0));
+
+        // The same seed shakes the same way
+        let mut again = ScreenShake::with_seed(42);
+        again.trigger(8.0, 0.4);
+        again.update(0.1);
+        let mut replay = ScreenShake::with_seed(42);
+        replay.trigger(8.0, 0.4);
+        replay.update(0.1);
+        assert_eq!(again.offset(), replay.offset());
+    }
+}
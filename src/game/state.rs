@@ -1,28 +1,102 @@
 //! Game state management
 
 use crate::board::{Board, Cell};
-use crate::tetromino::{Tetromino, TetrominoType};
+use crate::input::{HardDropConfirm, HeldInputs, InputAction};
+use crate::tetromino::{Randomizer, Tetromino, TetrominoType};
 use crate::game::config::*;
-use crate::rotation::{SRSRotationSystem, RotationSystem, RotationResult};
-use crate::scoring::{TetrisScoring, ScoringAction, LineClearType, PerfectClearDetector, determine_line_clear_type};
+use crate::game::difficulty::Difficulty;
+use crate::game::event::GameEvent;
+use crate::game::finesse;
+use crate::game::ghost_style::GhostStyle;
+use crate::game::gravity::GravityCurve;
+use crate::game::mode::GameMode;
+use crate::game::notification::{self, Notification};
+use crate::game::replay::{Replay, ReplaySnapshot};
+use crate::game::screen_shake::ScreenShake;
+use crate::game::summary::{GameSummary, LineClearEvent};
+use crate::game::template::TemplateOverlay;
+use crate::game::save::{self, SaveMeta, SaveResult, SAVE_VERSION};
+use crate::rotation::{RotationState, RotationSystem, RotationSystemKind, RotationResult};
+use crate::scoring::{attack_for, TetrisScoring, ScoringAction, LineClearType, PerfectClearDetector, determine_line_clear_type};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 use std::fs;
 use std::path::Path;
 
 /// Game states
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum GameState {
     Menu,
+    /// "Ready? Go!" lead-in before play starts; the board is shown but nothing moves
+    Countdown { remaining: f64 },
     Playing,
     Paused,
+    /// The game was lost and the board is filling in before the game-over screen
+    ToppingOut { timer: f64 },
     GameOver,
+    /// A goal-based mode (line goal or piece limit) was completed - a win, not a loss
+    #[serde(alias = "Victory")]
+    Finished,
+}
+
+impl GameState {
+    /// Whether the game is over, lost or finished, and no longer progresses
+    pub fn has_ended(self) -> bool {
+        matches!(self, GameState::GameOver | GameState::Finished)
+    }
+}
+
+/// Why a game ended, following the guideline's three top-out conditions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GameOverReason {
+    /// A new piece spawned overlapping the stack
+    BlockOut,
+    /// A piece locked entirely above the visible playfield
+    LockOut,
+    /// The stack grew into the spawn area
+    TopOut,
+}
+
+impl GameOverReason {
+    /// Heading shown on the game-over screen
+    pub fn title(self) -> &'static str {
+        match self {
+            GameOverReason::BlockOut => "BLOCK OUT",
+            GameOverReason::LockOut => "LOCK OUT",
+            GameOverReason::TopOut => "TOP OUT",
+        }
+    }
+    
+    /// One-line explanation for the game-over screen
+    pub fn description(self) -> &'static str {
+        match self {
+            GameOverReason::BlockOut => "No room for the next piece to spawn",
+            GameOverReason::LockOut => "A piece locked above the playfield",
+            GameOverReason::TopOut => "The stack reached the spawn area",
+        }
+    }
+}
+
+/// What happens when the stack tops out while an animation is still playing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TopOutBehavior {
+    /// Let the line clear or ghost throw play out, then show game over
+    #[default]
+    FinishAnimation,
+    /// Cut straight to game over
+    Immediate,
 }
 
 /// Main game struct
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Game {
+    /// Save format version (see `game::save`)
+    #[serde(default)]
+    pub version: u32,
     /// Current game state
     pub state: GameState,
     /// The game board
@@ -31,6 +105,12 @@ pub struct Game {
     pub current_piece: Option<Tetromino>,
     /// Next piece to spawn
     pub next_piece: TetrominoType,
+    /// Seeded randomizer that feeds the next piece
+    #[serde(default)]
+    pub randomizer: Randomizer,
+    /// Seed the randomizer started from, so the same piece sequence can be replayed
+    #[serde(default)]
+    pub initial_seed: u64,
     /// Held piece (can be swapped with current piece)
     pub held_piece: Option<TetrominoType>,
     /// Whether hold has been used for the current piece (prevents infinite swapping)
@@ -41,6 +121,21 @@ pub struct Game {
     pub drop_timer: f64,
     /// Time between drops (decreases with level)
     pub drop_interval: f64,
+    /// Drop interval for the current level, which `drop_interval` eases toward after a level up
+    #[serde(default)]
+    pub target_drop_interval: f64,
+    /// Drop interval the current ease started from
+    #[serde(default)]
+    pub drop_interval_ease_start: f64,
+    /// Time into the current ease; at `DROP_INTERVAL_EASE_TIME` or more no ease is running
+    #[serde(default)]
+    pub drop_interval_ease_timer: f64,
+    /// Seconds a newly spawned piece hangs before gravity starts on it (0 = none)
+    #[serde(default)]
+    pub spawn_grace: f64,
+    /// Spawn grace left for the current piece
+    #[serde(default)]
+    pub spawn_grace_remaining: f64,
     /// Game time in seconds
     pub game_time: f64,
     /// Lines being cleared with animation
@@ -56,6 +151,15 @@ pub struct Game {
 
     /// Ghost blocks available for placement
     pub ghost_blocks_available: u32,
+    /// Whether ghost blocks are awarded and usable at all
+    #[serde(default = "default_true")]
+    pub ghost_blocks_enabled: bool,
+    /// Lines cleared per ghost block awarded
+    #[serde(default = "default_ghost_block_every_n_lines")]
+    pub ghost_block_every_n_lines: u32,
+    /// Most ghost blocks that can be stored; awards beyond it are lost
+    #[serde(default = "default_ghost_block_max")]
+    pub ghost_block_max: u32,
     /// Ghost block placement mode active
     pub ghost_block_placement_mode: bool,
     /// Ghost block cursor position (x, y)
@@ -82,6 +186,19 @@ pub struct Game {
     pub tetris_celebration_active: bool,
     /// TETRIS celebration timer for animation
     pub tetris_celebration_timer: f64,
+    /// Whether the PERFECT CLEAR celebration is showing
+    #[serde(default)]
+    pub perfect_clear_celebration_active: bool,
+    /// Timer for the PERFECT CLEAR celebration
+    #[serde(default)]
+    pub perfect_clear_celebration_timer: f64,
+    
+    /// Whether the LEVEL N banner is showing
+    #[serde(default)]
+    pub level_up_banner_active: bool,
+    /// Timer for the LEVEL N banner
+    #[serde(default)]
+    pub level_up_banner_timer: f64,
     
     /// Ghost block throwing animation state
     pub ghost_throw_active: bool,
@@ -98,26 +215,309 @@ pub struct Game {
     /// Track if the last successful action was a rotation (for T-spin detection)
     pub last_action_was_rotation: bool,
     
-    /// Super Rotation System for handling piece rotation with wall kicks
-    pub rotation_system: SRSRotationSystem,
+    /// Rotation system for handling piece rotation, wall kicks and spawn orientation (SRS by default)
+    pub rotation_system: Box<dyn RotationSystem>,
     
     /// Enhanced scoring system with T-spins, combos, and back-to-back bonuses
     pub scoring_system: TetrisScoring,
+    
+    /// What to do when the stack tops out mid-animation
+    #[serde(default)]
+    pub topout_behavior: TopOutBehavior,
+    /// Top out detected during a ghost throw, waiting for the throw to finish
+    #[serde(default)]
+    pub topout_pending: bool,
+    /// Whether a loss plays the top-out animation before the game-over screen
+    #[serde(default)]
+    pub topout_animation: bool,
+    /// Swap busy effects for minimal ones: line clears flash briefly, TETRIS isn't celebrated
+    /// and ghost blocks land without being thrown
+    #[serde(default)]
+    pub reduce_motion: bool,
+    
+    /// Level the game started at (lines cleared add levels on top of this)
+    #[serde(default = "default_start_level")]
+    pub start_level: u32,
+    /// Optional cap on the effective level
+    #[serde(default)]
+    pub max_level: Option<u32>,
+    
+    /// Rule variant this game is played under
+    #[serde(default)]
+    pub mode: GameMode,
+    
+    /// Combo value shown by the combo indicator (kept briefly after a combo ends so it can fade)
+    #[serde(default)]
+    pub combo_display: u32,
+    /// Time since the displayed combo ended
+    #[serde(default)]
+    pub combo_fade_timer: f64,
+    
+    /// Cells of the most recently locked piece, flashed briefly by the renderer
+    #[serde(default)]
+    pub last_locked_cells: Vec<(i32, i32)>,
+    /// Whether the most recently locked piece was a T-spin, decided while it was still the
+    /// current piece so the clear it makes can be scored as one
+    #[serde(default)]
+    pub last_lock_was_t_spin: bool,
+    /// Time left on the lock flash
+    #[serde(default)]
+    pub lock_flash_timer: f64,
+    /// Whether hard drops leave a fading afterimage along the drop path
+    #[serde(default = "default_true")]
+    pub hard_drop_trail_enabled: bool,
+    /// Which hard drop presses go through (double tap, off); set from settings and not saved
+    #[serde(skip)]
+    pub hard_drop_confirm: HardDropConfirm,
+    /// Last hard drop's trail: the piece's cells where the drop started, rows dropped and color
+    #[serde(skip)]
+    pub hard_drop_trail: Option<HardDropTrail>,
+    /// Time left before the hard drop trail disappears
+    #[serde(skip)]
+    pub hard_drop_trail_timer: f64,
+    /// Board shake after hard drops and four-line clears
+    #[serde(default)]
+    pub screen_shake: ScreenShake,
+    /// Callouts for recent big clears, oldest first
+    #[serde(skip)]
+    pub notifications: Vec<Notification>,
+    
+    /// Difficulty preset the handling values below came from
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    /// How drop speed rises with level (the difficulty's multiplier is applied on top)
+    #[serde(default)]
+    pub gravity_curve: GravityCurve,
+    /// Time a grounded piece waits before locking
+    #[serde(default = "default_lock_delay")]
+    pub lock_delay: f64,
+    /// Maximum lock delay resets per piece
+    #[serde(default = "default_max_lock_resets")]
+    pub max_lock_resets: u32,
+    /// Delay before a held direction starts repeating
+    #[serde(default = "default_das")]
+    pub das: f64,
+    /// Time between repeated moves once DAS has charged
+    #[serde(default = "default_arr")]
+    pub arr: f64,
+    /// Buttons held this frame, applied to each new piece as it spawns (IRS/IHS)
+    #[serde(skip)]
+    pub held_inputs: HeldInputs,
+    /// Button presses waiting to be applied at the start of the next update, oldest first
+    #[serde(skip)]
+    pub queued_actions: VecDeque<InputAction>,
+    /// Actions applied by the last update and whether each took effect, for sound feedback
+    #[serde(skip)]
+    pub applied_actions: Vec<(InputAction, bool)>,
+    /// Events since the last `poll_events`, oldest first
+    #[serde(skip)]
+    events: Vec<GameEvent>,
+    /// Moves made since left was pressed (first is immediate, second waits for DAS)
+    #[serde(default)]
+    pub left_repeat_count: u32,
+    /// Moves made since right was pressed
+    #[serde(default)]
+    pub right_repeat_count: u32,
+    
+    /// Pieces locked onto the board this game
+    #[serde(default)]
+    pub pieces_placed: u32,
+    /// Number of pieces the game ends after, for score attack
+    #[serde(default)]
+    pub piece_limit: Option<u32>,
+    /// Number of lines the game ends after, for sprint
+    #[serde(default)]
+    pub line_goal: Option<u32>,
+    
+    /// Cheese (single-hole garbage) rows still at the bottom of the board
+    #[serde(default)]
+    pub cheese_remaining: u32,
+    /// Cheese rows cleared so far this game
+    #[serde(default)]
+    pub cheese_cleared_total: u32,
+    
+    /// Garbage lines earned by clears and not yet sent to an opponent
+    #[serde(default)]
+    pub pending_attack: u32,
+    /// Garbage and cheese hole rolls made so far; each roll seeds its own generator from the game seed
+    #[serde(default)]
+    pub garbage_rolls: u64,
+    
+    /// This run's progress, recorded to race against later
+    #[serde(default)]
+    pub replay: Replay,
+    /// Best previous run being raced against, if any
+    #[serde(default)]
+    pub ghost_race: Option<Replay>,
+    /// Setup the player is practicing, drawn as outlines over the board
+    #[serde(default)]
+    pub template: Option<TemplateOverlay>,
+    /// Whether board snapshots are recorded so a lost game can replay its final seconds
+    #[serde(default = "default_true")]
+    pub record_snapshots: bool,
+    /// Time since the game ended, driving the death-cam playback
+    #[serde(skip)]
+    pub game_over_timer: f64,
+    
+    /// Taps and rotations used on the current piece (held-direction repeats don't count)
+    #[serde(default)]
+    pub input_count: u32,
+    /// Placements that used more inputs than the minimum
+    #[serde(default)]
+    pub finesse_faults: u32,
+    /// Pieces locked of each type
+    #[serde(default)]
+    pub piece_counts: HashMap<TetrominoType, u32>,
+    /// Successful holds this game, kept apart from placements
+    #[serde(default)]
+    pub holds_used: u32,
+    /// Longest combo chain this game
+    #[serde(default)]
+    pub max_combo: u32,
+    /// Line clears made with a T-spin
+    #[serde(default)]
+    pub t_spins: u32,
+    /// Four-line clears
+    #[serde(default)]
+    pub tetrises: u32,
+    /// Every line clear this game, in order
+    #[serde(default)]
+    pub line_clear_log: Vec<LineClearEvent>,
+    /// Whether the most recent placement used the minimum number of inputs
+    #[serde(default = "default_true")]
+    pub last_placement_optimal: bool,
+    
+    /// Whether new piece sequences are kept from opening with S or Z
+    #[serde(default = "default_true")]
+    pub first_piece_no_sz: bool,
+    
+    /// Why the game ended, once it has
+    #[serde(default)]
+    pub game_over_reason: Option<GameOverReason>,
+    
+    /// Whether the ruleset allows holding at all
+    #[serde(default = "default_true")]
+    pub hold_enabled: bool,
+    /// Whether swapping a piece in from hold refreshes lock delay (off prevents hold-stalling)
+    #[serde(default = "default_true")]
+    pub hold_resets_lock: bool,
+    /// Whether a held piece keeps its rotation instead of returning in spawn orientation
+    #[serde(default)]
+    pub hold_preserves_rotation: bool,
+    /// Rotation state the held piece comes back out in (`RotationState::Spawn` unless rotation is preserved)
+    #[serde(default)]
+    pub held_rotation: RotationState,
+    /// Pieces that must spawn after a hold before the next one is allowed (`None` = no cooldown)
+    #[serde(default)]
+    pub hold_cooldown: Option<u32>,
+    /// Pieces spawned since hold was last used
+    #[serde(default)]
+    pub pieces_since_hold: u32,
+    /// How the renderer shows the ghost piece (the landing is computed either way)
+    #[serde(default)]
+    pub ghost_style: GhostStyle,
+    /// Outcome of the last save this session (not itself saved)
+    #[serde(skip)]
+    pub last_save_result: SaveResult,
+}
+
+/// A hard drop's afterimage: the piece's cells where the drop started, rows dropped and piece type
+pub type HardDropTrail = (Vec<(i32, i32)>, i32, TetrominoType);
+
+/// Level reached from a starting level and lines cleared at `lines_per_level`, limited by an optional cap
+pub(crate) fn effective_level(start_level: u32, lines_cleared: u32, lines_per_level: u32, max_level: Option<u32>) -> u32 {
+    let level = start_level.max(1) + lines_cleared / lines_per_level.max(1);
+    match max_level {
+        Some(cap) => level.min(cap.max(1)),
+        None => level,
+    }
+}
+
+/// Keeps garbage holes from following the same random stream as the piece sequence
+const GARBAGE_SEED_SALT: u64 = 0x6A09_E667_F3BC_C908;
+
+/// Default starting level for saves, settings and leaderboard entries made before it was configurable
+pub(crate) fn default_start_level() -> u32 {
+    1
+}
+
+/// For flags that are on unless a save or settings file says otherwise
+pub(crate) fn default_true() -> bool {
+    true
+}
+
+/// Ghost block cadence for saves and settings files that predate it
+pub(crate) fn default_ghost_block_every_n_lines() -> u32 {
+    GHOST_BLOCK_EVERY_N_LINES
+}
+
+/// Ghost block cap for saves and settings files that predate it
+pub(crate) fn default_ghost_block_max() -> u32 {
+    GHOST_BLOCK_MAX
+}
+
+/// Handling defaults for saves made before difficulty presets existed
+fn default_lock_delay() -> f64 {
+    Difficulty::Normal.lock_delay()
+}
+
+fn default_max_lock_resets() -> u32 {
+    Difficulty::Normal.max_lock_resets()
+}
+
+fn default_das() -> f64 {
+    Difficulty::Normal.das()
+}
+
+fn default_arr() -> f64 {
+    Difficulty::Normal.arr()
 }
 
 impl Game {
-    /// Create a new game instance
+    /// Create a new game instance, seeded from system entropy
     pub fn new() -> Self {
+        Self::new_with_randomizer(Randomizer::default())
+    }
+    
+    /// Create a new game whose piece sequence comes from a fixed seed
+    ///
+    /// Games created with the same seed deal the same pieces, which makes runs reproducible.
+    pub fn new_with_seed(seed: u64) -> Self {
+        Self::new_with_randomizer(crate::tetromino::RandomizerKind::default().create(seed))
+    }
+    
+    /// Create a new game dealing pieces from one of the built-in generators, such as a fixed
+    /// sequence in tests
+    ///
+    /// Only generators that convert into a `Randomizer` are accepted: the game is cloned and
+    /// saved, so its generator has to be too.
+    pub fn with_generator(generator: impl Into<Randomizer>) -> Self {
+        Self::new_with_randomizer(generator.into())
+    }
+    
+    /// Create a new game dealing pieces from the given randomizer
+    pub fn new_with_randomizer(mut randomizer: Randomizer) -> Self {
+        let initial_seed = randomizer.seed();
+        let next_piece = randomizer.next_piece();
+        
         let mut game = Self {
-            state: GameState::Playing,
+            version: SAVE_VERSION,
+            state: GameState::Countdown { remaining: COUNTDOWN_TIME },
             board: Board::new(),
             current_piece: None,
-            next_piece: TetrominoType::random(),
+            next_piece,
+            randomizer,
+            initial_seed,
             held_piece: None,
             hold_used_this_piece: false,
             score: 0,
             drop_timer: 0.0,
             drop_interval: 1.0, // Will be set properly by update_drop_interval()
+            target_drop_interval: 1.0,
+            drop_interval_ease_start: 1.0,
+            drop_interval_ease_timer: DROP_INTERVAL_EASE_TIME,
+            spawn_grace: 0.0,
+            spawn_grace_remaining: 0.0,
             game_time: 0.0,
             clearing_lines: Vec::new(),
             clear_animation_timer: 0.0,
@@ -126,6 +526,9 @@ impl Game {
             right_move_timer: 0.0,
 
             ghost_blocks_available: 0,
+            ghost_blocks_enabled: true,
+            ghost_block_every_n_lines: GHOST_BLOCK_EVERY_N_LINES,
+            ghost_block_max: GHOST_BLOCK_MAX,
             ghost_block_placement_mode: false,
             ghost_block_cursor: (BOARD_WIDTH as i32 / 2, (BUFFER_HEIGHT + VISIBLE_HEIGHT / 2) as i32),
             ghost_block_blink_timer: 0.0,
@@ -140,6 +543,10 @@ impl Game {
             
             tetris_celebration_active: false,
             tetris_celebration_timer: 0.0,
+            perfect_clear_celebration_active: false,
+            perfect_clear_celebration_timer: 0.0,
+            level_up_banner_active: false,
+            level_up_banner_timer: 0.0,
             
             ghost_throw_active: false,
             ghost_throw_timer: 0.0,
@@ -149,8 +556,79 @@ impl Game {
             legacy_mode: false, // Start in modern mode by default
             last_action_was_rotation: false,
             
-            rotation_system: SRSRotationSystem::new(),
+            rotation_system: RotationSystemKind::Srs.create(),
             scoring_system: TetrisScoring::new(),
+            
+            topout_behavior: TopOutBehavior::default(),
+            topout_pending: false,
+            topout_animation: false,
+            reduce_motion: false,
+            
+            start_level: 1,
+            max_level: None,
+            
+            mode: GameMode::default(),
+            
+            combo_display: 0,
+            combo_fade_timer: 0.0,
+            last_locked_cells: Vec::new(),
+            last_lock_was_t_spin: false,
+            lock_flash_timer: 0.0,
+            hard_drop_trail_enabled: true,
+            hard_drop_confirm: HardDropConfirm::default(),
+            hard_drop_trail: None,
+            hard_drop_trail_timer: 0.0,
+            screen_shake: ScreenShake::with_seed(rand::random()),
+            notifications: Vec::new(),
+            
+            difficulty: Difficulty::Normal,
+            gravity_curve: GravityCurve::default(),
+            lock_delay: Difficulty::Normal.lock_delay(),
+            max_lock_resets: Difficulty::Normal.max_lock_resets(),
+            das: Difficulty::Normal.das(),
+            arr: Difficulty::Normal.arr(),
+            held_inputs: HeldInputs::default(),
+            queued_actions: VecDeque::new(),
+            events: Vec::new(),
+            applied_actions: Vec::new(),
+            left_repeat_count: 0,
+            right_repeat_count: 0,
+            
+            pieces_placed: 0,
+            piece_limit: None,
+            line_goal: None,
+            pending_attack: 0,
+            garbage_rolls: 0,
+            cheese_remaining: 0,
+            cheese_cleared_total: 0,
+            replay: Replay::default(),
+            ghost_race: None,
+            template: None,
+            record_snapshots: true,
+            game_over_timer: 0.0,
+            
+            input_count: 0,
+            finesse_faults: 0,
+            piece_counts: HashMap::new(),
+            holds_used: 0,
+            max_combo: 0,
+            t_spins: 0,
+            tetrises: 0,
+            line_clear_log: Vec::new(),
+            last_placement_optimal: true,
+            
+            first_piece_no_sz: true,
+            
+            game_over_reason: None,
+            
+            hold_enabled: true,
+            hold_resets_lock: true,
+            hold_preserves_rotation: false,
+            held_rotation: RotationState::Spawn,
+            hold_cooldown: None,
+            pieces_since_hold: 0,
+            ghost_style: GhostStyle::default(),
+            last_save_result: SaveResult::None,
         };
         
         // Spawn the first piece
@@ -162,30 +640,299 @@ impl Game {
         game
     }
     
+    /// Create a new game starting at a higher level (classic-style challenge start)
+    pub fn new_with_level(start_level: u32) -> Self {
+        let mut game = Self::new();
+        game.start_level = start_level.max(1);
+        game.update_drop_interval();
+        game
+    }
+    
+    /// Create a score attack game that ends in victory after `pieces` pieces are placed
+    pub fn new_piece_limited(pieces: u32) -> Self {
+        let mut game = Self::new();
+        game.mode = GameMode::ScoreAttack;
+        game.hold_enabled = GameMode::ScoreAttack.hold_enabled();
+        game.piece_limit = Some(pieces);
+        game
+    }
+    
+    /// Create a sprint game that ends in victory after `lines` lines are cleared
+    pub fn new_line_goal(lines: u32) -> Self {
+        let mut game = Self::new();
+        game.mode = GameMode::Sprint;
+        game.replay = Replay::new(GameMode::Sprint);
+        game.line_goal = Some(lines);
+        game
+    }
+    
+    /// Create a cheese game starting with `rows` garbage rows to dig through
+    pub fn new_cheese(rows: u32) -> Self {
+        let mut game = Self::new();
+        game.mode = GameMode::Cheese { rows };
+        game.replay = Replay::new(game.mode);
+        game.add_cheese_rows(rows);
+        game
+    }
+    
+    /// Create a cheese drill that keeps `rows` garbage rows on the board
+    pub fn new_cheese_drill(rows: u32) -> Self {
+        let mut game = Self::new();
+        game.mode = GameMode::CheeseDrill { rows };
+        game.replay = Replay::new(game.mode);
+        game.add_cheese_rows(rows);
+        game
+    }
+    
+    /// Push `rows` cheese rows in under the stack, each with one hole in a different
+    /// column from the row above it
+    pub fn add_cheese_rows(&mut self, rows: u32) {
+        // The new rows go in under the current bottom cheese row, so start from its hole
+        let bottom = (BOARD_HEIGHT + BUFFER_HEIGHT - 1) as i32;
+        let mut previous_hole = if self.cheese_remaining > 0 {
+            (0..BOARD_WIDTH).find(|&x| self.board.get_cell(x as i32, bottom).is_some_and(|cell| cell.is_empty()))
+        } else {
+            None
+        };
+        
+        // Listed top to bottom, which is the order add_garbage_lines stacks them in
+        let mut rng = self.garbage_rng();
+        let mut holes = Vec::with_capacity(rows as usize);
+        for _ in 0..rows {
+            let mut hole = rng.gen_range(0..BOARD_WIDTH);
+            while Some(hole) == previous_hole {
+                hole = rng.gen_range(0..BOARD_WIDTH);
+            }
+            holes.push(hole);
+            previous_hole = Some(hole);
+        }
+        
+        self.cheese_remaining += rows;
+        if !self.board.add_garbage_lines(&holes) {
+            self.end_game(GameOverReason::TopOut);
+        }
+    }
+    
+    /// Cheese rows left to clear, or `None` outside cheese modes
+    pub fn cheese_remaining(&self) -> Option<u32> {
+        self.mode.cheese_rows().map(|_| self.cheese_remaining)
+    }
+    
+    /// Successful holds this game
+    pub fn holds_used(&self) -> u32 {
+        self.holds_used
+    }
+    
+    /// Cheese rows cleared so far, or `None` outside cheese modes
+    pub fn cheese_cleared_total(&self) -> Option<u32> {
+        self.mode.cheese_rows().map(|_| self.cheese_cleared_total)
+    }
+    
+    /// Cheese rows cleared per minute of play, or `None` outside cheese modes
+    pub fn cheese_rate(&self) -> Option<f64> {
+        let minutes = self.game_time / 60.0;
+        self.cheese_cleared_total()
+            .map(|cleared| if minutes > 0.0 { cleared as f64 / minutes } else { 0.0 })
+    }
+    
+    /// Pieces left to place in a piece-limited game, or `None` if there is no limit
+    pub fn pieces_remaining(&self) -> Option<u32> {
+        self.piece_limit.map(|limit| limit.saturating_sub(self.pieces_placed))
+    }
+    
+    /// Lines left to clear in a line-goal game, or `None` if there is no goal
+    pub fn lines_remaining(&self) -> Option<u32> {
+        self.line_goal.map(|goal| goal.saturating_sub(self.lines_cleared()))
+    }
+    
+    /// Take the garbage earned since the last call, to send to an opponent
+    pub fn take_pending_attack(&mut self) -> u32 {
+        std::mem::take(&mut self.pending_attack)
+    }
+    
+    /// Receive garbage from an opponent
+    ///
+    /// Incoming lines first cancel out pending attack; whatever is left is pushed in under the
+    /// stack with one shared hole. Returns how many garbage rows were actually added.
+    pub fn receive_garbage(&mut self, lines: u32) -> u32 {
+        let cancelled = lines.min(self.pending_attack);
+        self.pending_attack -= cancelled;
+        let incoming = lines - cancelled;
+        if incoming == 0 || self.state.has_ended() {
+            return 0;
+        }
+        
+        let hole = self.garbage_rng().gen_range(0..BOARD_WIDTH);
+        let fits = self.board.add_garbage_lines(&vec![hole; incoming as usize]);
+        
+        // Lift the falling piece clear of the rising stack
+        if let Some(mut piece) = self.current_piece.take() {
+            for _ in 0..incoming {
+                if self.is_piece_valid(&piece) {
+                    break;
+                }
+                piece.move_by(0, -1);
+            }
+            self.current_piece = Some(piece);
+        }
+        
+        if !fits {
+            self.end_game(GameOverReason::TopOut);
+        }
+        incoming
+    }
+    
+    /// Generator for the next garbage holes, derived from the game seed so a seed replays the same garbage
+    fn garbage_rng(&mut self) -> StdRng {
+        let roll = self.garbage_rolls;
+        self.garbage_rolls += 1;
+        StdRng::seed_from_u64(self.initial_seed ^ GARBAGE_SEED_SALT ^ roll.wrapping_mul(0x9E37_79B9_7F4A_7C15))
+    }
+    
+    /// Lines ahead of (positive) or behind (negative) the raced run at the current time
+    pub fn ghost_race_delta(&self) -> Option<i32> {
+        let best = self.ghost_race.as_ref()?;
+        Some(self.lines_cleared() as i32 - best.lines_at_time(self.game_time) as i32)
+    }
+    
+    /// Whether this finished run should be kept as the best sprint to race
+    ///
+    /// Practice games that deal only some piece types never replace the best run.
+    pub fn records_best_sprint(&self) -> bool {
+        !matches!(self.randomizer, Randomizer::Filtered(_)) && self.replay.is_better_than(self.ghost_race.as_ref())
+    }
+    
+    /// Switch the level-to-speed curve, taking effect at the current level
+    pub fn set_gravity_curve(&mut self, curve: GravityCurve) {
+        self.gravity_curve = curve;
+        self.update_drop_interval();
+    }
+    
+    /// Apply a difficulty preset's speed and handling values
+    pub fn set_difficulty(&mut self, difficulty: Difficulty) {
+        self.difficulty = difficulty;
+        self.lock_delay = difficulty.lock_delay();
+        self.max_lock_resets = difficulty.max_lock_resets();
+        self.das = difficulty.das();
+        self.arr = difficulty.arr();
+        self.update_drop_interval();
+    }
+    
     /// Update game logic
     pub fn update(&mut self, delta_time: f64) {
+        // Applied presses only describe this update, however it returns
+        self.applied_actions.clear();
+        
+        if let GameState::Countdown { remaining: before } = self.state {
+            let remaining = before - delta_time;
+            self.state = if remaining <= 0.0 {
+                log::info!("Countdown finished - go!");
+                self.events.push(GameEvent::CountdownGo);
+                GameState::Playing
+            } else {
+                if before >= COUNTDOWN_TIME || before.ceil() > remaining.ceil() {
+                    self.events.push(GameEvent::CountdownTick);
+                }
+                GameState::Countdown { remaining }
+            };
+            return;
+        }
+        
+        if let GameState::ToppingOut { timer } = self.state {
+            let timer = timer + delta_time;
+            self.state = if timer >= TOPOUT_ANIMATION_TIME {
+                GameState::GameOver
+            } else {
+                GameState::ToppingOut { timer }
+            };
+            return;
+        }
+        
+        if self.state.has_ended() {
+            self.game_over_timer += delta_time;
+            return;
+        }
+        
         if self.state != GameState::Playing {
+            self.queued_actions.clear();
             return;
         }
         
         // Reset piece locked flag at the start of each update cycle
         self.piece_just_locked = false;
         
+        // Presses from this frame act on the piece they were aimed at, before gravity can lock it.
+        // A clear still animating hasn't been scored yet, so a piece locking now would break the
+        // combo ahead of the clear that extends it; those presses wait for the clear to finish
+        if self.clearing_lines.is_empty() {
+            while let Some(action) = self.queued_actions.pop_front() {
+                let applied = self.apply_action(action);
+                self.applied_actions.push((action, applied));
+            }
+        }
+        
         self.game_time += delta_time;
         
+        if self.record_snapshots {
+            self.replay.record_snapshot(self.game_time, &self.board, self.current_piece.as_ref());
+        }
+        
+        self.screen_shake.update(delta_time);
+        // Held directions keep charging through line clears, so DAS carries over to the next piece
+        self.left_move_timer += delta_time;
+        self.right_move_timer += delta_time;
+        for notification in &mut self.notifications {
+            notification.age += delta_time;
+        }
+        self.notifications.retain(|notification| !notification.is_expired());
+        
+        // Lock flash runs on through line clear animations
+        if self.lock_flash_timer > 0.0 {
+            self.lock_flash_timer -= delta_time;
+            if self.lock_flash_timer <= 0.0 {
+                self.lock_flash_timer = 0.0;
+                self.last_locked_cells.clear();
+            }
+        }
+        if self.hard_drop_trail_timer > 0.0 {
+            self.hard_drop_trail_timer -= delta_time;
+            if self.hard_drop_trail_timer <= 0.0 {
+                self.hard_drop_trail_timer = 0.0;
+                self.hard_drop_trail = None;
+            }
+        }
+        
         // Handle line clearing animation
         if !self.clearing_lines.is_empty() {
+            if self.topout_behavior == TopOutBehavior::Immediate && self.board.is_game_over() {
+                log::info!("Topped out during line clear animation - ending game immediately");
+                self.clearing_lines.clear();
+                self.clear_animation_timer = 0.0;
+                self.end_game(GameOverReason::TopOut);
+                return;
+            }
+            
             self.clear_animation_timer += delta_time;
-            if self.clear_animation_timer >= LINE_CLEAR_ANIMATION_TIME {
+            if self.clear_animation_timer >= self.line_clear_animation_time() {
                 self.finish_line_clear();
             }
             return; // Don't update other game logic during animation
         }
         
-        self.drop_timer += delta_time;
+        // Ease gravity toward the current level's speed
+        if self.drop_interval_ease_timer < DROP_INTERVAL_EASE_TIME {
+            self.drop_interval_ease_timer = (self.drop_interval_ease_timer + delta_time).min(DROP_INTERVAL_EASE_TIME);
+            let progress = self.drop_interval_ease_timer / DROP_INTERVAL_EASE_TIME;
+            self.drop_interval = self.drop_interval_ease_start + (self.target_drop_interval - self.drop_interval_ease_start) * progress;
+        }
+        
+        // Gravity waits out the spawn grace before it starts on a new piece; only the part
+        // of the frame after the grace ends counts towards the next drop
+        let gravity_time = (delta_time - self.spawn_grace_remaining).max(0.0);
+        self.spawn_grace_remaining = (self.spawn_grace_remaining - delta_time).max(0.0);
+        let in_spawn_grace = self.spawn_grace_remaining > 0.0;
+        self.drop_timer += gravity_time;
         self.soft_drop_timer += delta_time;
-        self.left_move_timer += delta_time;
-        self.right_move_timer += delta_time;
         self.ghost_block_blink_timer += delta_time;
         
         // Update piece lifetime timer
@@ -202,36 +949,108 @@ impl Game {
             }
         }
         
+        // Update LEVEL N banner timer
+        if self.level_up_banner_active {
+            self.level_up_banner_timer += delta_time;
+            if self.level_up_banner_timer >= LEVEL_UP_BANNER_TIME {
+                self.level_up_banner_active = false;
+                self.level_up_banner_timer = 0.0;
+            }
+        }
+        
+        // Update PERFECT CLEAR celebration timer
+        if self.perfect_clear_celebration_active {
+            self.perfect_clear_celebration_timer += delta_time;
+            if self.perfect_clear_celebration_timer >= PERFECT_CLEAR_CELEBRATION_TIME {
+                self.perfect_clear_celebration_active = false;
+                self.perfect_clear_celebration_timer = 0.0;
+            }
+        }
+        
+        // Update combo indicator fade
+        if self.current_combo() >= 1 {
+            self.combo_display = self.current_combo();
+            self.combo_fade_timer = 0.0;
+        } else if self.combo_display > 0 {
+            self.combo_fade_timer += delta_time;
+            if self.combo_fade_timer >= COMBO_FADE_TIME {
+                self.combo_display = 0;
+                self.combo_fade_timer = 0.0;
+            }
+        }
+        
         // Update ghost throw animation timer
         if self.ghost_throw_active {
             self.ghost_throw_timer += delta_time;
-            if self.ghost_throw_timer >= GHOST_THROW_ANIMATION_TIME {
+            if self.reduce_motion || self.ghost_throw_timer >= GHOST_THROW_ANIMATION_TIME {
                 self.finish_ghost_throw();
+                
+                if self.topout_pending {
+                    self.topout_pending = false;
+                    if !self.clearing_lines.is_empty() {
+                        // The throw completed a line - the clear animation decides the outcome
+                        return;
+                    }
+                    if self.board.is_game_over() {
+                        self.end_game(GameOverReason::TopOut);
+                        return;
+                    }
+                    self.spawn_next_piece();
+                }
             }
         }
         
         // Check for force lock if piece has exceeded maximum lifetime
         // This is a critical safeguard against floating pieces
         if self.piece_lifetime_timer >= MAX_PIECE_LIFETIME {
-            log::warn!("Piece exceeded maximum lifetime of {}s, force-locking to prevent floating bug", MAX_PIECE_LIFETIME);
+            // Only a piece that could still fall indicates a real floating bug
+            log::log!(self.force_lock_log_level(),
+                      "Piece exceeded maximum lifetime of {}s, force-locking to prevent floating bug", MAX_PIECE_LIFETIME);
             self.lock_current_piece();
             return; // Don't continue with other logic after locking
         }
         
+        // A piece that has room to fall again (after a kick, hold or clear) isn't locking
+        if self.piece_is_locking && self.current_piece_can_fall() {
+            self.release_lock_delay();
+        }
+        
         // Update lock delay timer if piece is in locking state
         if self.piece_is_locking {
             self.lock_delay_timer += delta_time;
             // Check if lock delay time has expired
-            if self.lock_delay_timer >= LOCK_DELAY {
+            if self.lock_delay_timer >= self.lock_delay {
                 self.lock_current_piece();
                 return; // Don't continue with other logic after locking
             }
         }
         
-        // Check if it's time to drop the current piece
-        if self.drop_timer >= self.drop_interval {
-            self.drop_current_piece();
+        // Drop one row per elapsed interval, so a slow frame still moves the piece the right distance
+        if in_spawn_grace {
+            // Not even 20G pulls the piece down until the grace is over
+        } else if self.drop_interval <= INSTANT_GRAVITY_INTERVAL {
+            // 20G: the piece goes straight to the floor
+            while self.drop_current_piece() {}
             self.drop_timer = 0.0;
+        } else {
+            while self.drop_timer >= self.drop_interval {
+                if !self.drop_current_piece() {
+                    // Resting on the stack - don't bank the leftover time
+                    self.drop_timer = 0.0;
+                    break;
+                }
+                self.drop_timer -= self.drop_interval;
+            }
+        }
+    }
+    
+    /// Log level for a force-lock: warn only if the piece could still fall (a real floating bug),
+    /// since grounded pieces legitimately reach the lifetime cap in modes with long lock delays
+    fn force_lock_log_level(&self) -> log::Level {
+        if self.current_piece_can_fall() {
+            log::Level::Warn
+        } else {
+            log::Level::Debug
         }
     }
     
@@ -271,6 +1090,8 @@ impl Game {
     
     /// Lock the current piece to the board and spawn a new one
     pub fn lock_current_piece(&mut self) {
+        // T-spin detection needs the piece in place, so decide it before taking it
+        self.last_lock_was_t_spin = self.is_t_spin();
         if let Some(piece) = self.current_piece.take() {
             // Debug logging for piece locking
             log::debug!("Locking piece {:?} at position ({}, {}) after {:.2}s lifetime, {} lock resets",
@@ -279,6 +1100,10 @@ impl Game {
             
             // Set flag to indicate a piece was just locked (for audio feedback)
             self.piece_just_locked = true;
+            self.events.push(GameEvent::PieceLocked);
+            self.pieces_placed += 1;
+            *self.piece_counts.entry(piece.piece_type).or_insert(0) += 1;
+            self.check_finesse(&piece);
             
             // Reset lock delay state
             self.piece_is_locking = false;
@@ -286,13 +1111,25 @@ impl Game {
             self.lock_resets = 0;
             self.piece_lifetime_timer = 0.0;
             
+            let locked_out = self.board.is_lock_out(&piece);
+            
+            self.last_locked_cells = piece.absolute_blocks();
+            self.lock_flash_timer = LOCK_FLASH_TIME;
+            
             // Place the piece on the board
             for (x, y) in piece.absolute_blocks() {
                 if x >= 0 && y >= 0 {
-                    self.board.set_cell(x, y, Cell::Filled(piece.color()));
+                    self.board.set_cell(x, y, Cell::piece(piece.piece_type));
+                    self.board.set_lock_time(x, y, self.game_time);
                 }
             }
             
+            // A piece that locks wholly above the playfield ends the game outright
+            if locked_out {
+                self.end_game(GameOverReason::LockOut);
+                return;
+            }
+            
             // Check for complete lines and start animation
             let complete_lines = self.board.find_complete_lines();
             if !complete_lines.is_empty() {
@@ -300,9 +1137,17 @@ impl Game {
                 return; // Don't spawn next piece until animation is done
             }
             
+            // A placement without a line clear breaks the combo
+            self.add_score_for_lines(0);
+            
             // Check game over
             if self.board.is_game_over() {
-                self.state = GameState::GameOver;
+                if self.ghost_throw_active && self.topout_behavior == TopOutBehavior::FinishAnimation {
+                    // Let the throw land first; it may still clear the offending line
+                    self.topout_pending = true;
+                    return;
+                }
+                self.end_game(GameOverReason::TopOut);
                 return;
             }
             
@@ -311,21 +1156,65 @@ impl Game {
         }
     }
     
+    /// Compare the inputs spent on a piece against the fewest that reach its placement
+    fn check_finesse(&mut self, piece: &Tetromino) {
+        // Placements only reachable with tucks or spins can't be judged
+        let Some(minimum) = finesse::minimum_inputs(&self.board, piece) else {
+            self.last_placement_optimal = true;
+            return;
+        };
+        
+        self.last_placement_optimal = self.input_count <= minimum;
+        if !self.last_placement_optimal {
+            self.finesse_faults += 1;
+            log::debug!("Finesse fault: {:?} placed with {} inputs, {} needed",
+                       piece.piece_type, self.input_count, minimum);
+        }
+    }
+    
+    /// Whether the most recent placement used the minimum number of inputs
+    pub fn last_placement_was_optimal(&self) -> bool {
+        self.last_placement_optimal
+    }
+    
+    /// Peek at the next `n` pieces that will spawn, without consuming them
+    pub fn upcoming_pieces(&self, n: usize) -> Vec<TetrominoType> {
+        if n == 0 {
+            return Vec::new();
+        }
+        let mut pieces = vec![self.next_piece];
+        pieces.extend(self.randomizer.peek(n - 1));
+        pieces
+    }
+    
     /// Spawn the next piece
     pub fn spawn_next_piece(&mut self) {
-        let new_piece = Tetromino::new(self.next_piece);
+        // Piece-limited games are won once every piece has been placed
+        if self.pieces_remaining() == Some(0) {
+            log::info!("All {} pieces placed - score attack complete", self.pieces_placed);
+            self.state = GameState::Finished;
+            self.events.push(GameEvent::Finished);
+            return;
+        }
+        
+        let new_piece = self.spawn_from_queue(self.next_piece);
         log::debug!("Spawning new piece: {:?} at position ({}, {})", 
                    new_piece.piece_type, new_piece.position.0, new_piece.position.1);
-        self.next_piece = TetrominoType::random();
+        self.next_piece = self.randomizer.next_piece();
         
-        // Reset hold usage for the new piece
+        // Reset hold usage and input tracking for the new piece
         self.hold_used_this_piece = false;
+        self.pieces_since_hold = self.pieces_since_hold.saturating_add(1);
+        self.input_count = 0;
+        // A half-confirmed hard drop was aimed at the old piece
+        self.hard_drop_confirm.reset();
         
         // Reset lock delay state for new piece
         self.piece_is_locking = false;
         self.lock_delay_timer = 0.0;
         self.lock_resets = 0;
         self.piece_lifetime_timer = 0.0;
+        self.spawn_grace_remaining = self.spawn_grace;
         
         // Update drop interval if level changed
         self.update_drop_interval();
@@ -336,25 +1225,159 @@ impl Game {
         // Check if the new piece can be placed
         if self.is_piece_valid(&new_piece) {
             self.current_piece = Some(new_piece);
+            self.apply_initial_actions();
         } else {
             // Game over - can't spawn new piece
             log::warn!("Game over: Cannot spawn piece {:?} - board is full", new_piece.piece_type);
+            self.end_game(GameOverReason::BlockOut);
+        }
+    }
+    
+    /// Apply a hold (IHS) and then a rotation (IRS) held through the spawn to the new piece
+    fn apply_initial_actions(&mut self) {
+        let held = self.held_inputs;
+        if held.hold && self.hold_piece() {
+            log::debug!("Initial hold applied at spawn");
+        }
+        if held.rotate_clockwise {
+            self.rotate_piece_clockwise();
+        } else if held.rotate_counterclockwise {
+            self.rotate_piece_counterclockwise();
+        }
+    }
+    
+    /// How far the top-out animation has run (0.0 to 1.0), or `None` when it isn't playing
+    pub fn topout_progress(&self) -> Option<f32> {
+        match self.state {
+            GameState::ToppingOut { timer } => Some((timer / TOPOUT_ANIMATION_TIME).clamp(0.0, 1.0) as f32),
+            _ => None,
+        }
+    }
+    
+    /// Cut the top-out animation short and go straight to the game-over screen
+    pub fn skip_topout_animation(&mut self) {
+        if matches!(self.state, GameState::ToppingOut { .. }) {
             self.state = GameState::GameOver;
         }
     }
     
+    /// The final seconds of a lost game, for the death-cam
+    pub fn death_cam(&self) -> Option<Replay> {
+        if self.state != GameState::GameOver || self.replay.snapshots.is_empty() {
+            return None;
+        }
+        Some(self.replay.tail(DEATH_CAM_SECONDS))
+    }
+    
+    /// Snapshot the death-cam is showing now, or `None` once playback has finished
+    pub fn death_cam_frame(&self) -> Option<&ReplaySnapshot> {
+        if self.state != GameState::GameOver {
+            return None;
+        }
+        // Seek within the full recording rather than copying out the tail every frame
+        let end = self.replay.last_time();
+        let start = self.replay.snapshots.iter().map(|snapshot| snapshot.time).find(|&time| time >= end - DEATH_CAM_SECONDS)?;
+        let time = start + self.game_over_timer;
+        if time > end {
+            return None;
+        }
+        self.replay.snapshot_at(time)
+    }
+    
+    /// Headline numbers of this game for sharing
+    pub fn summary(&self) -> GameSummary {
+        let pieces: BTreeMap<String, u32> = TetrominoType::all()
+            .into_iter()
+            .map(|piece_type| (piece_type.name().to_string(), self.piece_counts.get(&piece_type).copied().unwrap_or(0)))
+            .collect();
+        
+        GameSummary {
+            mode: self.mode,
+            difficulty: self.difficulty,
+            score: self.score,
+            level: self.level(),
+            lines: self.lines_cleared(),
+            time: self.game_time,
+            pieces_placed: self.pieces_placed,
+            pieces,
+            holds: self.holds_used,
+            max_combo: self.max_combo,
+            t_spins: self.t_spins,
+            tetrises: self.tetrises,
+            line_clears: self.line_clear_log.clone(),
+        }
+    }
+    
+    /// Write a compact JSON summary of this game to a file
+    pub fn export_summary<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn std::error::Error>> {
+        self.summary().save_to_file(path)
+    }
+    
+    /// Cells the next piece will occupy when it spawns
+    pub fn next_spawn_cells(&self) -> Vec<(i32, i32)> {
+        self.spawn_from_queue(self.next_piece).absolute_blocks()
+    }
+    
+    /// Spawn a piece dealt from the queue, mirrored in mirror mode
+    fn spawn_from_queue(&self, piece_type: TetrominoType) -> Tetromino {
+        let piece = self.rotation_system.spawn_piece(piece_type);
+        if self.mode.mirrors_pieces() {
+            piece.mirrored()
+        } else {
+            piece
+        }
+    }
+    
+    /// Flip the current piece if the mode mirrors pieces, for games whose first piece was
+    /// dealt before the mode was chosen
+    pub fn mirror_first_piece_for_mode(&mut self) {
+        if self.mode.mirrors_pieces() {
+            self.current_piece = self.current_piece.take().map(|piece| piece.mirrored());
+        }
+    }
+    
+    /// Type of the piece that will spawn next, mirrored in mirror mode; previews show this
+    /// rather than `next_piece`, which is the type as dealt
+    pub fn next_piece_type(&self) -> TetrominoType {
+        if self.mode.mirrors_pieces() {
+            self.next_piece.mirrored()
+        } else {
+            self.next_piece
+        }
+    }
+    
+    /// End the game, recording why
+    fn end_game(&mut self, reason: GameOverReason) {
+        log::info!("Game over: {}", reason.title());
+        self.game_over_reason = Some(reason);
+        self.events.push(GameEvent::GameOver { reason });
+        self.state = if self.topout_animation {
+            GameState::ToppingOut { timer: 0.0 }
+        } else {
+            GameState::GameOver
+        };
+        if self.record_snapshots {
+            // Capture the exact final board for the death-cam
+            self.replay.push_snapshot(self.game_time, &self.board, self.current_piece.as_ref());
+        }
+    }
+    
     /// Add score for cleared lines using enhanced scoring system
     pub fn add_score_for_lines(&mut self, lines_cleared: u32) {
         if lines_cleared == 0 {
             // No lines cleared - break combo but preserve back-to-back
+            let had_combo = self.current_combo() >= 1;
             self.scoring_system.process_no_line_clear();
+            if had_combo {
+                self.events.push(GameEvent::ComboBroken);
+            }
             return;
         }
         
-        let level = self.board.level();
+        let level = self.level();
         
         // Determine if this was a T-spin and what type
-        let is_t_spin = self.is_t_spin();
+        let is_t_spin = self.last_lock_was_t_spin;
         let is_mini_t_spin = false; // TODO: Implement mini T-spin detection later
         
         // Determine line clear type
@@ -376,12 +1399,34 @@ impl Game {
             back_to_back,
         };
         
+        self.pending_attack += attack_for(&action);
+        
         // Process the scoring
         let result = self.scoring_system.process_line_clear(action);
         
         // Update the game's score (keep backward compatibility)
         self.score = self.scoring_system.total_score();
         
+        self.max_combo = self.max_combo.max(self.current_combo());
+        if self.current_combo() >= 1 {
+            self.events.push(GameEvent::ComboExtended { combo: self.current_combo() });
+        }
+        self.line_clear_log.push(LineClearEvent {
+            time: self.game_time,
+            clear_type: line_clear_type,
+            combo: self.current_combo(),
+            back_to_back,
+        });
+        for callout in notification::notifications_for(line_clear_type, back_to_back, self.current_combo()) {
+            self.push_notification(callout);
+        }
+        if line_clear_type.is_t_spin() {
+            self.t_spins += 1;
+        }
+        if lines_cleared == 4 {
+            self.tetrises += 1;
+        }
+        
         // Log detailed scoring info
         log::info!("Line clear scoring: {} | Base: {} | Combo: {} | B2B: {} | Perfect: {} | Total: {}",
                    line_clear_type.name(),
@@ -404,8 +1449,38 @@ impl Game {
         }
     }
     
+    /// Queue a button press to be applied at the start of the next update
+    pub fn queue_action(&mut self, action: InputAction) {
+        self.queued_actions.push_back(action);
+    }
+    
+    /// Apply a button press straight away
+    /// Returns true if it took effect
+    pub fn apply_action(&mut self, action: InputAction) -> bool {
+        match action {
+            InputAction::RotateClockwise => self.rotate_piece_clockwise(),
+            InputAction::RotateCounterclockwise => self.rotate_piece_counterclockwise(),
+            InputAction::HardDrop => {
+                let had_piece = self.current_piece.is_some();
+                self.hard_drop();
+                had_piece
+            },
+            InputAction::SonicDrop => self.sonic_drop(),
+            InputAction::Hold => self.hold_piece(),
+        }
+    }
+    
     /// Try to move the current piece
     pub fn move_piece(&mut self, dx: i32, dy: i32) -> bool {
+        let moved = self.shift_piece(dx, dy);
+        if moved && dx != 0 {
+            self.input_count += 1;
+        }
+        moved
+    }
+    
+    /// Move the current piece without counting it as a player input (auto-repeat)
+    fn shift_piece(&mut self, dx: i32, dy: i32) -> bool {
         if let Some(mut piece) = self.current_piece.clone() {
             piece.move_by(dx, dy);
             
@@ -429,14 +1504,13 @@ impl Game {
     /// Update lock delay state based on whether current piece can continue falling
     /// This should be called after any successful piece movement or rotation
     fn update_lock_state_for_current_piece(&mut self) {
-        if let Some(ref piece) = self.current_piece {
+        if self.current_piece.is_some() {
             // Test if piece can move down from its CURRENT position
-            let mut test_piece = piece.clone();
-            test_piece.move_by(0, 1);
-            
-            if self.is_piece_valid(&test_piece) {
-                // Piece can still fall - reset lock delay completely
-                self.reset_lock_delay();
+            if self.current_piece_can_fall() {
+                // Piece can still fall - e.g. a kick into an overhang lowered its footprint.
+                // Release directly rather than through reset_lock_delay, so this never
+                // depends on (or spends) the grounded reset allowance.
+                self.release_lock_delay();
                 log::debug!("Piece can still fall from current position - lock delay reset");
             } else {
                 // Piece is truly grounded - start/continue lock delay
@@ -459,6 +1533,7 @@ impl Game {
                     self.current_piece = Some(new_piece);
                     // Mark that the last successful action was a rotation
                     self.last_action_was_rotation = true;
+                    self.input_count += 1;
                     // Check lock state after successful rotation
                     self.update_lock_state_for_current_piece();
                     return true;
@@ -467,6 +1542,7 @@ impl Game {
                     self.current_piece = Some(new_piece);
                     // Mark that the last successful action was a rotation (with kick)
                     self.last_action_was_rotation = true;
+                    self.input_count += 1;
                     // Check lock state after successful rotation
                     self.update_lock_state_for_current_piece();
                     return true;
@@ -488,6 +1564,7 @@ impl Game {
                     self.current_piece = Some(new_piece);
                     // Mark that the last successful action was a rotation
                     self.last_action_was_rotation = true;
+                    self.input_count += 1;
                     // Check lock state after successful rotation
                     self.update_lock_state_for_current_piece();
                     return true;
@@ -496,6 +1573,7 @@ impl Game {
                     self.current_piece = Some(new_piece);
                     // Mark that the last successful action was a rotation (with kick)
                     self.last_action_was_rotation = true;
+                    self.input_count += 1;
                     // Check lock state after successful rotation
                     self.update_lock_state_for_current_piece();
                     return true;
@@ -511,7 +1589,9 @@ impl Game {
     
     /// Hard drop the current piece
     pub fn hard_drop(&mut self) {
-        if self.current_piece.is_some() {
+        if let Some(piece) = self.current_piece.as_ref() {
+            let start_cells = piece.absolute_blocks();
+            let piece_type = piece.piece_type;
             let mut drop_distance = 0;
             
             // Drop as far as possible
@@ -519,15 +1599,58 @@ impl Game {
                 drop_distance += 1;
             }
             
+            if self.hard_drop_trail_enabled && drop_distance > 0 {
+                self.hard_drop_trail = Some((start_cells, drop_distance, piece_type));
+                self.hard_drop_trail_timer = HARD_DROP_TRAIL_TIME;
+            }
+            
             // Add hard drop points through enhanced scoring system
             self.scoring_system.add_drop_points((drop_distance as u32) * SCORE_HARD_DROP);
             self.score = self.scoring_system.total_score();
             
+            if !self.reduce_motion {
+                self.screen_shake.trigger(HARD_DROP_SHAKE.0, HARD_DROP_SHAKE.1);
+            }
+            
             // Immediately lock the piece after hard drop - no lock delay
             self.lock_current_piece();
         }
     }
     
+    /// Sonic drop: move the piece straight to its landing position without locking it
+    ///
+    /// Lock delay starts as normal, so the piece can still be slid or rotated.
+    /// Returns true if the piece moved.
+    pub fn sonic_drop(&mut self) -> bool {
+        let Some(ghost_piece) = self.calculate_ghost_piece() else {
+            // Already resting on the stack - just make sure lock delay is running
+            self.update_lock_state_for_current_piece();
+            return false;
+        };
+        
+        let drop_distance = self.current_piece.as_ref()
+            .map_or(0, |piece| ghost_piece.position.1 - piece.position.1);
+        self.current_piece = Some(ghost_piece);
+        self.last_action_was_rotation = false;
+        
+        // Scored like the equivalent soft drop
+        self.scoring_system.add_drop_points(drop_distance as u32 * SCORE_SOFT_DROP);
+        self.score = self.scoring_system.total_score();
+        
+        self.update_lock_state_for_current_piece();
+        true
+    }
+    
+    /// Switch rotation systems, re-spawning the current piece with the new system's shapes
+    ///
+    /// Meant for setting up a game; a piece mid-fall returns to the spawn position.
+    pub fn set_rotation_system(&mut self, kind: RotationSystemKind) {
+        self.rotation_system = kind.create();
+        if let Some(piece_type) = self.current_piece.as_ref().map(|piece| piece.piece_type) {
+            self.current_piece = Some(self.rotation_system.spawn_piece(piece_type));
+        }
+    }
+    
     /// Pause/unpause the game
     pub fn toggle_pause(&mut self) {
         match self.state {
@@ -536,10 +1659,85 @@ impl Game {
             _ => {}, // Can't pause in other states
         }
     }
+
+    /// Pause the game if it is being played, without ever resuming it
+    ///
+    /// Returns true if the game was paused by this call.
+    pub fn request_pause(&mut self) -> bool {
+        if self.state == GameState::Playing {
+            self.state = GameState::Paused;
+            true
+        } else {
+            false
+        }
+    }
     
     /// Reset the game
     pub fn reset(&mut self) {
-        *self = Self::new();
+        self.restart_with_seed(rand::random());
+    }
+    
+    /// Start this game over with the exact piece sequence it was dealt the first time
+    pub fn retry_same_seed(&mut self) {
+        self.restart_with_seed(self.initial_seed);
+    }
+    
+    /// Start a fresh game with the same settings, dealing pieces from `seed`
+    fn restart_with_seed(&mut self, seed: u64) {
+        // Keep the chosen starting level, cap, mode, piece limit, line goal, raced run, template, randomizer, rotation system, gravity curve, lines per level and difficulty across restarts
+        let max_level = self.max_level;
+        let mode = self.mode;
+        let piece_limit = self.piece_limit;
+        let line_goal = self.line_goal;
+        let ghost_race = self.ghost_race.take();
+        let hold_enabled = self.hold_enabled;
+        let hold_cooldown = self.hold_cooldown;
+        let spawn_grace = self.spawn_grace;
+        let (ghost_blocks_enabled, ghost_block_every_n_lines, ghost_block_max) = (self.ghost_blocks_enabled, self.ghost_block_every_n_lines, self.ghost_block_max);
+        let difficulty = self.difficulty;
+        let gravity_curve = self.gravity_curve;
+        let start_level = self.start_level;
+        let first_piece_no_sz = self.first_piece_no_sz;
+        let rotation_system = self.rotation_system.kind();
+        let template = self.template.take();
+        let lines_per_level = self.board.lines_per_level();
+        *self = Self::new_with_randomizer(self.randomizer.reseeded(seed, first_piece_no_sz));
+        self.template = template;
+        self.board.set_lines_per_level(lines_per_level);
+        self.first_piece_no_sz = first_piece_no_sz;
+        self.start_level = start_level;
+        self.max_level = max_level;
+        self.mode = mode;
+        self.piece_limit = piece_limit;
+        self.line_goal = line_goal;
+        self.replay = Replay::new(mode);
+        self.ghost_race = ghost_race;
+        self.hold_enabled = hold_enabled;
+        self.hold_cooldown = hold_cooldown;
+        self.spawn_grace = spawn_grace;
+        self.ghost_blocks_enabled = ghost_blocks_enabled;
+        self.ghost_block_every_n_lines = ghost_block_every_n_lines;
+        self.ghost_block_max = ghost_block_max;
+        self.set_rotation_system(rotation_system);
+        self.mirror_first_piece_for_mode();
+        self.gravity_curve = gravity_curve;
+        self.set_difficulty(difficulty);
+        if let Some(rows) = mode.cheese_rows() {
+            self.add_cheese_rows(rows);
+        }
+    }
+    
+    /// Load a setup template to practice building
+    pub fn load_template<P: AsRef<Path>>(&mut self, path: P) -> Result<(), Box<dyn std::error::Error>> {
+        let template = TemplateOverlay::load_from_file(path)?;
+        log::info!("Loaded template '{}' with {} cells", template.name, template.cells.len());
+        self.template = Some(template);
+        Ok(())
+    }
+    
+    /// How many of the template's cells are filled (0 without a template)
+    pub fn template_progress(&self) -> usize {
+        self.template.as_ref().map_or(0, |template| template.filled_count(&self.board))
     }
     
     /// Toggle legacy mode (inspired by Pajitnov's original terminal version)
@@ -553,9 +1751,105 @@ impl Game {
         self.legacy_mode
     }
     
-    /// Get current level
+    /// Get current level, offset by the starting level and limited by the level cap
     pub fn level(&self) -> u32 {
-        self.board.level()
+        effective_level(self.start_level, self.board.lines_cleared(), self.board.lines_per_level(), self.max_level)
+    }
+    
+    /// Lines left before the next level, or `None` once the level cap is reached
+    pub fn lines_until_next_level(&self) -> Option<u32> {
+        self.board.lines_until_next_level(self.start_level, self.max_level)
+    }
+    
+    /// How far the grounded piece is through its lock delay (0.0-1.0), or None while it isn't locking
+    pub fn lock_delay_progress(&self) -> Option<f32> {
+        if !self.piece_is_locking || self.current_piece.is_none() || self.lock_delay <= 0.0 {
+            return None;
+        }
+        Some((self.lock_delay_timer / self.lock_delay).clamp(0.0, 1.0) as f32)
+    }
+    
+    /// Check if the stack is high enough to warn the player
+    pub fn is_in_danger(&self) -> bool {
+        self.board.stack_height() > DANGER_STACK_HEIGHT
+    }
+    
+    /// Get how visible a locked cell should be drawn (1.0 = fully visible)
+    /// Only affects rendering - collision always uses the real board
+    pub fn cell_visibility_alpha(&self, x: i32, y: i32) -> f32 {
+        if self.mode != GameMode::Invisible {
+            return 1.0;
+        }
+        
+        match self.board.lock_time(x, y) {
+            Some(lock_time) => {
+                let elapsed = (self.game_time - lock_time).max(0.0);
+                (1.0 - elapsed / INVISIBLE_FADE_TIME).clamp(0.0, 1.0) as f32
+            }
+            None => 1.0,
+        }
+    }
+    
+    /// Get how strongly a cell should flash from the last lock (0.0 = no flash)
+    /// Only affects rendering
+    pub fn lock_flash_alpha(&self, x: i32, y: i32) -> f32 {
+        if self.lock_flash_timer <= 0.0 || !self.last_locked_cells.contains(&(x, y)) {
+            return 0.0;
+        }
+        (self.lock_flash_timer / LOCK_FLASH_TIME).clamp(0.0, 1.0) as f32
+    }
+    
+    /// Get the last hard drop's trail as its starting cells, rows dropped and color, while it lasts
+    pub fn hard_drop_trail(&self) -> Option<&HardDropTrail> {
+        self.hard_drop_trail.as_ref()
+    }
+    
+    /// Get how opaque the hard drop trail should be drawn (fades from 1.0 to 0.0)
+    /// Only affects rendering
+    pub fn hard_drop_trail_alpha(&self) -> f32 {
+        if self.hard_drop_trail.is_none() {
+            return 0.0;
+        }
+        (self.hard_drop_trail_timer / HARD_DROP_TRAIL_TIME).clamp(0.0, 1.0) as f32
+    }
+    
+    /// Get how opaque the falling piece should be drawn while it fades in after spawning
+    /// Starts at half opacity so a fresh piece is never invisible
+    pub fn spawn_fade_alpha(&self) -> f32 {
+        let progress = (self.piece_lifetime_timer / SPAWN_FADE_TIME).clamp(0.0, 1.0) as f32;
+        0.5 + 0.5 * progress
+    }
+    
+    /// Get the current screen shake offset in pixels (cosmetic only)
+    pub fn screen_shake_offset(&self) -> (f32, f32) {
+        self.screen_shake.offset()
+    }
+    
+    /// Every line clear this game, in order
+    pub fn line_clear_log(&self) -> &[LineClearEvent] {
+        &self.line_clear_log
+    }
+    
+    /// Callouts currently on screen, oldest first
+    pub fn active_notifications(&self) -> &[Notification] {
+        &self.notifications
+    }
+    
+    /// Take every event since the last poll, oldest first
+    pub fn poll_events(&mut self) -> Vec<GameEvent> {
+        std::mem::take(&mut self.events)
+    }
+    
+    /// Show a callout, dropping the oldest once `MAX_NOTIFICATIONS` are on screen
+    fn push_notification(&mut self, notification: Notification) {
+        self.notifications.push(notification);
+        let excess = self.notifications.len().saturating_sub(MAX_NOTIFICATIONS);
+        self.notifications.drain(..excess);
+    }
+    
+    /// Get the current combo (0 on the first clear, +1 for each consecutive clearing placement)
+    pub fn current_combo(&self) -> u32 {
+        self.scoring_system.current_combo().saturating_sub(1)
     }
     
     /// Get lines cleared
@@ -564,43 +1858,102 @@ impl Game {
     }
     
     /// Start line clearing animation
-    pub fn start_line_clear_animation(&mut self, lines: Vec<usize>) {
+    ///
+    /// Returns false if a clear is already animating. The rejected lines are still full,
+    /// so `finish_line_clear` picks them up as a follow-up clear once the current one ends.
+    pub fn start_line_clear_animation(&mut self, lines: Vec<usize>) -> bool {
+        if !self.clearing_lines.is_empty() {
+            log::warn!("Line clear of {:?} requested while {:?} is still animating - deferring", lines, self.clearing_lines);
+            return false;
+        }
+        
+        let count = lines.len() as u32;
+        if let Some(clear_type) = determine_line_clear_type(count, self.last_lock_was_t_spin, false) {
+            self.events.push(GameEvent::LinesCleared { count, clear_type });
+        }
         self.clearing_lines = lines;
         self.clear_animation_timer = 0.0;
+        true
     }
     
     /// Finish line clearing animation and actually clear the lines
     pub fn finish_line_clear(&mut self) {
         if !self.clearing_lines.is_empty() {
-            let lines_cleared = self.board.clear_lines(&self.clearing_lines);
+            let previous_level = self.level();
+            let (lines_cleared, removed) = self.board.clear_lines(&self.clearing_lines);
+            
+            // Cheese rows are always the bottom rows of the board
+            let first_cheese_row = BOARD_HEIGHT + BUFFER_HEIGHT - self.cheese_remaining as usize;
+            let cheese_cleared = removed.iter().filter(|&&y| y >= first_cheese_row).count() as u32;
+            self.cheese_remaining -= cheese_cleared;
+            self.cheese_cleared_total += cheese_cleared;
+
+            let mut animated = self.clearing_lines.clone();
+            animated.sort();
+            debug_assert_eq!(removed, animated, "cleared rows differ from the animated rows");
             self.add_score_for_lines(lines_cleared);
+            self.replay.record_lines(self.game_time, lines_cleared);
+            // Follow-up clears come from ghost blocks, not the piece that spun in
+            self.last_lock_was_t_spin = false;
             
-            // Check for TETRIS celebration (4 lines cleared at once)
-            if lines_cleared == 4 {
+            // Check for TETRIS celebration (4 lines cleared at once); the callout still names it
+            // when motion is reduced
+            if lines_cleared == 4 && !self.reduce_motion {
                 self.tetris_celebration_active = true;
                 self.tetris_celebration_timer = 0.0;
+                self.screen_shake.trigger(TETRIS_SHAKE.0, TETRIS_SHAKE.1);
                 log::info!("TETRIS! 4 lines cleared - starting celebration!");
             }
             
-            // Award ghost block every 4 lines cleared
-            let total_lines_before = self.board.lines_cleared() - lines_cleared;
-            let total_lines_after = self.board.lines_cleared();
-            let ghost_blocks_before = total_lines_before / 4;
-            let ghost_blocks_after = total_lines_after / 4;
-            let ghost_blocks_earned = ghost_blocks_after - ghost_blocks_before;
+            if self.level() > previous_level {
+                self.events.push(GameEvent::LevelUp);
+                self.level_up_banner_active = true;
+                self.level_up_banner_timer = 0.0;
+                self.update_drop_interval();
+                log::info!("Level up! Now level {}", self.level());
+            }
             
-            if ghost_blocks_earned > 0 {
-                self.ghost_blocks_available += ghost_blocks_earned;
-                log::info!("Ghost block earned! {} available", self.ghost_blocks_available);
+            // Emptying the board outranks every other clear
+            if PerfectClearDetector::check_perfect_clear(&self.board, lines_cleared).is_some() {
+                self.events.push(GameEvent::PerfectClear);
+                self.perfect_clear_celebration_active = true;
+                self.perfect_clear_celebration_timer = 0.0;
+                log::info!("PERFECT CLEAR! Board emptied - starting celebration!");
             }
             
+            self.award_ghost_blocks(self.board.lines_cleared() - lines_cleared, self.board.lines_cleared());
+            
             self.clearing_lines.clear();
             self.clear_animation_timer = 0.0;
+            
+            // Line-goal games are won the moment the goal is reached
+            if self.lines_remaining() == Some(0) {
+                log::info!("{} lines cleared in {:.2}s - sprint complete", self.lines_cleared(), self.game_time);
+                self.replay.finish_time = Some(self.game_time);
+                self.state = GameState::Finished;
+                self.events.push(GameEvent::Finished);
+                return;
+            }
+            
+            // Drills push in fresh cheese for every row dug out, which can top the stack out
+            if cheese_cleared > 0 && self.mode.refills_cheese() {
+                self.add_cheese_rows(cheese_cleared);
+                if self.state != GameState::Playing {
+                    return;
+                }
+            }
+            
+            // Lines completed while this clear was animating get their own clear
+            let deferred = self.board.find_complete_lines();
+            if !deferred.is_empty() {
+                self.start_line_clear_animation(deferred);
+                return;
+            }
         }
         
         // Check game over after clearing lines
         if self.board.is_game_over() {
-            self.state = GameState::GameOver;
+            self.end_game(GameOverReason::TopOut);
             return;
         }
         
@@ -642,25 +1995,42 @@ impl Game {
     
     /// Handle continuous left movement
     pub fn update_left_movement(&mut self, is_held: bool) {
-        if is_held && self.left_move_timer >= HORIZONTAL_MOVE_INTERVAL {
-            self.move_piece(-1, 0);
+        // First move is immediate, the second waits for DAS, then moves repeat every ARR
+        let interval = if self.left_repeat_count >= 2 { self.arr } else { self.das };
+        // With no piece out (during a line clear) the charge is kept for the next one
+        if is_held && self.current_piece.is_some() && self.left_move_timer >= interval {
+            // Only the initial press counts as an input; DAS repeats are free
+            if self.left_repeat_count == 0 {
+                self.move_piece(-1, 0);
+            } else {
+                self.shift_piece(-1, 0);
+            }
             self.left_move_timer = 0.0;
+            self.left_repeat_count += 1;
         }
         
         if !is_held {
-            self.left_move_timer = HORIZONTAL_MOVE_INTERVAL; // Allow immediate move when pressed
+            self.left_move_timer = self.das; // Allow immediate move when pressed
+            self.left_repeat_count = 0;
         }
     }
     
     /// Handle continuous right movement
     pub fn update_right_movement(&mut self, is_held: bool) {
-        if is_held && self.right_move_timer >= HORIZONTAL_MOVE_INTERVAL {
-            self.move_piece(1, 0);
+        let interval = if self.right_repeat_count >= 2 { self.arr } else { self.das };
+        if is_held && self.current_piece.is_some() && self.right_move_timer >= interval {
+            if self.right_repeat_count == 0 {
+                self.move_piece(1, 0);
+            } else {
+                self.shift_piece(1, 0);
+            }
             self.right_move_timer = 0.0;
+            self.right_repeat_count += 1;
         }
         
         if !is_held {
-            self.right_move_timer = HORIZONTAL_MOVE_INTERVAL; // Allow immediate move when pressed
+            self.right_move_timer = self.das; // Allow immediate move when pressed
+            self.right_repeat_count = 0;
         }
     }
     
@@ -677,14 +2047,36 @@ impl Game {
         Ok(())
     }
     
-    /// Load the game state from a file
+    /// Save the game and record the outcome for the UI's save toast
+    pub fn save_with_feedback<P: AsRef<Path>>(&mut self, path: P) -> Result<(), Box<dyn std::error::Error>> {
+        let result = self.save_to_file(path);
+        self.last_save_result = match result {
+            Ok(()) => SaveResult::Saved(self.game_time),
+            Err(_) => SaveResult::Failed(self.game_time),
+        };
+        result
+    }
+    
+    /// Load the game state from a file, upgrading older save formats
     pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
         let json = fs::read_to_string(path)?;
-        let game: Game = serde_json::from_str(&json)?;
+        let game = Self::load_from_str(&json)?;
         log::info!("Game loaded successfully");
         Ok(game)
     }
     
+    /// Parse a saved game, upgrading older save formats
+    pub fn load_from_str(json: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let save: serde_json::Value = serde_json::from_str(json)?;
+        let mut game: Game = serde_json::from_value(save::migrate(save)?)?;
+        // Saves from before gravity easing have no target; keep their current speed
+        if game.target_drop_interval <= 0.0 {
+            game.target_drop_interval = game.drop_interval;
+            game.drop_interval_ease_timer = DROP_INTERVAL_EASE_TIME;
+        }
+        Ok(game)
+    }
+    
     /// Check if a save file exists
     pub fn save_file_exists<P: AsRef<Path>>(path: P) -> bool {
         path.as_ref().exists()
@@ -697,13 +2089,28 @@ impl Game {
             .join("tetris_save.json")
     }
     
+    /// Get the save file path for a slot (1-based)
+    ///
+    /// Slot 1 is the original single save file, so saves from before slots existed still show up.
+    pub fn save_path_for_slot(slot: usize) -> std::path::PathBuf {
+        match slot {
+            0 | 1 => Self::default_save_path(),
+            n => Self::default_save_path().with_file_name(format!("tetris_save_{}.json", n)),
+        }
+    }
+    
+    /// Read the score, level and save time of a save file without loading the whole game
+    pub fn save_metadata<P: AsRef<Path>>(path: P) -> Option<SaveMeta> {
+        save::read_metadata(path.as_ref())
+    }
+    
     /// Get a hash of the current game state for efficient change detection
     pub fn get_state_hash(&self) -> u64 {
         let mut hasher = DefaultHasher::new();
         // Hash key game state components that matter for saves
         self.score.hash(&mut hasher);
         self.board.lines_cleared().hash(&mut hasher);
-        self.board.level().hash(&mut hasher);
+        self.level().hash(&mut hasher);
         self.ghost_blocks_available.hash(&mut hasher);
         // Hash current piece position and type
         if let Some(ref piece) = self.current_piece {
@@ -728,13 +2135,27 @@ impl Game {
         if self.clearing_lines.is_empty() {
             0.0
         } else {
-            (self.clear_animation_timer / LINE_CLEAR_ANIMATION_TIME).min(1.0)
+            (self.clear_animation_timer / self.line_clear_animation_time()).min(1.0)
+        }
+    }
+    
+    /// How long a line clear animates before the rows are removed
+    fn line_clear_animation_time(&self) -> f64 {
+        if self.reduce_motion {
+            REDUCED_LINE_CLEAR_TIME
+        } else {
+            LINE_CLEAR_ANIMATION_TIME
         }
     }
     
     /// Hold the current piece (swap with held piece)
     /// Can only be used once per piece to prevent infinite swapping
     pub fn hold_piece(&mut self) -> bool {
+        // Some rulesets have no hold at all
+        if !self.hold_enabled {
+            return false;
+        }
+        
         // Can't hold if already used for this piece
         if self.hold_used_this_piece {
             return false;
@@ -745,82 +2166,136 @@ impl Game {
             return false;
         }
         
+        // Cooldown rulesets make hold wait a few pieces between uses
+        if self.hold_cooldown_remaining() > 0 {
+            return false;
+        }
+        
         // Mark hold as used for this "piece cycle"
         self.hold_used_this_piece = true;
+        self.pieces_since_hold = 0;
+        // The piece coming out of hold starts with fresh finesse tracking and no half-confirmed drop
+        self.input_count = 0;
+        self.hard_drop_confirm.reset();
         
         if let Some(current) = self.current_piece.take() {
+            let held_rotation = self.held_rotation;
+            self.held_rotation = if self.hold_preserves_rotation { current.rotation } else { RotationState::Spawn };
+            
             match self.held_piece {
                 Some(held_type) => {
                     // Swap current piece with held piece
                     self.held_piece = Some(current.piece_type);
-                    let new_piece = Tetromino::new(held_type);
+                    let mut new_piece = self.rotation_system.spawn_piece(held_type);
+                    if held_rotation != RotationState::Spawn {
+                        let mut rotated = new_piece.clone();
+                        rotated.rotation = held_rotation;
+                        rotated.update_blocks();
+                        // Fall back to spawn orientation if the kept rotation doesn't fit
+                        if self.is_piece_valid(&rotated) {
+                            new_piece = rotated;
+                        }
+                    }
                     
                     // Check if the swapped piece can be placed
                     if self.is_piece_valid(&new_piece) {
                         self.current_piece = Some(new_piece);
                         // Reset lock delay for held piece
-                        self.reset_lock_delay();
+                        if self.hold_resets_lock {
+                            self.reset_lock_delay();
+                        }
                     } else {
                         // Can't place swapped piece - game over
                         self.held_piece = Some(current.piece_type); // Keep the piece in hold
-                        self.state = GameState::GameOver;
+                        self.end_game(GameOverReason::BlockOut);
                         return false;
                     }
                 }
                 None => {
-                    // First time holding - store current piece and spawn next
+                    // First time holding - store current piece and spawn next from the
+                    // same queue spawn_next_piece uses, so the preview stays honest
                     self.held_piece = Some(current.piece_type);
                     // Don't reset hold_used_this_piece when manually spawning in hold context
-                    let new_piece = Tetromino::new(self.next_piece);
-                    self.next_piece = TetrominoType::random();
+                    let new_piece = self.spawn_from_queue(self.next_piece);
+                    self.next_piece = self.randomizer.next_piece();
                     
                     // Check if the new piece can be placed
                     if self.is_piece_valid(&new_piece) {
                         self.current_piece = Some(new_piece);
                         // Reset lock delay for new piece from hold
-                        self.reset_lock_delay();
+                        if self.hold_resets_lock {
+                            self.reset_lock_delay();
+                        }
                     } else {
                         // Game over - can't spawn new piece
-                        self.state = GameState::GameOver;
+                        self.end_game(GameOverReason::BlockOut);
                         return false;
                     }
                 }
             }
         }
         
+        // A hold only swaps pieces; the placement is counted when the piece that came out locks
+        self.holds_used += 1;
         true
     }
     
     /// Check if hold is available for the current piece
     pub fn can_hold(&self) -> bool {
-        !self.hold_used_this_piece && self.current_piece.is_some()
+        self.hold_enabled && !self.hold_used_this_piece && self.current_piece.is_some() && self.hold_cooldown_remaining() == 0
+    }
+    
+    /// Pieces still to spawn before the hold cooldown allows another hold
+    ///
+    /// Always 0 without a cooldown, and before the first hold of a game.
+    pub fn hold_cooldown_remaining(&self) -> u32 {
+        match self.hold_cooldown {
+            Some(cooldown) if self.held_piece.is_some() => cooldown.saturating_sub(self.pieces_since_hold),
+            _ => 0,
+        }
+    }
+    
+    /// Whether hold will be usable once the next piece spawns
+    ///
+    /// Lets the hold panel say whether a locked-out hold is about to come back.
+    pub fn hold_available_next_piece(&self) -> bool {
+        self.hold_enabled && self.hold_cooldown_remaining() <= 1
+    }
+    
+    /// Whether the current piece has room to move down one row
+    pub fn current_piece_can_fall(&self) -> bool {
+        self.current_piece.as_ref().is_some_and(|piece| {
+            let mut test_piece = piece.clone();
+            test_piece.move_by(0, 1);
+            self.is_piece_valid(&test_piece)
+        })
+    }
+    
+    /// Leave the locking state entirely, with a fresh timer and reset allowance
+    fn release_lock_delay(&mut self) {
+        self.piece_is_locking = false;
+        self.lock_delay_timer = 0.0;
+        self.lock_resets = 0;
     }
     
     /// Reset the lock delay timer and state with improved anti-floating logic
     pub fn reset_lock_delay(&mut self) {
         // Always allow reset if piece can actually move down (not grounded)
-        if let Some(ref piece) = self.current_piece {
-            let mut test_piece = piece.clone();
-            test_piece.move_by(0, 1);
-            
-            if self.is_piece_valid(&test_piece) {
-                // Piece can move down - allow reset regardless of reset count
-                self.piece_is_locking = false;
-                self.lock_delay_timer = 0.0;
-                self.lock_resets = 0; // Reset counter since piece can move down
-                log::debug!("Lock delay reset: piece can still fall");
-                return;
-            }
+        if self.current_piece_can_fall() {
+            // Piece can move down - allow reset regardless of reset count
+            self.release_lock_delay();
+            log::debug!("Lock delay reset: piece can still fall");
+            return;
         }
         
         // Piece is grounded - only reset if we haven't exceeded the maximum number of resets
-        if self.lock_resets < MAX_LOCK_RESETS {
+        if self.lock_resets < self.max_lock_resets {
             self.piece_is_locking = false;
             self.lock_delay_timer = 0.0;
             self.lock_resets += 1;
             log::debug!("Lock delay reset #{}: grounded piece gets more time", self.lock_resets);
         } else {
-            log::debug!("Lock delay reset denied: max resets ({}) exceeded, piece will lock soon", MAX_LOCK_RESETS);
+            log::debug!("Lock delay reset denied: max resets ({}) exceeded, piece will lock soon", self.max_lock_resets);
             // Force the piece into locking state if it wasn't already
             if !self.piece_is_locking {
                 self.piece_is_locking = true;
@@ -853,9 +2328,23 @@ impl Game {
         None
     }
     
+    /// Award a ghost block for each `ghost_block_every_n_lines` boundary crossed going from
+    /// `lines_before` to `lines_after` total lines, up to `ghost_block_max` stored
+    fn award_ghost_blocks(&mut self, lines_before: u32, lines_after: u32) {
+        if !self.ghost_blocks_enabled || self.ghost_block_every_n_lines == 0 {
+            return;
+        }
+        
+        let earned = lines_after / self.ghost_block_every_n_lines - lines_before / self.ghost_block_every_n_lines;
+        if earned > 0 {
+            self.ghost_blocks_available = (self.ghost_blocks_available + earned).min(self.ghost_block_max);
+            log::info!("Ghost block earned! {} available", self.ghost_blocks_available);
+        }
+    }
+    
     /// Toggle ghost block placement mode
     pub fn toggle_ghost_block_mode(&mut self) {
-        if self.ghost_blocks_available > 0 {
+        if self.ghost_blocks_enabled && self.ghost_blocks_available > 0 {
             self.ghost_block_placement_mode = !self.ghost_block_placement_mode;
             if self.ghost_block_placement_mode {
                 // Analyze board and find smart positions
@@ -1057,6 +2546,34 @@ impl Game {
         }
     }
     
+    /// Check if the LEVEL N banner is currently showing
+    pub fn level_up_banner_active(&self) -> bool {
+        self.level_up_banner_active
+    }
+    
+    /// Get the LEVEL N banner animation progress (0.0 to 1.0)
+    pub fn get_level_up_banner_progress(&self) -> f64 {
+        if self.level_up_banner_active {
+            (self.level_up_banner_timer / LEVEL_UP_BANNER_TIME).min(1.0)
+        } else {
+            0.0
+        }
+    }
+    
+    /// Check if PERFECT CLEAR celebration is currently active
+    pub fn is_perfect_clear_celebration_active(&self) -> bool {
+        self.perfect_clear_celebration_active
+    }
+    
+    /// Get the PERFECT CLEAR celebration animation progress (0.0 to 1.0)
+    pub fn get_perfect_clear_celebration_progress(&self) -> f64 {
+        if self.perfect_clear_celebration_active {
+            (self.perfect_clear_celebration_timer / PERFECT_CLEAR_CELEBRATION_TIME).min(1.0)
+        } else {
+            0.0
+        }
+    }
+    
     /// Start ghost block throwing animation
     fn start_ghost_throw(&mut self, target_x: i32, target_y: i32) {
         // Calculate starting position (off-screen or from a corner)
@@ -1084,8 +2601,11 @@ impl Game {
         let (target_x, target_y) = self.ghost_throw_target;
         
         // Actually place the block now
-        self.board.set_cell(target_x, target_y, Cell::Filled(macroquad::prelude::Color::new(0.8, 0.8, 1.0, 1.0)));
+        self.board.set_cell(target_x, target_y, Cell::block(macroquad::prelude::Color::new(0.8, 0.8, 1.0, 1.0)));
+        self.board.set_lock_time(target_x, target_y, self.game_time);
         self.ghost_blocks_available -= 1;
+        self.events.push(GameEvent::GhostBlockUsed);
+        self.last_lock_was_t_spin = false;
         
         // Check if this placement creates any complete lines
         let complete_lines = self.board.find_complete_lines();
@@ -1158,32 +2678,25 @@ impl Game {
     
     /// Update drop interval based on current level
     /// Uses a more reasonable progression that doesn't become microscopic
+    ///
+    /// Mid-game speed changes ease in over `DROP_INTERVAL_EASE_TIME`; before play starts the
+    /// new speed applies at once.
     fn update_drop_interval(&mut self) {
-        let level = self.board.level();
-        
-        // Use a more reasonable drop speed progression
-        // Each level increases speed but maintains playable intervals
-        self.drop_interval = match level {
-            1 => 1.0,      // 1 second (slow start)
-            2 => 0.85,     // 850ms
-            3 => 0.72,     // 720ms
-            4 => 0.61,     // 610ms 
-            5 => 0.52,     // 520ms
-            6 => 0.44,     // 440ms
-            7 => 0.37,     // 370ms
-            8 => 0.31,     // 310ms
-            9 => 0.26,     // 260ms
-            10 => 0.22,    // 220ms
-            11 => 0.19,    // 190ms
-            12 => 0.16,    // 160ms
-            13 => 0.13,    // 130ms
-            14 => 0.11,    // 110ms
-            15 => 0.09,    // 90ms
-            _ => 0.08,     // 80ms minimum (very fast but still playable)
-        };
+        let level = self.level();
+        let multiplier = self.difficulty.drop_interval_multiplier();
+        let target = self.gravity_curve.drop_interval(level) * multiplier;
+        
+        if self.state != GameState::Playing {
+            self.drop_interval = target;
+            self.drop_interval_ease_timer = DROP_INTERVAL_EASE_TIME;
+        } else if target != self.target_drop_interval {
+            self.drop_interval_ease_start = self.drop_interval;
+            self.drop_interval_ease_timer = 0.0;
+        }
+        self.target_drop_interval = target;
         
         log::debug!("Updated drop interval for level {} to {:.3}s ({:.1}ms)", 
-                   level, self.drop_interval, self.drop_interval * 1000.0);
+                   level, target, target * 1000.0);
     }
     
     /// Check if the current piece placement qualifies as a T-spin
@@ -1200,29 +2713,9 @@ impl Game {
                 return false;
             }
             
-            // Check if T-piece is in a "3-corner rule" position
-            // For a proper T-spin, at least 3 corners around the T center should be occupied
-            let center_x = piece.position.0;
-            let center_y = piece.position.1;
-            
-            // Check the 4 corner positions around the T-piece center
-            let corners = [
-                (center_x - 1, center_y - 1), // Top-left
-                (center_x + 1, center_y - 1), // Top-right  
-                (center_x - 1, center_y + 1), // Bottom-left
-                (center_x + 1, center_y + 1), // Bottom-right
-            ];
-            
-            let occupied_corners = corners.iter()
-                .filter(|(x, y)| {
-                    // Consider position occupied if it's out of bounds or has a block
-                    !self.board.is_position_valid(*x, *y) || 
-                    self.board.get_cell(*x, *y).map_or(true, |cell| cell.is_filled())
-                })
-                .count();
-            
-            // T-spin if at least 3 corners are occupied
-            occupied_corners >= 3
+            // The rotation system decides whether the position counts (SRS uses the
+            // 3-corner rule; ARS never awards T-spins)
+            self.rotation_system.is_t_spin_position(piece, &self.board, None)
         } else {
             false
         }
@@ -1308,4 +2801,1600 @@ mod tests {
         assert!(!game.can_hold());
         assert!(!game.hold_piece());
     }
+    
+    #[test]
+    fn test_upcoming_pieces_is_pure_lookahead() {
+        let mut game = Game::new();
+        
+        // Advance until the next piece is the first of a fresh bag
+        let remaining_in_bag = |game: &Game| match &game.randomizer {
+            Randomizer::SevenBag(bag) => bag.remaining_in_bag(),
+            other => panic!("expected a 7-bag, got {:?}", other.kind()),
+        };
+        while remaining_in_bag(&game) != 6 {
+            game.spawn_next_piece();
+        }
+        
+        let upcoming = game.upcoming_pieces(7);
+        assert_eq!(upcoming, game.upcoming_pieces(7));
+        
+        let mut sorted = upcoming.clone();
+        sorted.sort_by_key(|p| *p as u8);
+        let mut all = TetrominoType::all().to_vec();
+        all.sort_by_key(|p| *p as u8);
+        assert_eq!(sorted, all);
+        
+        // Peeking must not have advanced the real sequence
+        for expected in upcoming {
+            game.spawn_next_piece();
+            assert_eq!(game.current_piece.as_ref().unwrap().piece_type, expected);
+        }
+    }
+    
+    /// Put the game into a clear animation with the stack reaching into the buffer zone
+    fn start_topped_out_clear(game: &mut Game) {
+        game.state = GameState::Playing;
+        game.current_piece = None;
+        let bottom = (BOARD_HEIGHT + BUFFER_HEIGHT - 1) as i32;
+        for x in 0..BOARD_WIDTH as i32 {
+            game.board.set_cell(x, bottom, Cell::block(macroquad::prelude::RED));
+        }
+        // A block at the very top stays in the buffer even after the clear shifts it down
+        game.board.set_cell(0, 0, Cell::block(macroquad::prelude::RED));
+        let lines = game.board.find_complete_lines();
+        game.start_line_clear_animation(lines);
+    }
+    
+    #[test]
+    fn test_overlapping_line_clear_is_deferred() {
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        game.current_piece = None;
+        let bottom = BOARD_HEIGHT + BUFFER_HEIGHT - 1;
+        for x in 0..BOARD_WIDTH as i32 {
+            game.board.set_cell(x, bottom as i32, Cell::block(macroquad::prelude::RED));
+        }
+        assert!(game.start_line_clear_animation(game.board.find_complete_lines()));
+        
+        // A second line completes mid-animation, e.g. from a ghost block throw
+        for x in 0..BOARD_WIDTH as i32 {
+            game.board.set_cell(x, bottom as i32 - 1, Cell::block(macroquad::prelude::BLUE));
+        }
+        assert!(!game.start_line_clear_animation(game.board.find_complete_lines()));
+        assert_eq!(game.get_clearing_lines(), &[bottom]);
+        
+        // Finishing the first clear starts the deferred one on its shifted row
+        game.finish_line_clear();
+        assert_eq!(game.lines_cleared(), 1);
+        assert_eq!(game.get_clearing_lines(), &[bottom]);
+        
+        game.finish_line_clear();
+        assert_eq!(game.lines_cleared(), 2);
+        assert!(!game.is_clearing_lines());
+        assert_eq!(game.board.filled_cells_count(), 0);
+    }
+    
+    #[test]
+    fn test_topout_finishes_clear_animation_first() {
+        let mut game = Game::new();
+        game.topout_behavior = TopOutBehavior::FinishAnimation;
+        start_topped_out_clear(&mut game);
+        
+        game.update(LINE_CLEAR_ANIMATION_TIME / 2.0);
+        assert_eq!(game.state, GameState::Playing);
+        assert!(game.is_clearing_lines());
+        
+        game.update(LINE_CLEAR_ANIMATION_TIME);
+        assert_eq!(game.state, GameState::GameOver);
+        assert!(!game.is_clearing_lines());
+    }
+    
+    #[test]
+    fn test_game_over_reasons() {
+        // Block out: the spawn area is already occupied
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        for y in 0..(BUFFER_HEIGHT + 2) as i32 {
+            for x in 0..BOARD_WIDTH as i32 {
+                game.board.set_cell(x, y, Cell::block(macroquad::prelude::RED));
+            }
+        }
+        game.spawn_next_piece();
+        assert_eq!(game.state, GameState::GameOver);
+        assert_eq!(game.game_over_reason, Some(GameOverReason::BlockOut));
+        
+        // Lock out: the piece locks without ever entering the playfield
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        let piece = game.current_piece.clone().unwrap();
+        assert!(game.board.is_lock_out(&piece));
+        game.lock_current_piece();
+        assert_eq!(game.game_over_reason, Some(GameOverReason::LockOut));
+        
+        // Top out: the piece reaches into the playfield but its top pokes into the buffer
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        let mut piece = Tetromino::new(TetrominoType::I);
+        piece.rotate_clockwise();
+        piece.position.1 = BUFFER_HEIGHT as i32;
+        assert!(!game.board.is_lock_out(&piece));
+        assert!(piece.absolute_blocks().iter().any(|&(_, y)| y < BUFFER_HEIGHT as i32));
+        game.current_piece = Some(piece);
+        game.lock_current_piece();
+        assert_eq!(game.state, GameState::GameOver);
+        assert_eq!(game.game_over_reason, Some(GameOverReason::TopOut));
+        
+        // A fresh game has no reason yet
+        game.reset();
+        assert_eq!(game.game_over_reason, None);
+    }
+    
+    #[test]
+    fn test_topout_immediate_cuts_animation() {
+        let mut game = Game::new();
+        game.topout_behavior = TopOutBehavior::Immediate;
+        start_topped_out_clear(&mut game);
+        
+        game.update(LINE_CLEAR_ANIMATION_TIME / 2.0);
+        assert_eq!(game.state, GameState::GameOver);
+    }
+    
+    #[test]
+    fn test_new_with_level_offsets_speed_and_scoring() {
+        let mut game = Game::new_with_level(9);
+        assert_eq!(game.level(), 9);
+        assert_eq!(game.drop_interval, Game::new_with_level(9).drop_interval);
+        assert!((game.drop_interval - 0.26).abs() < f64::EPSILON);
+        
+        // Leave a stray block so the clear isn't a perfect clear
+        game.current_piece = None;
+        game.board.set_cell(0, (BOARD_HEIGHT + BUFFER_HEIGHT - 2) as i32, Cell::block(macroquad::prelude::RED));
+        game.add_score_for_lines(1);
+        assert_eq!(game.score, LineClearType::Single.base_score() * 9);
+    }
+    
+    #[test]
+    fn test_level_cap_limits_effective_level() {
+        let mut game = Game::new_with_level(12);
+        game.max_level = Some(10);
+        assert_eq!(game.level(), 10);
+        assert_eq!(game.lines_until_next_level(), None);
+        
+        game.max_level = None;
+        assert_eq!(game.lines_until_next_level(), Some(LINES_PER_LEVEL));
+        let rows: Vec<usize> = (0..(LINES_PER_LEVEL * 2) as usize).collect();
+        game.board.clear_lines(&rows);
+        assert_eq!(game.level(), 14);
+    }
+    
+    #[test]
+    fn test_expert_is_faster_than_normal() {
+        let normal = Game::new();
+        let mut expert = Game::new();
+        expert.set_difficulty(Difficulty::Expert);
+        
+        assert_eq!(normal.level(), 1);
+        assert_eq!(expert.level(), 1);
+        assert!(expert.drop_interval < normal.drop_interval);
+        assert!(expert.lock_delay < normal.lock_delay);
+    }
+    
+    #[test]
+    fn test_piece_limited_game_ends_in_victory() {
+        let mut game = Game::new_piece_limited(3);
+        game.state = GameState::Playing;
+        assert_eq!(game.pieces_remaining(), Some(3));
+        assert_eq!(Game::new().pieces_remaining(), None);
+        
+        game.hard_drop();
+        game.hard_drop();
+        assert_eq!(game.pieces_remaining(), Some(1));
+        assert_eq!(game.state, GameState::Playing);
+        
+        game.hard_drop();
+        assert_eq!(game.pieces_placed, 3);
+        assert_eq!(game.pieces_remaining(), Some(0));
+        assert_eq!(game.state, GameState::Finished);
+        assert!(game.current_piece.is_none());
+    }
+    
+    #[test]
+    fn test_finesse_faults_count_wasted_inputs() {
+        let mut game = Game::new();
+        game.current_piece = Some(Tetromino::new(TetrominoType::I));
+        
+        // Tapping all the way to the wall costs more than holding the direction
+        while game.move_piece(-1, 0) {}
+        game.hard_drop();
+        assert!(!game.last_placement_was_optimal());
+        assert_eq!(game.finesse_faults, 1);
+        
+        // A held direction is a single input no matter how far the piece slides
+        game.current_piece = Some(Tetromino::new(TetrominoType::I));
+        game.input_count = 0;
+        game.update_left_movement(true);
+        for _ in 0..10 {
+            game.left_move_timer = game.das;
+            game.update_left_movement(true);
+        }
+        game.hard_drop();
+        assert!(game.last_placement_was_optimal());
+        assert_eq!(game.finesse_faults, 1);
+    }
+    
+    #[test]
+    fn test_hold_without_lock_reset_keeps_lock_delay() {
+        let ground = |game: &mut Game| {
+            while game.drop_current_piece() {}
+            game.lock_delay_timer = 0.3;
+            game.lock_resets = 5;
+        };
+        
+        let mut game = Game::new();
+        game.hold_resets_lock = false;
+        ground(&mut game);
+        assert!(game.piece_is_locking);
+        assert!(game.hold_piece());
+        assert!(game.piece_is_locking);
+        assert_eq!(game.lock_delay_timer, 0.3);
+        assert_eq!(game.lock_resets, 5);
+        
+        // The default ruleset refreshes lock delay on hold
+        let mut game = Game::new();
+        ground(&mut game);
+        assert!(game.hold_piece());
+        assert!(!game.piece_is_locking);
+        assert_eq!(game.lock_delay_timer, 0.0);
+        assert_eq!(game.lock_resets, 0);
+    }
+    
+    #[test]
+    fn test_lock_delay_progress_while_grounded() {
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        assert_eq!(game.lock_delay_progress(), None);
+        
+        while game.drop_current_piece() {}
+        assert!(game.piece_is_locking);
+        let start = game.lock_delay_progress().unwrap();
+        
+        game.update(game.lock_delay / 4.0);
+        let later = game.lock_delay_progress().unwrap();
+        assert!(later > start, "{} should exceed {}", later, start);
+        assert!(later < 1.0);
+    }
+    
+    #[test]
+    fn test_sonic_drop_lands_without_locking() {
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        let ghost = game.calculate_ghost_piece().unwrap();
+        
+        assert!(game.sonic_drop());
+        let piece = game.current_piece.as_ref().expect("sonic drop must not lock the piece");
+        assert_eq!(piece.position, ghost.position);
+        assert!(game.piece_is_locking);
+        assert_eq!(game.pieces_placed, 0);
+        
+        // Already on the floor, so a second sonic drop goes nowhere
+        assert!(!game.sonic_drop());
+        assert!(game.current_piece.is_some());
+    }
+    
+    /// A save in the original, unversioned layout
+    fn v1_save_fixture() -> serde_json::Value {
+        const V1_FIELDS: &[&str] = &[
+            "board", "clear_animation_timer", "clearing_lines", "current_piece", "drop_interval", "drop_timer",
+            "game_time", "ghost_block_blink_timer", "ghost_block_cursor", "ghost_block_placement_mode",
+            "ghost_blocks_available", "ghost_cursor_index", "ghost_smart_positions", "ghost_throw_active",
+            "ghost_throw_start", "ghost_throw_target", "ghost_throw_timer", "held_piece", "hold_used_this_piece",
+            "last_action_was_rotation", "left_move_timer", "legacy_mode", "lock_delay_timer", "lock_resets",
+            "next_piece", "piece_is_locking", "piece_just_locked", "piece_lifetime_timer", "right_move_timer",
+            "rotation_system", "score", "scoring_system", "soft_drop_timer", "state", "tetris_celebration_active",
+            "tetris_celebration_timer",
+        ];
+        
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        game.score = 4321;
+        let mut save = serde_json::to_value(&game).unwrap();
+        let fields = save.as_object_mut().unwrap();
+        fields.retain(|key, _| V1_FIELDS.contains(&key.as_str()));
+        // Boards were a dense grid of cells, without lock times
+        fields["board"] = serde_json::json!({
+            "grid": vec![vec!["Empty"; BOARD_WIDTH]; BOARD_HEIGHT + BUFFER_HEIGHT],
+            "lines_cleared": 0,
+            "level": 1,
+        });
+        save
+    }
+    
+    #[test]
+    fn test_v1_save_loads_with_defaults() {
+        let json = serde_json::to_string(&v1_save_fixture()).unwrap();
+        let game = Game::load_from_str(&json).unwrap();
+        
+        assert_eq!(game.version, SAVE_VERSION);
+        assert_eq!(game.score, 4321);
+        assert_eq!(game.state, GameState::Playing);
+        assert_eq!(game.mode, GameMode::Marathon);
+        assert_eq!(game.start_level, 1);
+        assert_eq!(game.difficulty, Difficulty::Normal);
+        assert_eq!(game.lock_delay, Difficulty::Normal.lock_delay());
+        assert!(game.hold_enabled);
+        assert_eq!(game.game_over_reason, None);
+        assert_eq!(game.randomizer.peek(7).len(), 7);
+        
+        // Re-saving writes the current version, which loads unchanged
+        let resaved = serde_json::to_string(&game).unwrap();
+        assert_eq!(Game::load_from_str(&resaved).unwrap().score, 4321);
+    }
+    
+    #[test]
+    fn test_save_slots_keep_separate_metadata() {
+        let dir = std::env::temp_dir().join(format!("rust_tetris_slots_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        
+        let slot_paths: Vec<std::path::PathBuf> = (1..=crate::game::SAVE_SLOT_COUNT)
+            .map(|slot| dir.join(Game::save_path_for_slot(slot).file_name().unwrap()))
+            .collect();
+        for (i, path) in slot_paths.iter().enumerate() {
+            assert!(!slot_paths[..i].contains(path), "slot {} shares a file", i + 1);
+            
+            let mut game = Game::new_with_level(i as u32 + 2);
+            game.score = 1000 * (i as u32 + 1);
+            game.save_to_file(path).unwrap();
+        }
+        
+        for (i, path) in slot_paths.iter().enumerate() {
+            let meta = Game::save_metadata(path).unwrap();
+            assert_eq!(meta.score, 1000 * (i as u32 + 1));
+            assert_eq!(meta.level, i as u32 + 2);
+            assert_eq!(meta.lines_cleared, 0);
+            assert!(meta.saved_at.is_some());
+        }
+        assert_eq!(Game::save_metadata(dir.join("missing.json")), None);
+        
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    
+    #[test]
+    fn test_rotation_system_survives_reset_and_save() {
+        let mut game = Game::new();
+        game.set_rotation_system(RotationSystemKind::Ars);
+        assert_eq!(game.current_piece.as_ref().unwrap().rotation_system, RotationSystemKind::Ars);
+        
+        game.reset();
+        assert_eq!(game.rotation_system.kind(), RotationSystemKind::Ars);
+        assert_eq!(game.current_piece.as_ref().unwrap().rotation_system, RotationSystemKind::Ars);
+        
+        let json = serde_json::to_string(&game).unwrap();
+        let loaded = Game::load_from_str(&json).unwrap();
+        assert_eq!(loaded.rotation_system.kind(), RotationSystemKind::Ars);
+        assert_eq!(loaded.clone().rotation_system.kind(), RotationSystemKind::Ars);
+    }
+    
+    #[test]
+    fn test_no_gravity_during_countdown() {
+        let mut game = Game::new();
+        assert!(matches!(game.state, GameState::Countdown { .. }));
+        let start = game.current_piece.as_ref().unwrap().position;
+        
+        // Several seconds' worth of gravity, but less than the countdown
+        for _ in 0..10 {
+            game.update(COUNTDOWN_TIME / 12.0);
+        }
+        assert!(matches!(game.state, GameState::Countdown { .. }));
+        assert_eq!(game.current_piece.as_ref().unwrap().position, start);
+        assert_eq!(game.game_time, 0.0);
+        
+        game.update(COUNTDOWN_TIME);
+        assert_eq!(game.state, GameState::Playing);
+        
+        // Pausing and resuming goes straight back to play
+        game.toggle_pause();
+        game.toggle_pause();
+        assert_eq!(game.state, GameState::Playing);
+    }
+    
+    #[test]
+    fn test_spawns_match_earlier_preview() {
+        for kind in crate::tetromino::RandomizerKind::all() {
+            let mut game = Game::new_with_randomizer(kind.create(2024));
+            let preview = game.upcoming_pieces(20);
+            
+            for (i, expected) in preview.into_iter().enumerate() {
+                game.spawn_next_piece();
+                let spawned = game.current_piece.as_ref().unwrap().piece_type;
+                assert_eq!(spawned, expected, "{:?} spawn {} differed from its preview", kind, i);
+            }
+        }
+    }
+    
+    #[test]
+    fn test_seeded_games_never_open_with_s_or_z() {
+        for seed in 0..200 {
+            let mut game = Game::new_with_randomizer(crate::tetromino::RandomizerKind::SevenBag.create(seed));
+            let first = game.current_piece.as_ref().unwrap().piece_type;
+            assert!(!matches!(first, TetrominoType::S | TetrominoType::Z), "seed {} opened with {:?}", seed, first);
+            
+            // Restarts keep the rule
+            game.reset();
+            let first = game.current_piece.as_ref().unwrap().piece_type;
+            assert!(!matches!(first, TetrominoType::S | TetrominoType::Z), "restart opened with {:?}", first);
+        }
+    }
+    
+    #[test]
+    fn test_hold_disabled_never_swaps() {
+        let mut game = Game::new();
+        game.hold_enabled = false;
+        let piece_before = game.current_piece.clone();
+        
+        assert!(!game.can_hold());
+        for _ in 0..3 {
+            assert!(!game.hold_piece());
+        }
+        assert_eq!(game.current_piece, piece_before);
+        assert_eq!(game.held_piece, None);
+        assert!(!game.hold_used_this_piece);
+    }
+    
+    #[test]
+    fn test_combo_counts_consecutive_clears_and_resets() {
+        let mut game = Game::new();
+        game.board.set_cell(0, (BOARD_HEIGHT + BUFFER_HEIGHT - 1) as i32, Cell::block(macroquad::prelude::RED));
+        
+        assert_eq!(game.current_combo(), 0);
+        game.add_score_for_lines(1);
+        assert_eq!(game.current_combo(), 0);
+        game.add_score_for_lines(2);
+        assert_eq!(game.current_combo(), 1);
+        game.add_score_for_lines(1);
+        assert_eq!(game.current_combo(), 2);
+        
+        // Locking a piece without clearing a line breaks the combo
+        game.hard_drop();
+        assert_eq!(game.current_combo(), 0);
+    }
+    
+    #[test]
+    fn test_force_lock_warns_only_for_movable_piece() {
+        let mut game = Game::new();
+        
+        // Freshly spawned piece can still fall - force-locking it would be a bug
+        assert_eq!(game.force_lock_log_level(), log::Level::Warn);
+        
+        // Grounded piece reaching the lifetime cap is expected
+        while game.drop_current_piece() {}
+        assert_eq!(game.force_lock_log_level(), log::Level::Debug);
+    }
+    
+    #[test]
+    fn test_invisible_mode_only_hides_rendering() {
+        let mut game = Game::new();
+        game.mode = GameMode::Invisible;
+        game.hard_drop();
+        
+        let (x, y) = (0..BOARD_WIDTH as i32)
+            .flat_map(|x| (0..(BOARD_HEIGHT + BUFFER_HEIGHT) as i32).map(move |y| (x, y)))
+            .find(|&(x, y)| game.board.get_cell(x, y).is_some_and(|cell| cell.is_filled()))
+            .expect("hard drop should lock a piece");
+        assert_eq!(game.cell_visibility_alpha(x, y), 1.0);
+        
+        game.game_time += INVISIBLE_FADE_TIME * 2.0;
+        assert_eq!(game.cell_visibility_alpha(x, y), 0.0);
+        
+        // Fully faded cells are still solid
+        assert!(game.board.get_cell(x, y).unwrap().is_filled());
+        assert!(!game.board.is_position_valid(x, y));
+    }
+
+    #[test]
+    fn test_request_pause_only_pauses_while_playing() {
+        let mut game = Game::new();
+        assert!(matches!(game.state, GameState::Countdown { .. }));
+        assert!(!game.request_pause());
+        assert!(matches!(game.state, GameState::Countdown { .. }));
+
+        game.state = GameState::Playing;
+        assert!(game.request_pause());
+        assert_eq!(game.state, GameState::Paused);
+
+        // Asking again never unpauses
+        assert!(!game.request_pause());
+        assert_eq!(game.state, GameState::Paused);
+
+        game.state = GameState::GameOver;
+        assert!(!game.request_pause());
+        assert_eq!(game.state, GameState::GameOver);
+    }
+
+    #[test]
+    fn test_ghost_off_still_computes_landing() {
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        game.ghost_style = GhostStyle::Off;
+        
+        let ghost = game.calculate_ghost_piece().expect("hidden ghost should still find the landing");
+        let mut below = ghost.clone();
+        below.move_by(0, 1);
+        assert!(game.is_piece_valid(&ghost));
+        assert!(!game.is_piece_valid(&below));
+        assert!(ghost.position.1 > game.current_piece.as_ref().unwrap().position.1);
+        
+        // Sonic drop relies on the same landing
+        assert!(game.sonic_drop());
+        assert_eq!(game.current_piece.as_ref().unwrap().position, ghost.position);
+    }
+
+    #[test]
+    fn test_lock_flash_marks_locked_cells_then_clears() {
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        let landing = game.calculate_ghost_piece().unwrap().absolute_blocks();
+        
+        game.hard_drop();
+        assert_eq!(game.last_locked_cells, landing);
+        let (x, y) = landing[0];
+        assert!(game.lock_flash_alpha(x, y) > 0.0);
+        
+        game.update(LOCK_FLASH_TIME * 2.0);
+        assert!(game.last_locked_cells.is_empty());
+        assert_eq!(game.lock_flash_alpha(x, y), 0.0);
+    }
+
+    #[test]
+    fn test_clearing_last_line_awards_perfect_clear() {
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        let bottom = BOARD_HEIGHT + BUFFER_HEIGHT - 1;
+        for x in 0..BOARD_WIDTH as i32 {
+            game.board.set_cell(x, bottom as i32, Cell::block(macroquad::prelude::RED));
+        }
+        
+        let score_before = game.score;
+        game.start_line_clear_animation(vec![bottom]);
+        game.finish_line_clear();
+        
+        let level = game.level();
+        let single = LineClearType::Single.base_score() * level;
+        let bonus = crate::scoring::PerfectClearType::Single.base_bonus() * level;
+        assert_eq!(game.score - score_before, single + bonus);
+        assert!(game.is_perfect_clear_celebration_active());
+        
+        game.update(PERFECT_CLEAR_CELEBRATION_TIME + 0.1);
+        assert!(!game.is_perfect_clear_celebration_active());
+    }
+
+    #[test]
+    fn test_save_with_feedback_records_outcome() {
+        let mut game = Game::new();
+        assert_eq!(game.last_save_result, SaveResult::None);
+        game.game_time = 12.5;
+        
+        let path = std::env::temp_dir().join(format!("tetris_feedback_{}.json", std::process::id()));
+        game.save_with_feedback(&path).unwrap();
+        assert_eq!(game.last_save_result, SaveResult::Saved(12.5));
+        let _ = fs::remove_file(&path);
+        
+        // A directory can't be written as a file
+        assert!(game.save_with_feedback(std::env::temp_dir()).is_err());
+        assert_eq!(game.last_save_result, SaveResult::Failed(12.5));
+    }
+
+    #[test]
+    fn test_first_hold_keeps_preview_in_sync() {
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        let current = game.current_piece.as_ref().unwrap().piece_type;
+        let upcoming = std::iter::once(game.next_piece).chain(game.randomizer.peek(6)).collect::<Vec<_>>();
+        
+        assert!(game.hold_piece());
+        assert_eq!(game.held_piece, Some(current));
+        assert_eq!(game.current_piece.as_ref().unwrap().piece_type, upcoming[0]);
+        
+        // Every later spawn follows the preview shown before the hold
+        let mut dealt = vec![game.next_piece];
+        dealt.extend(game.randomizer.peek(5));
+        assert_eq!(dealt, upcoming[1..]);
+    }
+    
+    #[test]
+    fn test_hold_preserves_rotation_flag() {
+        for preserve in [false, true] {
+            let mut game = Game::new_with_randomizer(crate::tetromino::RandomizerKind::SevenBag.create(3));
+            game.state = GameState::Playing;
+            game.hold_preserves_rotation = preserve;
+            
+            // Drop the piece a few rows so it has room to turn, then hold it rotated
+            game.move_piece(0, 3);
+            assert!(game.rotate_piece_clockwise());
+            let held_type = game.current_piece.as_ref().unwrap().piece_type;
+            assert!(game.hold_piece());
+            
+            // Next piece: swap the held one back out
+            game.hold_used_this_piece = false;
+            assert!(game.hold_piece());
+            let piece = game.current_piece.as_ref().unwrap();
+            assert_eq!(piece.piece_type, held_type);
+            assert_eq!(piece.rotation, if preserve { RotationState::Right } else { RotationState::Spawn }, "preserve = {}", preserve);
+        }
+    }
+
+    #[test]
+    fn test_sprint_records_clears_and_races_best() {
+        let mut game = Game::new_line_goal(2);
+        game.state = GameState::Playing;
+        let mut best = Replay::new(GameMode::Sprint);
+        best.record_lines(1.0, 1);
+        best.record_lines(3.0, 1);
+        game.ghost_race = Some(best);
+        
+        let bottom = BOARD_HEIGHT + BUFFER_HEIGHT - 1;
+        let clear_bottom_row = |game: &mut Game| {
+            for x in 0..BOARD_WIDTH as i32 {
+                game.board.set_cell(x, bottom as i32, Cell::block(macroquad::prelude::RED));
+            }
+            game.start_line_clear_animation(vec![bottom]);
+            game.finish_line_clear();
+        };
+        
+        game.game_time = 2.0;
+        assert_eq!(game.ghost_race_delta(), Some(-1));
+        clear_bottom_row(&mut game);
+        assert_eq!(game.ghost_race_delta(), Some(0));
+        assert_eq!(game.lines_remaining(), Some(1));
+        
+        game.game_time = 2.5;
+        clear_bottom_row(&mut game);
+        assert_eq!(game.state, GameState::Finished);
+        assert_eq!(game.replay.line_clear_times, vec![2.0, 2.5]);
+        assert_eq!(game.replay.finish_time, Some(2.5));
+        assert!(game.records_best_sprint());
+        
+        // The same run with a piece filter is only practice
+        game.randomizer = Randomizer::Filtered(crate::tetromino::FilteredGenerator::new(1, vec![TetrominoType::I]));
+        assert!(game.replay.is_better_than(game.ghost_race.as_ref()));
+        assert!(!game.records_best_sprint());
+    }
+
+    #[test]
+    fn test_hard_drop_shakes_then_settles() {
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        assert_eq!(game.screen_shake_offset(), (0.0, 0.0));
+        
+        game.hard_drop();
+        let (x, y) = game.screen_shake_offset();
+        assert!(x != 0.0 || y != 0.0);
+        
+        game.update(HARD_DROP_SHAKE.1 + 0.01);
+        assert_eq!(game.screen_shake_offset(), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_tetrises_build_attack_and_cancel_garbage() {
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        let bottom = (BOARD_HEIGHT + BUFFER_HEIGHT - 1) as i32;
+        // A stray block on the floor keeps these clears from being perfect clears
+        game.board.set_cell(0, bottom, Cell::block(macroquad::prelude::RED));
+        
+        let tetris = |game: &mut Game| {
+            let rows: Vec<usize> = (1..=4).map(|up| (bottom - up) as usize).collect();
+            for &y in &rows {
+                for x in 0..BOARD_WIDTH as i32 {
+                    game.board.set_cell(x, y as i32, Cell::block(macroquad::prelude::RED));
+                }
+            }
+            game.start_line_clear_animation(rows);
+            game.finish_line_clear();
+        };
+        
+        tetris(&mut game);
+        assert_eq!(game.take_pending_attack(), 4);
+        assert_eq!(game.take_pending_attack(), 0);
+        
+        // Back-to-back adds one, and the combo has started adding too
+        tetris(&mut game);
+        assert_eq!(game.pending_attack, 4 + 1 + crate::scoring::attack::combo_attack(1));
+        
+        // Incoming garbage is cancelled by pending attack first
+        let pending = game.pending_attack;
+        assert_eq!(game.receive_garbage(pending - 1), 0);
+        assert_eq!(game.pending_attack, 1);
+        let filled_before = game.board.filled_cells_count();
+        assert_eq!(game.receive_garbage(3), 2);
+        assert_eq!(game.pending_attack, 0);
+        assert_eq!(game.board.filled_cells_count(), filled_before + 2 * (BOARD_WIDTH - 1));
+    }
+    
+    /// A game with a T spun into a T-spin double slot (overhang on the right), not yet locked
+    fn t_spin_double_game() -> Game {
+        let bottom = (BOARD_HEIGHT + BUFFER_HEIGHT - 1) as i32;
+        let mut game = crate::game::board_builder::BoardBuilder::new()
+            .rows(&["...#......", "#...######", "##.#######"])
+            .current_piece(TetrominoType::T, (2, bottom - 1))
+            .build();
+        assert!(game.rotate_piece_clockwise());
+        assert!(game.rotate_piece_clockwise());
+        game
+    }
+    
+    #[test]
+    fn test_t_spin_double_is_decided_at_lock_and_attacks() {
+        let mut game = t_spin_double_game();
+        assert!(game.is_t_spin());
+        game.lock_current_piece();
+        assert!(game.last_lock_was_t_spin);
+        assert!(game.events.contains(&GameEvent::LinesCleared { count: 2, clear_type: LineClearType::TSpinDouble }));
+        game.finish_line_clear();
+        
+        assert_eq!(game.take_pending_attack(), 4);
+    }
+    
+    #[test]
+    fn test_garbage_holes_follow_the_game_seed() {
+        let garbage_after = |seed: u64| {
+            let mut game = Game::new_with_seed(seed);
+            game.current_piece = None;
+            for lines in [2, 1, 3] {
+                game.receive_garbage(lines);
+            }
+            game.board.to_ascii()
+        };
+        assert_eq!(garbage_after(99), garbage_after(99));
+        assert!((0..8).any(|seed| garbage_after(seed) != garbage_after(99)));
+    }
+
+    #[test]
+    fn test_cheese_rows_have_one_hole_and_count_down() {
+        let mut game = Game::new_cheese(5);
+        game.state = GameState::Playing;
+        assert_eq!(game.cheese_remaining(), Some(5));
+        
+        let total_rows = BOARD_HEIGHT + BUFFER_HEIGHT;
+        let holes: Vec<usize> = (total_rows - 5..total_rows)
+            .map(|y| {
+                assert_eq!(game.board.row_fill_count(y), BOARD_WIDTH as u16 - 1);
+                (0..BOARD_WIDTH).find(|&x| game.board.get_cell(x as i32, y as i32).unwrap().is_empty()).unwrap()
+            })
+            .collect();
+        assert!(holes.windows(2).all(|pair| pair[0] != pair[1]), "adjacent holes repeat: {:?}", holes);
+        assert_eq!(game.board.row_fill_count(total_rows - 6), 0);
+        
+        // Plug the top cheese row's hole and clear it
+        let top = total_rows - 5;
+        game.board.set_cell(holes[0] as i32, top as i32, Cell::block(crate::graphics::colors::GARBAGE_COLOR));
+        game.clearing_lines = vec![top];
+        game.finish_line_clear();
+        assert_eq!(game.cheese_remaining(), Some(4));
+        
+        // More cheese tops the counter back up
+        game.add_cheese_rows(1);
+        assert_eq!(game.cheese_remaining(), Some(5));
+        assert_eq!(Game::new().cheese_remaining(), None);
+    }
+    
+    #[test]
+    fn test_cheese_follows_the_game_seed() {
+        let mut game = Game::new_cheese(6);
+        let first = game.board.to_ascii();
+        game.retry_same_seed();
+        assert_eq!(game.board.to_ascii(), first);
+        
+        let cheese_for = |seed: u64| {
+            let mut game = Game::new_with_seed(seed);
+            game.mode = GameMode::Cheese { rows: 6 };
+            game.add_cheese_rows(6);
+            game.board.to_ascii()
+        };
+        assert_eq!(cheese_for(5), cheese_for(5));
+        assert!((0..8).any(|seed| cheese_for(seed) != cheese_for(5)));
+    }
+
+    #[test]
+    fn test_next_spawn_cells_match_a_fresh_next_piece() {
+        let mut game = Game::new();
+        for _ in 0..7 {
+            assert_eq!(game.next_spawn_cells(), Tetromino::new(game.next_piece).absolute_blocks());
+            game.spawn_next_piece();
+        }
+    }
+
+    #[test]
+    fn test_slow_frame_drops_multiple_rows() {
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        game.drop_interval = 0.08;
+        let start_y = game.current_piece.as_ref().unwrap().position.1;
+        
+        // 200ms at 80ms per row is two rows, with 40ms carried into the next frame
+        game.update(0.2);
+        assert_eq!(game.current_piece.as_ref().unwrap().position.1, start_y + 2);
+        assert!((game.drop_timer - 0.04).abs() < 1e-9);
+        
+        // 20G sends the piece straight to the floor
+        game.drop_interval = 0.0;
+        game.update(0.001);
+        let mut below = game.current_piece.clone().unwrap();
+        below.move_by(0, 1);
+        assert!(!game.is_piece_valid(&below));
+        assert!(game.piece_is_locking);
+    }
+
+    #[test]
+    fn test_export_summary_round_trips() {
+        let mut game = t_spin_double_game();
+        game.mode = GameMode::Sprint;
+        game.line_goal = Some(40);
+        game.lock_current_piece();
+        game.finish_line_clear();
+        assert_eq!(game.t_spins, 1);
+        
+        game.score = 12_345;
+        game.game_time = 95.5;
+        game.pieces_placed = 3;
+        game.piece_counts.insert(TetrominoType::T, 2);
+        game.piece_counts.insert(TetrominoType::I, 1);
+        game.max_combo = 4;
+        game.tetrises = 1;
+        
+        let path = std::env::temp_dir().join(format!("tetris_summary_test_{}.json", std::process::id()));
+        game.export_summary(&path).unwrap();
+        let summary = GameSummary::load_from_file(&path).unwrap();
+        let _ = fs::remove_file(&path);
+        
+        assert_eq!(summary, game.summary());
+        assert_eq!(summary.mode, GameMode::Sprint);
+        assert_eq!(summary.score, 12_345);
+        assert_eq!(summary.time, 95.5);
+        assert_eq!(summary.pieces["T-piece"], 2);
+        assert_eq!(summary.pieces["I-piece"], 1);
+        assert_eq!(summary.pieces["O-piece"], 0);
+        assert_eq!((summary.max_combo, summary.t_spins, summary.tetrises), (4, 1, 1));
+    }
+
+    #[test]
+    fn test_same_seed_deals_same_pieces() {
+        for kind in crate::tetromino::RandomizerKind::all() {
+            let first = Game::new_with_randomizer(kind.create(777));
+            let second = Game::new_with_randomizer(kind.create(777));
+            assert_eq!(first.initial_seed, 777);
+            assert_eq!(first.upcoming_pieces(14), second.upcoming_pieces(14));
+        }
+        
+        // Retrying after playing some pieces deals the original sequence again
+        let mut game = Game::new_line_goal(40);
+        let opening = game.upcoming_pieces(14);
+        for _ in 0..5 {
+            game.spawn_next_piece();
+        }
+        game.retry_same_seed();
+        assert_eq!(game.upcoming_pieces(14), opening);
+        assert_eq!(game.mode, GameMode::Sprint);
+        
+        // A normal restart rerolls
+        game.reset();
+        assert_ne!(game.upcoming_pieces(14), opening);
+    }
+
+    #[test]
+    fn test_level_up_shows_banner_and_eases_gravity() {
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        let level_one_interval = game.drop_interval;
+        
+        // Nine lines in, then a single that crosses the 10-line boundary
+        let bottom = BOARD_HEIGHT + BUFFER_HEIGHT - 1;
+        game.board.clear_lines(&(0..LINES_PER_LEVEL as usize - 1).collect::<Vec<_>>());
+        game.current_piece = None;
+        for x in 0..BOARD_WIDTH {
+            game.board.set_cell(x as i32, bottom as i32, Cell::block(crate::graphics::colors::GARBAGE_COLOR));
+        }
+        game.clearing_lines = vec![bottom];
+        game.finish_line_clear();
+        
+        assert_eq!(game.level(), 2);
+        assert!(game.level_up_banner_active());
+        assert!(game.target_drop_interval < level_one_interval);
+        // The speed change eases in rather than snapping
+        assert_eq!(game.drop_interval, level_one_interval);
+        game.update(DROP_INTERVAL_EASE_TIME / 2.0);
+        assert!(game.drop_interval < level_one_interval && game.drop_interval > game.target_drop_interval);
+        game.update(DROP_INTERVAL_EASE_TIME);
+        assert_eq!(game.drop_interval, game.target_drop_interval);
+        
+        game.update(LEVEL_UP_BANNER_TIME);
+        assert!(!game.level_up_banner_active());
+    }
+
+    #[test]
+    fn test_death_cam_replays_final_seconds() {
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        for _ in 0..100 {
+            game.update(1.0 / 20.0);
+        }
+        assert!(game.death_cam().is_none(), "no death-cam while still playing");
+        
+        game.end_game(GameOverReason::TopOut);
+        let tail = game.death_cam().unwrap();
+        let end = game.game_time;
+        assert!(tail.snapshots.iter().all(|snapshot| snapshot.time >= end - DEATH_CAM_SECONDS));
+        assert_eq!(tail.last_time(), end);
+        
+        // Playback starts at the beginning of the tail and stops once it has run out
+        assert_eq!(game.death_cam_frame().unwrap().time, tail.start_time().unwrap());
+        game.update(DEATH_CAM_SECONDS + 0.5);
+        assert!(game.death_cam_frame().is_none());
+    }
+    
+    #[test]
+    fn test_hold_cooldown_gates_hold() {
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        game.hold_cooldown = Some(3);
+        
+        // The first hold of a game is always free
+        assert!(game.can_hold());
+        assert!(game.hold_piece());
+        assert_eq!(game.hold_cooldown_remaining(), 3);
+        
+        // One more spawn is not enough
+        game.spawn_next_piece();
+        assert!(!game.can_hold());
+        assert!(!game.hold_available_next_piece());
+        assert!(!game.hold_piece());
+        
+        game.spawn_next_piece();
+        assert_eq!(game.hold_cooldown_remaining(), 1);
+        assert!(!game.can_hold());
+        assert!(game.hold_available_next_piece());
+        
+        // The third piece after the hold may hold again, restarting the cooldown
+        game.spawn_next_piece();
+        assert!(game.can_hold());
+        assert!(game.hold_piece());
+        assert_eq!(game.hold_cooldown_remaining(), 3);
+        
+        // Without a cooldown only the once-per-piece rule applies
+        game.hold_cooldown = None;
+        game.spawn_next_piece();
+        assert!(game.can_hold());
+    }
+    
+    #[test]
+    fn test_sequence_generator_spawns_known_pieces() {
+        use crate::tetromino::SequenceGenerator;
+        let order = vec![TetrominoType::I, TetrominoType::T, TetrominoType::S, TetrominoType::O, TetrominoType::L];
+        let mut game = Game::with_generator(SequenceGenerator::new(order.clone()));
+        assert_eq!(game.current_piece.as_ref().unwrap().piece_type, TetrominoType::I);
+        
+        let mut spawned = Vec::new();
+        for _ in 0..order.len() {
+            game.spawn_next_piece();
+            spawned.push(game.current_piece.as_ref().unwrap().piece_type);
+        }
+        // The sequence carries on from the opening piece and loops back round
+        assert_eq!(spawned, [&order[1..], &order[..1]].concat());
+        
+        // Restarting deals the sequence from the top again
+        game.reset();
+        assert_eq!(game.current_piece.as_ref().unwrap().piece_type, TetrominoType::I);
+        assert_eq!(game.next_piece, TetrominoType::T);
+    }
+    
+    #[test]
+    fn test_cheese_drill_refills_cleared_rows() {
+        let mut game = Game::new_cheese_drill(4);
+        game.state = GameState::Playing;
+        assert_eq!(game.cheese_cleared_total(), Some(0));
+        
+        let total_rows = BOARD_HEIGHT + BUFFER_HEIGHT;
+        let top = total_rows - 4;
+        // A block resting on the cheese gets pushed back up by the refill
+        game.board.set_cell(0, top as i32 - 1, Cell::block(crate::graphics::colors::GARBAGE_COLOR));
+        
+        let hole = (0..BOARD_WIDTH).find(|&x| game.board.get_cell(x as i32, top as i32).unwrap().is_empty()).unwrap();
+        game.board.set_cell(hole as i32, top as i32, Cell::block(crate::graphics::colors::GARBAGE_COLOR));
+        game.clearing_lines = vec![top];
+        game.game_time = 30.0;
+        game.finish_line_clear();
+        
+        assert_eq!(game.cheese_remaining(), Some(4));
+        assert_eq!(game.cheese_cleared_total(), Some(1));
+        assert_eq!(game.cheese_rate(), Some(2.0));
+        assert!(game.board.get_cell(0, top as i32 - 1).unwrap().is_filled());
+        assert!((top..total_rows).all(|y| game.board.row_fill_count(y) == BOARD_WIDTH as u16 - 1));
+        
+        // Plain cheese digs down instead
+        let mut plain = Game::new_cheese(4);
+        plain.state = GameState::Playing;
+        let hole = (0..BOARD_WIDTH).find(|&x| plain.board.get_cell(x as i32, top as i32).unwrap().is_empty()).unwrap();
+        plain.board.set_cell(hole as i32, top as i32, Cell::block(crate::graphics::colors::GARBAGE_COLOR));
+        plain.clearing_lines = vec![top];
+        plain.finish_line_clear();
+        assert_eq!(plain.cheese_remaining(), Some(3));
+        assert_eq!(plain.cheese_cleared_total(), Some(1));
+        
+        // Refilling under a stack that reaches the ceiling tops out
+        game.board.set_cell(0, 0, Cell::block(crate::graphics::colors::GARBAGE_COLOR));
+        game.add_cheese_rows(1);
+        assert_eq!(game.state, GameState::GameOver);
+    }
+    
+    #[test]
+    fn test_new_with_seed_is_reproducible_and_survives_saving() {
+        let mut first = Game::new_with_seed(42);
+        let second = Game::new_with_seed(42);
+        assert_eq!(first.initial_seed, 42);
+        assert_eq!(first.upcoming_pieces(21), second.upcoming_pieces(21));
+        
+        // Entropy-seeded games almost never share a three-bag opening
+        assert_ne!(Game::new().upcoming_pieces(21), Game::new().upcoming_pieces(21));
+        
+        // A loaded save carries on with exactly the pieces the original would have dealt
+        for _ in 0..4 {
+            first.spawn_next_piece();
+        }
+        let loaded = Game::load_from_str(&serde_json::to_string(&first).unwrap()).unwrap();
+        assert_eq!(loaded.initial_seed, 42);
+        assert_eq!(loaded.upcoming_pieces(21), first.upcoming_pieces(21));
+    }
+    
+    #[test]
+    fn test_rotating_over_a_gap_releases_lock_delay() {
+        // A two-wide, two-deep pit the flat T straddles but the upright T fits into
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        game.board = Board::from_ascii(
+            "#####..###\n\
+             #####..###",
+        ).unwrap();
+        let mut piece = Tetromino::new(TetrominoType::T);
+        piece.position = (5, (BOARD_HEIGHT + BUFFER_HEIGHT - 3) as i32);
+        game.current_piece = Some(piece);
+        
+        // Resting on the left lip and part way into its lock delay
+        assert!(!game.drop_current_piece());
+        assert!(game.piece_is_locking);
+        game.lock_delay_timer = game.lock_delay * 0.8;
+        game.lock_resets = 3;
+        
+        assert!(game.rotate_piece_clockwise());
+        assert!(!game.piece_is_locking, "a rotation that can fall further must release lock delay");
+        assert_eq!(game.lock_delay_timer, 0.0);
+        
+        // The piece keeps falling instead of locking out of the next update
+        game.update(game.lock_delay * 0.5);
+        assert!(game.current_piece.is_some());
+        assert!(game.drop_current_piece());
+        assert!(!game.drop_current_piece());
+        assert!(game.piece_is_locking);
+        
+        // A locking state left over on a piece that can fall is dropped rather than timed out
+        game.spawn_next_piece();
+        game.piece_is_locking = true;
+        game.lock_delay_timer = game.lock_delay * 0.9;
+        game.update(game.lock_delay * 0.5);
+        assert!(game.current_piece.is_some());
+        assert!(!game.piece_is_locking);
+    }
+    
+    #[test]
+    fn test_hold_counts_separately_from_placements() {
+        use crate::tetromino::SequenceGenerator;
+        let mut game = Game::with_generator(SequenceGenerator::new(vec![TetrominoType::T, TetrominoType::I, TetrominoType::O]));
+        game.state = GameState::Playing;
+        
+        // Holding the T brings out the I, and isn't a placement
+        assert!(game.hold_piece());
+        assert_eq!(game.holds_used(), 1);
+        assert_eq!(game.pieces_placed, 0);
+        assert!(game.piece_counts.is_empty());
+        
+        game.hard_drop();
+        assert_eq!(game.pieces_placed, 1);
+        assert_eq!(game.piece_counts.get(&TetrominoType::I), Some(&1));
+        assert_eq!(game.piece_counts.get(&TetrominoType::T), None);
+        assert_eq!(game.summary().holds, 1);
+        
+        game.reset();
+        assert_eq!(game.holds_used(), 0);
+    }
+    
+    #[test]
+    fn test_reaching_sprint_goal_finishes_rather_than_ends() {
+        let mut game = Game::new_line_goal(1);
+        game.state = GameState::Playing;
+        let bottom = BOARD_HEIGHT + BUFFER_HEIGHT - 1;
+        for x in 0..BOARD_WIDTH as i32 {
+            game.board.set_cell(x, bottom as i32, Cell::block(macroquad::prelude::RED));
+        }
+        game.game_time = 83.0;
+        game.start_line_clear_animation(vec![bottom]);
+        game.finish_line_clear();
+        
+        assert_eq!(game.state, GameState::Finished);
+        assert_ne!(game.state, GameState::GameOver);
+        assert!(game.state.has_ended());
+        assert_eq!(game.game_over_reason, None);
+        
+        // Nothing progresses once finished
+        let board = game.board.clone();
+        game.update(1.5);
+        assert_eq!(game.game_time, 83.0);
+        assert_eq!(game.board, board);
+        
+        // Saves from before the rename still load as finished
+        let old: GameState = serde_json::from_str("\"Victory\"").unwrap();
+        assert_eq!(old, GameState::Finished);
+    }
+    
+    #[test]
+    fn test_spawn_grace_holds_off_gravity() {
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        game.spawn_grace = 0.2;
+        game.spawn_next_piece();
+        game.drop_interval = 0.05;
+        game.drop_interval_ease_timer = DROP_INTERVAL_EASE_TIME;
+        let spawn_y = game.current_piece.as_ref().unwrap().position.1;
+        
+        // Several drop intervals pass, but the piece stays put through the grace
+        for _ in 0..2 {
+            game.update(0.1);
+            assert_eq!(game.current_piece.as_ref().unwrap().position.1, spawn_y);
+        }
+        assert_eq!(game.spawn_grace_remaining, 0.0);
+        assert_eq!(game.drop_timer, 0.0);
+        
+        game.update(0.05);
+        assert_eq!(game.current_piece.as_ref().unwrap().position.1, spawn_y + 1);
+        
+        // The default keeps gravity immediate
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        game.spawn_next_piece();
+        assert_eq!(game.spawn_grace_remaining, 0.0);
+    }
+    
+    #[test]
+    fn test_back_to_back_tetris_pushes_a_b2b_notification() {
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        let bottom = (BOARD_HEIGHT + BUFFER_HEIGHT - 1) as i32;
+        game.board.set_cell(0, bottom, Cell::block(macroquad::prelude::RED));
+        
+        let tetris = |game: &mut Game| {
+            let rows: Vec<usize> = (1..=4).map(|up| (bottom - up) as usize).collect();
+            for &y in &rows {
+                for x in 0..BOARD_WIDTH as i32 {
+                    game.board.set_cell(x, y as i32, Cell::block(macroquad::prelude::RED));
+                }
+            }
+            game.start_line_clear_animation(rows);
+            game.finish_line_clear();
+        };
+        
+        tetris(&mut game);
+        let texts: Vec<&str> = game.active_notifications().iter().map(|n| n.text.as_str()).collect();
+        assert_eq!(texts, vec!["TETRIS"]);
+        
+        tetris(&mut game);
+        assert_eq!(game.active_notifications().last().unwrap().text, "B2B TETRIS");
+        
+        // Callouts decay away on their own
+        game.update(NOTIFICATION_DURATION);
+        assert!(game.active_notifications().is_empty());
+    }
+    
+    #[test]
+    fn test_t_spin_double_pushes_its_callout() {
+        let mut game = t_spin_double_game();
+        game.lock_current_piece();
+        game.finish_line_clear();
+        
+        let texts: Vec<&str> = game.active_notifications().iter().map(|n| n.text.as_str()).collect();
+        assert_eq!(texts, vec!["T-SPIN DOUBLE"]);
+    }
+    
+    #[test]
+    fn test_buttons_held_through_spawn_act_on_the_new_piece() {
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        
+        // Rotate held at spawn: the piece appears already turned (IRS)
+        game.held_inputs.rotate_clockwise = true;
+        game.current_piece = None;
+        game.spawn_next_piece();
+        assert_eq!(game.current_piece.as_ref().unwrap().rotation, RotationState::Right);
+        
+        // Hold held at spawn: the piece goes straight to hold (IHS), then IRS turns the next
+        game.held_inputs.hold = true;
+        let spawned = game.next_piece;
+        let after = game.randomizer.peek(1)[0];
+        game.current_piece = None;
+        game.spawn_next_piece();
+        assert_eq!(game.held_piece, Some(spawned));
+        let piece = game.current_piece.as_ref().unwrap();
+        assert_eq!((piece.piece_type, piece.rotation), (after, RotationState::Right));
+        
+        // Nothing held: spawns are untouched
+        game.held_inputs = HeldInputs::default();
+        game.current_piece = None;
+        game.spawn_next_piece();
+        assert_eq!(game.current_piece.as_ref().unwrap().rotation, RotationState::Spawn);
+    }
+    
+    #[test]
+    fn test_das_charge_carries_over_to_the_next_piece() {
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        game.current_piece = Some(Tetromino::new(TetrominoType::T));
+        
+        // Charge DAS fully while holding left
+        game.update_left_movement(false);
+        game.update_left_movement(true);
+        game.left_move_timer = game.das;
+        game.update_left_movement(true);
+        assert_eq!(game.left_repeat_count, 2);
+        
+        // While no piece is out, the held direction keeps its charge
+        game.current_piece = None;
+        game.left_move_timer = 0.0;
+        for _ in 0..10 {
+            game.left_move_timer += game.arr;
+            game.update_left_movement(true);
+        }
+        assert_eq!(game.left_repeat_count, 2);
+        
+        // The new piece moves at the repeat rate straight away, without waiting out DAS again
+        game.current_piece = Some(Tetromino::new(TetrominoType::T));
+        let x = game.current_piece.as_ref().unwrap().position.0;
+        game.update_left_movement(true);
+        assert_eq!(game.current_piece.as_ref().unwrap().position.0, x - 1);
+    }
+    
+    #[test]
+    fn test_ghost_block_cadence_and_cap() {
+        let mut game = Game::new();
+        game.ghost_block_every_n_lines = 8;
+        game.award_ghost_blocks(0, 4);
+        assert_eq!(game.ghost_blocks_available, 0);
+        game.award_ghost_blocks(4, 8);
+        assert_eq!(game.ghost_blocks_available, 1);
+        
+        // A big jump earns one per boundary crossed, but never more than the cap
+        game.ghost_block_max = 3;
+        game.award_ghost_blocks(8, 40);
+        assert_eq!(game.ghost_blocks_available, 3);
+        
+        // Turned off, nothing is awarded and placement mode can't be entered
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        game.ghost_blocks_enabled = false;
+        game.award_ghost_blocks(0, 40);
+        assert_eq!(game.ghost_blocks_available, 0);
+        game.ghost_blocks_available = 1;
+        game.toggle_ghost_block_mode();
+        assert!(!game.ghost_block_placement_mode);
+    }
+    
+    #[test]
+    fn test_line_clear_log_records_each_clear() {
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        let bottom = (BOARD_HEIGHT + BUFFER_HEIGHT - 1) as i32;
+        game.board.set_cell(0, bottom, Cell::block(macroquad::prelude::RED));
+        
+        let clear = |game: &mut Game, count: i32| {
+            let rows: Vec<usize> = (1..=count).map(|up| (bottom - up) as usize).collect();
+            for &y in &rows {
+                for x in 0..BOARD_WIDTH as i32 {
+                    game.board.set_cell(x, y as i32, Cell::block(macroquad::prelude::RED));
+                }
+            }
+            game.start_line_clear_animation(rows);
+            game.finish_line_clear();
+        };
+        
+        game.game_time = 3.0;
+        clear(&mut game, 2);
+        game.game_time = 7.5;
+        clear(&mut game, 4);
+        
+        let log = game.line_clear_log();
+        assert_eq!(log.len(), 2);
+        assert_eq!((log[0].time, log[0].clear_type, log[0].combo, log[0].back_to_back), (3.0, LineClearType::Double, 0, false));
+        assert_eq!((log[1].time, log[1].clear_type, log[1].combo, log[1].back_to_back), (7.5, LineClearType::Tetris, 1, false));
+        
+        // Saved with the game and carried into the summary
+        let restored: Game = serde_json::from_str(&serde_json::to_string(&game).unwrap()).unwrap();
+        assert_eq!(restored.line_clear_log(), log);
+        assert_eq!(game.summary().line_clears, log);
+    }
+    
+    #[test]
+    fn test_hard_drop_trail_spans_the_drop_then_fades() {
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        let start = game.current_piece.as_ref().unwrap().absolute_blocks();
+        let landing = game.calculate_ghost_piece().unwrap().absolute_blocks();
+        let top_row = |cells: &[(i32, i32)]| cells.iter().map(|&(_, y)| y).min().unwrap();
+        let piece_type = game.current_piece.as_ref().unwrap().piece_type;
+        
+        game.hard_drop();
+        let (cells, distance, trail_type) = game.hard_drop_trail().unwrap().clone();
+        assert_eq!(trail_type, piece_type);
+        assert_eq!(cells, start);
+        assert_eq!(top_row(&cells), top_row(&start));
+        assert_eq!(top_row(&cells) + distance, top_row(&landing));
+        assert!(game.hard_drop_trail_alpha() > 0.0);
+        
+        game.update(HARD_DROP_TRAIL_TIME * 2.0);
+        assert!(game.hard_drop_trail().is_none());
+        assert_eq!(game.hard_drop_trail_alpha(), 0.0);
+        
+        game.hard_drop_trail_enabled = false;
+        game.hard_drop();
+        assert!(game.hard_drop_trail().is_none());
+    }
+    
+    #[test]
+    fn test_topout_animation_plays_before_game_over() {
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        game.topout_animation = true;
+        game.end_game(GameOverReason::BlockOut);
+        assert_eq!(game.state, GameState::ToppingOut { timer: 0.0 });
+        assert!(!game.state.has_ended());
+        
+        game.update(TOPOUT_ANIMATION_TIME / 2.0);
+        assert!(matches!(game.state, GameState::ToppingOut { .. }));
+        assert!((game.topout_progress().unwrap() - 0.5).abs() < 0.01);
+        
+        game.update(TOPOUT_ANIMATION_TIME);
+        assert_eq!(game.state, GameState::GameOver);
+        assert_eq!(game.topout_progress(), None);
+        
+        // Skipping goes straight to the game-over screen
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        game.topout_animation = true;
+        game.end_game(GameOverReason::TopOut);
+        game.skip_topout_animation();
+        assert_eq!(game.state, GameState::GameOver);
+    }
+    
+    #[test]
+    fn test_mirror_mode_flips_pieces_as_they_spawn() {
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        game.mode = GameMode::Mirror;
+        game.next_piece = TetrominoType::S;
+        // The preview shows the piece that will actually spawn
+        assert_eq!(game.next_piece_type(), TetrominoType::Z);
+        game.spawn_next_piece();
+        assert_eq!(game.current_piece.as_ref().unwrap().piece_type, TetrominoType::Z);
+        
+        // A held piece comes back out as it was played
+        game.hold_piece();
+        game.spawn_next_piece();
+        game.hold_piece();
+        assert_eq!(game.current_piece.as_ref().unwrap().piece_type, TetrominoType::Z);
+    }
+    
+    #[test]
+    fn test_queued_action_applies_before_gravity_locks_the_piece() {
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        game.current_piece = Some(game.rotation_system.spawn_piece(TetrominoType::T));
+        game.sonic_drop();
+        // One more tick and this piece locks
+        game.lock_delay_timer = game.lock_delay - 0.001;
+        
+        game.queue_action(InputAction::RotateClockwise);
+        game.update(0.01);
+        
+        assert_eq!(game.pieces_placed, 0, "the rotation should reset lock delay before it runs out");
+        assert_eq!(game.current_piece.as_ref().unwrap().rotation, RotationState::Right);
+        assert_eq!(game.applied_actions, vec![(InputAction::RotateClockwise, true)]);
+        assert!(game.queued_actions.is_empty());
+    }
+    
+    #[test]
+    fn test_spawn_disarms_a_half_confirmed_hard_drop() {
+        use crate::input::HardDropMode;
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        game.hard_drop_confirm = HardDropConfirm::new(HardDropMode::DoubleTap, 0.25);
+        assert!(!game.hard_drop_confirm.press(1.0));
+        
+        // Gravity locks the piece before the second tap, and the next one spawns
+        game.sonic_drop();
+        game.lock_current_piece();
+        assert_eq!(game.pieces_placed, 1);
+        assert!(!game.hard_drop_confirm.press(1.1), "a tap aimed at the old piece must not drop the new one");
+    }
+    
+    #[test]
+    fn test_ended_game_reports_no_applied_actions() {
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        game.queue_action(InputAction::HardDrop);
+        game.update(0.01);
+        assert_eq!(game.applied_actions, vec![(InputAction::HardDrop, true)]);
+        
+        // Frames after the game ends (or pauses) must not replay the last press
+        for state in [GameState::GameOver, GameState::Finished, GameState::Paused, GameState::ToppingOut { timer: 0.0 }] {
+            game.applied_actions = vec![(InputAction::HardDrop, true)];
+            game.state = state;
+            game.update(0.01);
+            assert!(game.applied_actions.is_empty(), "{:?}", state);
+        }
+    }
+    
+    #[test]
+    fn test_combo_counts_once_per_clear_across_the_animation() {
+        use crate::tetromino::SequenceGenerator;
+        let mut game = Game::with_generator(SequenceGenerator::new(vec![TetrominoType::I; 8]));
+        game.state = GameState::Playing;
+        let bottom = (BOARD_HEIGHT + BUFFER_HEIGHT - 1) as i32;
+        let fill_row = |game: &mut Game, y: i32, skip: &[i32]| {
+            for x in (0..BOARD_WIDTH as i32).filter(|x| !skip.contains(x)) {
+                game.board.set_cell(x, y, Cell::block(macroquad::prelude::RED));
+            }
+        };
+        
+        // The I completes the bottom row; nothing is scored until the animation ends
+        let piece_columns: Vec<i32> = game.current_piece.as_ref().unwrap().absolute_blocks().iter().map(|&(x, _)| x).collect();
+        fill_row(&mut game, bottom, &piece_columns);
+        game.hard_drop();
+        assert!(game.is_clearing_lines());
+        assert_eq!(game.scoring_system.current_combo(), 0);
+        
+        // A row completed mid-animation (as by a ghost block) waits its turn
+        fill_row(&mut game, bottom - 1, &[]);
+        assert!(!game.start_line_clear_animation(game.board.find_complete_lines()));
+        let step = LINE_CLEAR_ANIMATION_TIME / 10.0;
+        for _ in 0..9 {
+            game.update(step);
+            assert!(game.is_clearing_lines());
+            assert_eq!(game.scoring_system.current_combo(), 0);
+        }
+        
+        // Each clear counts exactly once as its animation finishes
+        game.update(step * 2.0);
+        assert_eq!(game.scoring_system.current_combo(), 1);
+        assert!(game.is_clearing_lines());
+        game.update(LINE_CLEAR_ANIMATION_TIME);
+        assert_eq!(game.scoring_system.current_combo(), 2);
+        assert_eq!(game.lines_cleared(), 2);
+        assert!(!game.is_clearing_lines());
+        
+        // A drop pressed while a ghost block's clear animates can't lock and break the combo first
+        assert!(game.current_piece.is_some());
+        let placed = game.pieces_placed;
+        fill_row(&mut game, bottom, &[]);
+        assert!(game.start_line_clear_animation(vec![bottom as usize]));
+        game.queue_action(InputAction::HardDrop);
+        game.update(step);
+        assert_eq!(game.pieces_placed, placed);
+        assert_eq!(game.scoring_system.current_combo(), 2);
+        game.update(LINE_CLEAR_ANIMATION_TIME);
+        assert_eq!(game.scoring_system.current_combo(), 3);
+        
+        // The press waited rather than being thrown away
+        assert_eq!(game.queued_actions.len(), 1);
+        game.update(0.0);
+        assert_eq!(game.pieces_placed, placed + 1);
+        assert_eq!(game.applied_actions, vec![(InputAction::HardDrop, true)]);
+    }
+    
+    #[test]
+    fn test_reduce_motion_skips_the_tetris_celebration_and_shortens_clears() {
+        let mut game = Game::new();
+        game.state = GameState::Playing;
+        game.current_piece = None;
+        game.reduce_motion = true;
+        let bottom = BOARD_HEIGHT + BUFFER_HEIGHT - 1;
+        for y in bottom - 3..=bottom {
+            for x in 0..BOARD_WIDTH as i32 {
+                game.board.set_cell(x, y as i32, Cell::block(macroquad::prelude::RED));
+            }
+        }
+        game.start_line_clear_animation(game.board.find_complete_lines());
+        
+        // The brief flash ends well before the full animation would have
+        game.update(REDUCED_LINE_CLEAR_TIME);
+        assert!(!game.is_clearing_lines());
+        assert_eq!(game.lines_cleared(), 4);
+        assert!(!game.is_tetris_celebration_active());
+        assert!(game.active_notifications().iter().any(|notification| notification.text.contains("TETRIS")));
+        assert_eq!(game.screen_shake_offset(), (0.0, 0.0));
+        
+        // Hard drops don't shake the screen either
+        game.spawn_next_piece();
+        game.hard_drop();
+        assert_eq!(game.screen_shake_offset(), (0.0, 0.0));
+    }
+    
+    #[test]
+    fn test_locking_and_clearing_emit_events_in_order() {
+        use crate::tetromino::SequenceGenerator;
+        let mut game = Game::with_generator(SequenceGenerator::new(vec![TetrominoType::I; 4]));
+        game.state = GameState::Playing;
+        let bottom = (BOARD_HEIGHT + BUFFER_HEIGHT - 1) as i32;
+        
+        // A plain drop only locks
+        game.hard_drop();
+        assert_eq!(game.poll_events(), vec![GameEvent::PieceLocked]);
+        assert!(game.poll_events().is_empty());
+        
+        // Completing the rest of the bottom row locks, then clears on the same drop
+        let landing: Vec<i32> = game.current_piece.as_ref().unwrap().absolute_blocks().iter().map(|&(x, _)| x).collect();
+        game.board.clear();
+        for x in (0..BOARD_WIDTH as i32).filter(|x| !landing.contains(x)) {
+            game.board.set_cell(x, bottom, Cell::block(macroquad::prelude::RED));
+        }
+        game.hard_drop();
+        assert_eq!(game.poll_events(), vec![
+            GameEvent::PieceLocked,
+            GameEvent::LinesCleared { count: 1, clear_type: LineClearType::Single },
+        ]);
+        
+        // The board is empty once the row goes
+        game.update(LINE_CLEAR_ANIMATION_TIME);
+        assert_eq!(game.poll_events(), vec![GameEvent::PerfectClear]);
+    }
+    
+    #[test]
+    fn test_template_progress_counts_filled_target_cells() {
+        let bottom = (BOARD_HEIGHT + BUFFER_HEIGHT - 1) as i32;
+        let path = std::env::temp_dir().join(format!("tetris_template_test_{}.json", std::process::id()));
+        let template = TemplateOverlay::new("L STACK", vec![(0, bottom), (1, bottom), (2, bottom), (0, bottom - 1)]);
+        fs::write(&path, serde_json::to_string(&template).unwrap()).unwrap();
+        
+        let mut game = Game::new();
+        assert_eq!(game.template_progress(), 0);
+        game.load_template(&path).unwrap();
+        assert_eq!(game.template, Some(template));
+        assert_eq!(game.template_progress(), 0);
+        
+        game.board.set_cell(0, bottom, Cell::block(macroquad::prelude::RED));
+        game.board.set_cell(1, bottom, Cell::block(macroquad::prelude::RED));
+        // Cells outside the template don't count
+        game.board.set_cell(5, bottom, Cell::block(macroquad::prelude::RED));
+        assert_eq!(game.template_progress(), 2);
+        
+        game.board.set_cell(2, bottom, Cell::block(macroquad::prelude::RED));
+        game.board.set_cell(0, bottom - 1, Cell::block(macroquad::prelude::RED));
+        assert_eq!(game.template_progress(), 4);
+        assert!(game.template.as_ref().unwrap().is_complete(&game.board));
+        
+        // The template stays up across restarts, and off-board cells are refused
+        game.reset();
+        assert!(game.template.is_some());
+        fs::write(&path, r#"{"name":"BAD","cells":[[10,0]]}"#).unwrap();
+        assert!(game.load_template(&path).is_err());
+        fs::remove_file(&path).ok();
+    }
+    
+    #[test]
+    fn test_gravity_curve_sets_the_drop_interval() {
+        let mut game = Game::new_with_level(10);
+        let table = game.target_drop_interval;
+        assert_eq!(table, GravityCurve::Table.drop_interval(10) * game.difficulty.drop_interval_multiplier());
+        
+        game.set_gravity_curve(GravityCurve::Guideline);
+        assert_eq!(game.target_drop_interval, GravityCurve::Guideline.drop_interval(10) * game.difficulty.drop_interval_multiplier());
+        assert_ne!(game.target_drop_interval, table);
+        
+        // Restarts keep the curve
+        game.reset();
+        assert_eq!(game.gravity_curve, GravityCurve::Guideline);
+    }
 }
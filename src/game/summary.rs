@@ -0,0 +1,81 @@
+//! Compact, shareable report of a finished game
+
+use crate::game::difficulty::Difficulty;
+use crate::game::mode::GameMode;
+use crate::scoring::LineClearType;
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The headline numbers of a game, written out as readable JSON for sharing
+///
+/// Unlike a save this holds no board or piece state, so it cannot be resumed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameSummary {
+    /// Mode the game was played in
+    pub mode: GameMode,
+    /// Difficulty the game was played on
+    pub difficulty: Difficulty,
+    /// Final score
+    pub score: u32,
+    /// Level reached
+    pub level: u32,
+    /// Lines cleared
+    pub lines: u32,
+    /// Game time in seconds
+    pub time: f64,
+    /// Total pieces locked
+    pub pieces_placed: u32,
+    /// Pieces locked of each type, keyed by piece name
+    pub pieces: BTreeMap<String, u32>,
+    /// Times hold was used
+    #[serde(default)]
+    pub holds: u32,
+    /// Longest combo chain
+    pub max_combo: u32,
+    /// Line clears made with a T-spin
+    pub t_spins: u32,
+    /// Four-line clears
+    pub tetrises: u32,
+    /// Every line clear, in order
+    #[serde(default)]
+    pub line_clears: Vec<LineClearEvent>,
+}
+
+/// One line clear, as recorded for post-game analysis
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LineClearEvent {
+    /// Game time the clear finished at
+    pub time: f64,
+    /// What kind of clear it was
+    pub clear_type: LineClearType,
+    /// Combo after the clear (0 for the first clear in a row)
+    pub combo: u32,
+    /// Whether the clear continued a back-to-back chain
+    pub back_to_back: bool,
+}
+
+impl GameSummary {
+    /// Where to export a summary, named after the current time so exports don't overwrite
+    pub fn default_path() -> PathBuf {
+        let file_name = format!("tetris_summary_{}.json", chrono::Local::now().format("%Y%m%d_%H%M%S"));
+        std::env::current_dir()
+            .unwrap_or_else(|_| PathBuf::from("."))
+            .join(file_name)
+    }
+
+    /// Write the summary to a file
+    pub fn save_to_file<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn std::error::Error>> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json)?;
+        log::info!("Game summary exported successfully");
+        Ok(())
+    }
+
+    /// Read a summary back from a file
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        let json = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+}
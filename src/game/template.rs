@@ -0,0 +1,54 @@
+//! Target shapes drawn faintly on the board, for practicing openings such as a PCO or DT cannon
+
+use crate::board::Board;
+use crate::game::config::{BOARD_HEIGHT, BOARD_WIDTH, BUFFER_HEIGHT};
+use serde::{Serialize, Deserialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Cells the player should fill to build a setup
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TemplateOverlay {
+    /// Name of the setup, shown beside the board
+    #[serde(default)]
+    pub name: String,
+    /// Target cells as (x, y) board coordinates, buffer rows included like `Board::set_cell`
+    pub cells: Vec<(i32, i32)>,
+}
+
+impl TemplateOverlay {
+    /// A template for `cells`
+    pub fn new(name: impl Into<String>, cells: Vec<(i32, i32)>) -> Self {
+        Self { name: name.into(), cells }
+    }
+
+    /// Where the in-game template key loads from
+    pub fn default_path() -> PathBuf {
+        std::env::current_dir()
+            .unwrap_or_else(|_| PathBuf::from("."))
+            .join("tetris_template.json")
+    }
+
+    /// Load a template from a JSON file, rejecting cells that are off the board
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        let json = fs::read_to_string(path)?;
+        let template: Self = serde_json::from_str(&json)?;
+        let rows = (BOARD_HEIGHT + BUFFER_HEIGHT) as i32;
+        if let Some(&(x, y)) = template.cells.iter().find(|&&(x, y)| x < 0 || y < 0 || x >= BOARD_WIDTH as i32 || y >= rows) {
+            return Err(format!("template cell ({}, {}) is off the board", x, y).into());
+        }
+        Ok(template)
+    }
+
+    /// How many target cells are filled on `board`
+    pub fn filled_count(&self, board: &Board) -> usize {
+        self.cells.iter()
+            .filter(|&&(x, y)| board.get_cell(x, y).is_some_and(|cell| cell.is_filled()))
+            .count()
+    }
+
+    /// Whether every target cell is filled on `board`
+    pub fn is_complete(&self, board: &Board) -> bool {
+        self.filled_count(board) == self.cells.len()
+    }
+}
@@ -0,0 +1,57 @@
+//! Game clock display formats
+
+use serde::{Serialize, Deserialize};
+
+/// How game time is written out in the UI
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TimeFormat {
+    /// Whole seconds, e.g. `125s`
+    Seconds,
+    /// Minutes and seconds, e.g. `2:05`
+    #[default]
+    MinutesSeconds,
+    /// Minutes, seconds and milliseconds, e.g. `2:05.400`
+    MinutesSecondsMillis,
+}
+
+impl TimeFormat {
+    /// All formats in settings order
+    pub fn all() -> Vec<TimeFormat> {
+        vec![TimeFormat::Seconds, TimeFormat::MinutesSeconds, TimeFormat::MinutesSecondsMillis]
+    }
+
+    /// Display name for menus
+    pub fn name(self) -> &'static str {
+        match self {
+            TimeFormat::Seconds => "SECONDS",
+            TimeFormat::MinutesSeconds => "M:SS",
+            TimeFormat::MinutesSecondsMillis => "M:SS.mmm",
+        }
+    }
+}
+
+/// Format a game time in seconds for display
+pub fn format_game_time(secs: f64, format: TimeFormat) -> String {
+    let total_millis = (secs.max(0.0) * 1000.0).round() as u64;
+    let minutes = total_millis / 60_000;
+    let seconds = (total_millis / 1000) % 60;
+
+    match format {
+        TimeFormat::Seconds => format!("{}s", total_millis / 1000),
+        TimeFormat::MinutesSeconds => format!("{}:{:02}", minutes, seconds),
+        TimeFormat::MinutesSecondsMillis => format!("{}:{:02}.{:03}", minutes, seconds, total_millis % 1000),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_game_time() {
+        assert_eq!(format_game_time(125.4, TimeFormat::Seconds), "125s");
+        assert_eq!(format_game_time(125.4, TimeFormat::MinutesSeconds), "2:05");
+        assert_eq!(format_game_time(125.4, TimeFormat::MinutesSecondsMillis), "2:05.400");
+        assert_eq!(format_game_time(0.0, TimeFormat::MinutesSeconds), "0:00");
+    }
+}
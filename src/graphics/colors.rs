@@ -35,6 +35,9 @@ pub const GHOST_PIECE_ALPHA: f32 = 0.3;
 pub const LINE_CLEAR_FLASH: Color = Color::new(1.0, 1.0, 1.0, 0.8);
 pub const GAME_OVER_OVERLAY: Color = Color::new(0.0, 0.0, 0.0, 0.7);
 
+/// Neutral block color for garbage and boards built from text
+pub const GARBAGE_COLOR: Color = Color::new(0.55, 0.55, 0.6, 1.0);
+
 /// Get the color for a specific tetromino type
 pub fn get_tetromino_color(piece_type: &crate::tetromino::TetrominoType) -> Color {
     match piece_type {
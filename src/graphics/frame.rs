@@ -0,0 +1,74 @@
+//! Decorative frames drawn around the playfield
+
+use macroquad::math::Rect;
+use serde::{Serialize, Deserialize};
+
+/// Look of the frame around the board
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FrameStyle {
+    /// Thin single-color border
+    #[default]
+    Plain,
+    /// Wide beveled metal border
+    Metal,
+    /// Glowing neon tube
+    Neon,
+}
+
+impl FrameStyle {
+    /// All styles in settings order
+    pub fn all() -> Vec<FrameStyle> {
+        vec![FrameStyle::Plain, FrameStyle::Metal, FrameStyle::Neon]
+    }
+
+    /// Display name for menus
+    pub fn name(self) -> &'static str {
+        match self {
+            FrameStyle::Plain => "PLAIN",
+            FrameStyle::Metal => "METAL",
+            FrameStyle::Neon => "NEON",
+        }
+    }
+
+    /// Width of the frame band in pixels
+    pub fn thickness(self) -> f32 {
+        match self {
+            FrameStyle::Plain => 3.0,
+            FrameStyle::Metal => 12.0,
+            FrameStyle::Neon => 6.0,
+        }
+    }
+
+    /// Outer bounds of the frame wrapped around a board
+    pub fn outer_rect(self, board: Rect) -> Rect {
+        let thickness = self.thickness();
+        Rect::new(
+            board.x - thickness,
+            board.y - thickness,
+            board.w + thickness * 2.0,
+            board.h + thickness * 2.0,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_rect_wraps_board() {
+        let board = Rect::new(100.0, 50.0, 300.0, 600.0);
+
+        let metal = FrameStyle::Metal.outer_rect(board);
+        assert_eq!(metal, Rect::new(88.0, 38.0, 324.0, 624.0));
+
+        for style in FrameStyle::all() {
+            let outer = style.outer_rect(board);
+            let thickness = style.thickness();
+            assert_eq!(outer.x + thickness, board.x);
+            assert_eq!(outer.y + thickness, board.y);
+            assert_eq!(outer.right() - thickness, board.right());
+            assert_eq!(outer.bottom() - thickness, board.bottom());
+        }
+    }
+}
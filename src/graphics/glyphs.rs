@@ -0,0 +1,21 @@
+//! Letter glyphs that tell pieces apart without relying on color
+
+use crate::tetromino::TetrominoType;
+
+/// Symbol drawn on each block of a piece type: the initial of its name
+pub fn piece_glyph(piece_type: TetrominoType) -> char {
+    piece_type.name().chars().next().unwrap_or('?')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_every_piece_has_its_own_glyph() {
+        let glyphs: HashSet<char> = TetrominoType::all().into_iter().map(piece_glyph).collect();
+        assert_eq!(glyphs.len(), TetrominoType::all().len());
+        assert_eq!(piece_glyph(TetrominoType::T), 'T');
+    }
+}
@@ -0,0 +1,160 @@
+//! Board background grid styles
+
+use crate::board::Board;
+use crate::game::config::{BOARD_HEIGHT_PX, BOARD_OFFSET_X, BOARD_OFFSET_Y, BOARD_WIDTH, BOARD_WIDTH_PX, BUFFER_HEIGHT, CELL_SIZE, VISIBLE_HEIGHT};
+use serde::{Serialize, Deserialize};
+
+/// How the empty board background is marked out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GridStyle {
+    /// Plain background
+    None,
+    /// A dot in the middle of every cell (the legacy look)
+    Dots,
+    /// Lines along every cell edge (the modern look)
+    Lines,
+    /// Alternate cells are shaded like a checkerboard
+    Checker,
+    /// Cell edges only where they touch a filled cell, leaving empty space clean
+    Minimal,
+}
+
+/// A single primitive the renderer draws for the grid, in screen coordinates
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GridMark {
+    Line { x1: f32, y1: f32, x2: f32, y2: f32 },
+    Dot { x: f32, y: f32 },
+    Tile { x: f32, y: f32, size: f32 },
+}
+
+impl GridStyle {
+    /// All styles in settings order
+    pub fn all() -> Vec<GridStyle> {
+        vec![GridStyle::None, GridStyle::Dots, GridStyle::Lines, GridStyle::Checker, GridStyle::Minimal]
+    }
+
+    /// Display name for menus
+    pub fn name(self) -> &'static str {
+        match self {
+            GridStyle::None => "NONE",
+            GridStyle::Dots => "DOTS",
+            GridStyle::Lines => "LINES",
+            GridStyle::Checker => "CHECKER",
+            GridStyle::Minimal => "MINIMAL",
+        }
+    }
+
+    /// The style each display mode used before grids were configurable
+    pub fn preset(legacy_mode: bool) -> Self {
+        if legacy_mode {
+            GridStyle::Dots
+        } else {
+            GridStyle::Lines
+        }
+    }
+
+    /// Everything that has to be drawn for this style over the visible board
+    ///
+    /// Only `Minimal` looks at the board's contents; the other styles are fixed patterns.
+    pub fn marks(self, board: &Board) -> Vec<GridMark> {
+        let cells = || (0..VISIBLE_HEIGHT).flat_map(|y| (0..BOARD_WIDTH).map(move |x| (x, y)));
+        let cell_origin = |x: usize, y: usize| (BOARD_OFFSET_X + x as f32 * CELL_SIZE, BOARD_OFFSET_Y + y as f32 * CELL_SIZE);
+
+        match self {
+            GridStyle::None => Vec::new(),
+            GridStyle::Dots => cells()
+                .map(|(x, y)| {
+                    let (cell_x, cell_y) = cell_origin(x, y);
+                    GridMark::Dot { x: cell_x + CELL_SIZE / 2.0, y: cell_y + CELL_SIZE / 2.0 }
+                })
+                .collect(),
+            GridStyle::Lines => {
+                let vertical = (0..=BOARD_WIDTH).map(|x| {
+                    let line_x = BOARD_OFFSET_X + x as f32 * CELL_SIZE;
+                    GridMark::Line { x1: line_x, y1: BOARD_OFFSET_Y, x2: line_x, y2: BOARD_OFFSET_Y + BOARD_HEIGHT_PX }
+                });
+                let horizontal = (0..=VISIBLE_HEIGHT).map(|y| {
+                    let line_y = BOARD_OFFSET_Y + y as f32 * CELL_SIZE;
+                    GridMark::Line { x1: BOARD_OFFSET_X, y1: line_y, x2: BOARD_OFFSET_X + BOARD_WIDTH_PX, y2: line_y }
+                });
+                vertical.chain(horizontal).collect()
+            },
+            GridStyle::Checker => cells()
+                .filter(|(x, y)| (x + y) % 2 == 0)
+                .map(|(x, y)| {
+                    let (cell_x, cell_y) = cell_origin(x, y);
+                    GridMark::Tile { x: cell_x, y: cell_y, size: CELL_SIZE }
+                })
+                .collect(),
+            GridStyle::Minimal => {
+                let filled = |x: i32, y: i32| {
+                    y >= 0 && board.get_cell(x, y + BUFFER_HEIGHT as i32).is_some_and(|cell| cell.is_filled())
+                };
+                let mut marks = Vec::new();
+                for (x, y) in cells() {
+                    let (cell_x, cell_y) = cell_origin(x, y);
+                    let (x, y) = (x as i32, y as i32);
+                    // Each cell owns its right and bottom edges; the first row and column also own the outer ones
+                    if filled(x, y) || filled(x + 1, y) {
+                        marks.push(GridMark::Line { x1: cell_x + CELL_SIZE, y1: cell_y, x2: cell_x + CELL_SIZE, y2: cell_y + CELL_SIZE });
+                    }
+                    if filled(x, y) || filled(x, y + 1) {
+                        marks.push(GridMark::Line { x1: cell_x, y1: cell_y + CELL_SIZE, x2: cell_x + CELL_SIZE, y2: cell_y + CELL_SIZE });
+                    }
+                    if x == 0 && filled(x, y) {
+                        marks.push(GridMark::Line { x1: cell_x, y1: cell_y, x2: cell_x, y2: cell_y + CELL_SIZE });
+                    }
+                    if y == 0 && filled(x, y) {
+                        marks.push(GridMark::Line { x1: cell_x, y1: cell_y, x2: cell_x + CELL_SIZE, y2: cell_y });
+                    }
+                }
+                marks
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_style_marks() {
+        let board = Board::new();
+        assert!(GridStyle::None.marks(&board).is_empty());
+
+        let dots = GridStyle::Dots.marks(&board);
+        assert_eq!(dots.len(), BOARD_WIDTH * VISIBLE_HEIGHT);
+        assert!(dots.iter().all(|mark| matches!(mark, GridMark::Dot { .. })));
+
+        let lines = GridStyle::Lines.marks(&board);
+        assert_eq!(lines.len(), (BOARD_WIDTH + 1) + (VISIBLE_HEIGHT + 1));
+        assert!(lines.iter().all(|mark| matches!(mark, GridMark::Line { .. })));
+
+        let tiles = GridStyle::Checker.marks(&board);
+        assert_eq!(tiles.len(), BOARD_WIDTH * VISIBLE_HEIGHT / 2);
+        assert!(tiles.iter().all(|mark| matches!(mark, GridMark::Tile { .. })));
+    }
+
+    #[test]
+    fn test_minimal_grid_only_outlines_filled_cells() {
+        use crate::board::Cell;
+        use crate::graphics::colors::GARBAGE_COLOR;
+
+        let mut board = Board::new();
+        assert!(GridStyle::Minimal.marks(&board).is_empty());
+
+        // A lone block gets its four edges
+        board.set_cell(4, (BUFFER_HEIGHT + 10) as i32, Cell::block(GARBAGE_COLOR));
+        assert_eq!(GridStyle::Minimal.marks(&board).len(), 4);
+
+        // A neighbour shares one edge, so only three more are added
+        board.set_cell(5, (BUFFER_HEIGHT + 10) as i32, Cell::block(GARBAGE_COLOR));
+        assert_eq!(GridStyle::Minimal.marks(&board).len(), 7);
+
+        // Corner blocks still get their outer edges
+        let mut corner = Board::new();
+        corner.set_cell(0, BUFFER_HEIGHT as i32, Cell::block(GARBAGE_COLOR));
+        assert_eq!(GridStyle::Minimal.marks(&corner).len(), 4);
+    }
+}
@@ -0,0 +1,171 @@
+//! Screen positions of the board and the panels around it
+
+use crate::game::config::{BOARD_WIDTH, CELL_SIZE, UI_MARGIN, VISIBLE_HEIGHT, WINDOW_HEIGHT, WINDOW_WIDTH};
+use macroquad::camera::Camera2D;
+use macroquad::math::{Rect, Vec2};
+
+/// Side of the square area a preview or hold piece is drawn in, in cells
+pub const PANEL_CELLS: f32 = 4.0;
+/// Pieces shown in the next queue
+pub const PREVIEW_QUEUE_SLOTS: usize = 1;
+
+/// Smallest render scale, for small screens
+pub const MIN_RENDER_SCALE: f32 = 0.5;
+/// Largest render scale, for 4K and other high-DPI screens
+pub const MAX_RENDER_SCALE: f32 = 3.0;
+
+/// Gap between the board and the panels beside it
+const PANEL_GAP: f32 = UI_MARGIN + 35.0;
+/// Vertical distance between stacked preview slots
+const PREVIEW_SLOT_SPACING: f32 = 50.0;
+
+/// Where everything on the play screen goes, derived from the window and board size
+///
+/// Panel rects are the area the piece is drawn in; `panel_frame` gives the box drawn around it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layout {
+    /// Visible playfield
+    pub board: Rect,
+    /// Next queue, soonest piece first
+    pub preview_slots: Vec<Rect>,
+    /// Hold panel, left of the board
+    pub hold: Rect,
+    /// Top-left of the statistics panel, under the next queue
+    pub stats: Vec2,
+    /// Where the combo counter is drawn, under the hold panel
+    pub combo: Vec2,
+}
+
+impl Layout {
+    /// Lay out a `window` of (width, height) pixels around a board of (columns, visible rows)
+    pub fn compute(window: (f32, f32), board_dims: (usize, usize)) -> Self {
+        let (window_width, window_height) = window;
+        let board_width = board_dims.0 as f32 * CELL_SIZE;
+        let board_height = board_dims.1 as f32 * CELL_SIZE;
+        // Centered, nudged slightly down to leave room for the title
+        let board = Rect::new((window_width - board_width) / 2.0, (window_height - board_height) / 2.0 + 20.0, board_width, board_height);
+
+        let panel_size = PANEL_CELLS * CELL_SIZE;
+        let preview_x = board.right() + PANEL_GAP;
+        let preview_slots: Vec<Rect> = (0..PREVIEW_QUEUE_SLOTS)
+            .map(|slot| Rect::new(preview_x, board.y + slot as f32 * (panel_size + PREVIEW_SLOT_SPACING), panel_size, panel_size))
+            .collect();
+
+        // Hug the left edge, unless a wide board needs the hold panel pushed further out
+        let hold_x = UI_MARGIN.min(board.x - PANEL_GAP - panel_size);
+        let hold = Rect::new(hold_x, board.y, panel_size, panel_size);
+
+        let last_preview = preview_slots.last().copied().unwrap_or(Rect::new(preview_x, board.y, panel_size, 0.0));
+        Self {
+            board,
+            preview_slots,
+            hold,
+            stats: Vec2::new(preview_x, last_preview.bottom() + 60.0),
+            combo: Vec2::new(hold.x, hold.bottom() + 70.0),
+        }
+    }
+
+    /// The next piece's panel
+    pub fn next_preview(&self) -> Rect {
+        self.preview_slots[0]
+    }
+
+    /// Box drawn around a panel, leaving room for its label above
+    pub fn panel_frame(panel: Rect) -> Rect {
+        Rect::new(panel.x - 10.0, panel.y - 30.0, panel.w + 20.0, panel.h + 40.0)
+    }
+}
+
+/// Window size at a render scale
+///
+/// The constants in `config` are this layout at scale 1.0, and the renderers draw in those
+/// base units. `base_camera` maps them onto the whole window, so at any scale the picture
+/// fills a window of `window_width` by `window_height` rather than sitting small in a corner.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LayoutConfig {
+    /// Render scale the sizes below were computed at
+    pub scale: f32,
+    /// Window width
+    pub window_width: f32,
+    /// Window height
+    pub window_height: f32,
+}
+
+impl LayoutConfig {
+    /// Sizes at `scale` times the base layout (clamped to the supported range)
+    pub fn new(scale: f32) -> Self {
+        let scale = scale.clamp(MIN_RENDER_SCALE, MAX_RENDER_SCALE);
+        Self {
+            scale,
+            window_width: WINDOW_WIDTH as f32 * scale,
+            window_height: WINDOW_HEIGHT as f32 * scale,
+        }
+    }
+    
+    /// Camera that draws base-unit coordinates across the whole window, moved by `offset`
+    /// base units (for screen shake)
+    pub fn base_camera(offset: (f32, f32)) -> Camera2D {
+        let (width, height) = (WINDOW_WIDTH as f32, WINDOW_HEIGHT as f32);
+        Camera2D::from_display_rect(Rect::new(-offset.0, height - offset.1, width, -height))
+    }
+}
+
+impl Default for LayoutConfig {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl Default for Layout {
+    /// The layout for the game's window and board
+    fn default() -> Self {
+        Self::compute((WINDOW_WIDTH as f32, WINDOW_HEIGHT as f32), (BOARD_WIDTH, VISIBLE_HEIGHT))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::config::{BOARD_OFFSET_X, BOARD_OFFSET_Y};
+
+    #[test]
+    fn test_layout_centers_board_and_keeps_panels_apart() {
+        // The default layout puts the board where the rest of the renderer expects it
+        let default = Layout::default();
+        assert_eq!((default.board.x, default.board.y), (BOARD_OFFSET_X, BOARD_OFFSET_Y));
+
+        for (window, board_dims) in [((900.0, 750.0), (10, 20)), ((1280.0, 960.0), (16, 24)), ((1100.0, 750.0), (20, 20))] {
+            let layout = Layout::compute(window, board_dims);
+            assert_eq!(layout.board.center().x, window.0 / 2.0, "{:?} board off-center", board_dims);
+            assert_eq!(layout.board.w, board_dims.0 as f32 * CELL_SIZE);
+
+            let mut frames = vec![Layout::panel_frame(layout.hold)];
+            frames.extend(layout.preview_slots.iter().map(|&slot| Layout::panel_frame(slot)));
+            for (i, frame) in frames.iter().enumerate() {
+                assert!(!frame.overlaps(&layout.board), "{:?} panel {} overlaps the board", board_dims, i);
+                assert!(frame.x >= 0.0 && frame.right() <= window.0, "{:?} panel {} is off screen", board_dims, i);
+                assert!(frames[i + 1..].iter().all(|other| !frame.overlaps(other)), "{:?} panels overlap", board_dims);
+            }
+
+            // Stats sit under the queue and beside the board
+            assert!(layout.stats.y > layout.preview_slots.last().unwrap().bottom());
+            assert!(layout.stats.x > layout.board.right());
+        }
+    }
+
+    #[test]
+    fn test_layout_config_scales_proportionally() {
+        let base = LayoutConfig::new(1.0);
+        assert_eq!((base.window_width, base.window_height), (WINDOW_WIDTH as f32, WINDOW_HEIGHT as f32));
+
+        for scale in [0.5, 1.5, 2.0, 3.0] {
+            let scaled = LayoutConfig::new(scale);
+            assert_eq!(scaled.scale, scale);
+            assert_eq!(scaled.window_width, base.window_width * scale);
+            assert_eq!(scaled.window_width / scaled.window_height, base.window_width / base.window_height);
+        }
+
+        // Out-of-range scales are clamped
+        assert_eq!(LayoutConfig::new(10.0), LayoutConfig::new(MAX_RENDER_SCALE));
+    }
+}
@@ -0,0 +1,76 @@
+//! Visual cues for a grounded piece's lock delay
+
+use serde::{Serialize, Deserialize};
+
+/// How the renderer shows lock delay running out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LockDelayCue {
+    /// No cue
+    #[default]
+    Off,
+    /// The grounded piece pulses faster as it nears locking
+    Pulse,
+    /// A small bar under the piece fills up
+    Bar,
+    /// The piece itself steadily brightens towards white
+    Brighten,
+}
+
+impl LockDelayCue {
+    /// All cues in settings order
+    pub fn all() -> Vec<LockDelayCue> {
+        vec![LockDelayCue::Off, LockDelayCue::Pulse, LockDelayCue::Bar, LockDelayCue::Brighten]
+    }
+
+    /// Display name for menus
+    pub fn name(self) -> &'static str {
+        match self {
+            LockDelayCue::Off => "OFF",
+            LockDelayCue::Pulse => "PULSE",
+            LockDelayCue::Bar => "BAR",
+            LockDelayCue::Brighten => "BRIGHTEN",
+        }
+    }
+
+    /// Pulse overlay strength (0.0-1.0) for a lock delay progress at a point in time
+    ///
+    /// The pulse speeds up and brightens as progress approaches 1.0.
+    pub fn pulse_strength(progress: f32, time: f64) -> f32 {
+        let progress = progress.clamp(0.0, 1.0);
+        let speed = 6.0 + 18.0 * progress as f64;
+        let wave = ((time * speed).sin() * 0.5 + 0.5) as f32;
+        (0.15 + 0.45 * progress) * wave
+    }
+
+    /// How far (0.0-1.0) to blend a grounded piece's color towards white at a lock delay progress
+    ///
+    /// Eases in, so the change is subtle at first and obvious just before the piece locks.
+    pub fn brighten_amount(progress: f32) -> f32 {
+        let progress = progress.clamp(0.0, 1.0);
+        0.65 * progress * progress
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pulse_grows_with_progress() {
+        // Peak strength at the top of the wave rises as the lock approaches
+        let peak = |progress: f32| {
+            (0..1000).map(|i| LockDelayCue::pulse_strength(progress, i as f64 * 0.001)).fold(0.0, f32::max)
+        };
+        assert!(peak(0.9) > peak(0.1));
+        assert!(peak(1.0) <= 0.6 + f32::EPSILON);
+    }
+
+    #[test]
+    fn test_brighten_rises_with_progress() {
+        assert_eq!(LockDelayCue::brighten_amount(0.0), 0.0);
+        let steps: Vec<f32> = (0..=10).map(|i| LockDelayCue::brighten_amount(i as f32 / 10.0)).collect();
+        assert!(steps.windows(2).all(|pair| pair[1] > pair[0]));
+        // Never fully white, so the piece's color still shows
+        assert!(LockDelayCue::brighten_amount(2.0) < 1.0);
+    }
+}
@@ -1,6 +1,12 @@
 //! Graphics module containing colors, rendering utilities, and visual effects
 
 pub mod colors;
+pub mod frame;
+pub mod glyphs;
+pub mod grid;
+pub mod layout;
+pub mod lock_cue;
+pub mod theme;
 pub mod utils;
 
 pub use colors::*;
\ No newline at end of file
@@ -0,0 +1,108 @@
+//! Selectable tetromino color palettes
+
+use crate::board::Cell;
+use crate::tetromino::TetrominoType;
+use macroquad::prelude::Color;
+use serde::{Serialize, Deserialize};
+
+/// Palette used to color the pieces
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ColorTheme {
+    /// Standard guideline colors
+    #[default]
+    Guideline,
+    /// Softer, lighter versions of the guideline colors
+    Pastel,
+    /// Shades of gray, told apart by brightness
+    Monochrome,
+    /// Colorblind-safe palette (Okabe-Ito) with strong brightness differences
+    HighContrast,
+}
+
+impl ColorTheme {
+    /// All themes in settings order
+    pub fn all() -> Vec<ColorTheme> {
+        vec![ColorTheme::Guideline, ColorTheme::Pastel, ColorTheme::Monochrome, ColorTheme::HighContrast]
+    }
+
+    /// Display name for menus
+    pub fn name(self) -> &'static str {
+        match self {
+            ColorTheme::Guideline => "GUIDELINE",
+            ColorTheme::Pastel => "PASTEL",
+            ColorTheme::Monochrome => "MONOCHROME",
+            ColorTheme::HighContrast => "HIGH CONTRAST",
+        }
+    }
+
+    /// Color of a piece type in this theme
+    pub fn color_for(self, piece_type: TetrominoType) -> Color {
+        use TetrominoType::*;
+
+        match self {
+            ColorTheme::Guideline => piece_type.color(),
+            ColorTheme::Pastel => match piece_type {
+                I => Color::new(0.6, 0.92, 0.95, 1.0),
+                O => Color::new(0.98, 0.94, 0.6, 1.0),
+                T => Color::new(0.78, 0.65, 0.92, 1.0),
+                S => Color::new(0.66, 0.9, 0.66, 1.0),
+                Z => Color::new(0.96, 0.64, 0.64, 1.0),
+                J => Color::new(0.6, 0.7, 0.95, 1.0),
+                L => Color::new(0.98, 0.78, 0.56, 1.0),
+            },
+            ColorTheme::Monochrome => {
+                let shade = match piece_type {
+                    I => 0.95,
+                    O => 0.85,
+                    T => 0.5,
+                    S => 0.75,
+                    Z => 0.4,
+                    J => 0.3,
+                    L => 0.65,
+                };
+                Color::new(shade, shade, shade, 1.0)
+            },
+            ColorTheme::HighContrast => match piece_type {
+                I => Color::new(0.34, 0.71, 0.91, 1.0),
+                O => Color::new(0.94, 0.89, 0.26, 1.0),
+                T => Color::new(0.8, 0.47, 0.65, 1.0),
+                S => Color::new(0.0, 0.62, 0.45, 1.0),
+                Z => Color::new(0.84, 0.37, 0.0, 1.0),
+                J => Color::new(0.0, 0.45, 0.7, 1.0),
+                L => Color::new(0.9, 0.62, 0.0, 1.0),
+            },
+        }
+    }
+
+    /// Color of a locked board cell, or `None` if it is empty
+    ///
+    /// Cells filled by a piece take that piece's theme color; anything else (garbage, ghost
+    /// blocks) keeps its own color.
+    pub fn cell_color(self, cell: Cell) -> Option<Color> {
+        match cell.piece_type() {
+            Some(piece_type) => Some(self.color_for(piece_type)),
+            None => cell.color(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_themes_recolor_s_piece() {
+        let guideline = ColorTheme::Guideline.color_for(TetrominoType::S);
+        let high_contrast = ColorTheme::HighContrast.color_for(TetrominoType::S);
+        assert_ne!(guideline, high_contrast);
+
+        // Locked cells follow the theme too, but other colors pass through
+        assert_eq!(ColorTheme::HighContrast.cell_color(Cell::piece(TetrominoType::S)), Some(high_contrast));
+        let garbage = crate::graphics::colors::GARBAGE_COLOR;
+        assert_eq!(ColorTheme::Pastel.cell_color(Cell::block(garbage)), Some(garbage));
+        assert_eq!(ColorTheme::Pastel.cell_color(Cell::Empty), None);
+
+        // A plain block that happens to share a piece's color is still not that piece
+        assert_eq!(ColorTheme::HighContrast.cell_color(Cell::block(TetrominoType::S.color())), Some(guideline));
+    }
+}
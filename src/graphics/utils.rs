@@ -1,4 +1,213 @@
 //! Graphics rendering utilities
 
-// Graphics utility functions (placeholder for Phase 1)
-// Will be implemented as needed
+use crate::game::config::CELL_SIZE;
+use crate::tetromino::{Tetromino, TetrominoType};
+use macroquad::prelude::*;
+use std::collections::VecDeque;
+
+/// Default size of next/hold panel pieces relative to a board cell
+pub const DEFAULT_PANEL_PIECE_SCALE: f32 = 0.7;
+/// Smallest and largest panel piece scale (the largest still fits an I-piece in a 4-cell panel)
+pub const MIN_PANEL_PIECE_SCALE: f32 = 0.5;
+pub const MAX_PANEL_PIECE_SCALE: f32 = 1.0;
+
+/// Compute where to draw each block of a piece so it is centered in a square panel
+/// Returns the top-left screen position of every block and the block size
+pub fn panel_piece_blocks(
+    piece_type: TetrominoType,
+    panel_x: f32,
+    panel_y: f32,
+    panel_size: f32,
+    scale: f32,
+) -> (Vec<(f32, f32)>, f32) {
+    let block_size = CELL_SIZE * scale;
+    
+    // Offsets are block centers, so step back half a block to each block's top-left corner
+    let center_x = panel_x + panel_size / 2.0 - block_size / 2.0;
+    let center_y = panel_y + panel_size / 2.0 - block_size / 2.0;
+    let positions = Tetromino::new(piece_type)
+        .centered_preview_offsets()
+        .into_iter()
+        .map(|(dx, dy)| (center_x + dx * block_size, center_y + dy * block_size))
+        .collect();
+    
+    (positions, block_size)
+}
+
+/// Frames kept by the performance overlay (four seconds at 60 FPS)
+pub const PERF_MONITOR_SAMPLES: usize = 240;
+
+/// Rolling record of frame times for the performance overlay
+///
+/// Stats are computed from the recorded samples only, so they can be checked without a window.
+#[derive(Debug, Clone)]
+pub struct PerfMonitor {
+    /// Most recent frame times in seconds, oldest first
+    frame_times: VecDeque<f32>,
+    /// Samples kept before the oldest is dropped
+    capacity: usize,
+}
+
+impl PerfMonitor {
+    /// Create a monitor keeping the last `capacity` frame times
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            frame_times: VecDeque::with_capacity(capacity),
+            capacity: capacity.max(1),
+        }
+    }
+    
+    /// Record one frame's duration in seconds
+    pub fn record(&mut self, frame_time: f32) {
+        if self.frame_times.len() == self.capacity {
+            self.frame_times.pop_front();
+        }
+        self.frame_times.push_back(frame_time);
+    }
+    
+    /// Number of frames currently recorded
+    pub fn len(&self) -> usize {
+        self.frame_times.len()
+    }
+    
+    /// Whether no frames have been recorded yet
+    pub fn is_empty(&self) -> bool {
+        self.frame_times.is_empty()
+    }
+    
+    /// Shortest recorded frame time
+    pub fn min_frame_time(&self) -> Option<f32> {
+        self.frame_times.iter().copied().reduce(f32::min)
+    }
+    
+    /// Longest recorded frame time
+    pub fn max_frame_time(&self) -> Option<f32> {
+        self.frame_times.iter().copied().reduce(f32::max)
+    }
+    
+    /// Mean recorded frame time
+    pub fn average_frame_time(&self) -> Option<f32> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(self.frame_times.iter().sum::<f32>() / self.len() as f32)
+    }
+    
+    /// Frame time that `percentile` percent of frames were no slower than
+    pub fn percentile_frame_time(&self, percentile: f32) -> Option<f32> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut sorted: Vec<f32> = self.frame_times.iter().copied().collect();
+        sorted.sort_by(f32::total_cmp);
+        let rank = ((percentile.clamp(0.0, 100.0) / 100.0) * sorted.len() as f32).ceil() as usize;
+        Some(sorted[rank.clamp(1, sorted.len()) - 1])
+    }
+    
+    /// Frames per second over the recorded window
+    pub fn average_fps(&self) -> Option<f32> {
+        self.average_frame_time().filter(|&time| time > 0.0).map(|time| 1.0 / time)
+    }
+    
+    /// "1% low": the frame rate averaged over the slowest 1% of frames (at least one)
+    pub fn one_percent_low_fps(&self) -> Option<f32> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut sorted: Vec<f32> = self.frame_times.iter().copied().collect();
+        sorted.sort_by(|a, b| b.total_cmp(a));
+        let slowest = &sorted[..sorted.len().div_ceil(100)];
+        let average = slowest.iter().sum::<f32>() / slowest.len() as f32;
+        (average > 0.0).then(|| 1.0 / average)
+    }
+    
+    /// Draw the stats and a bar graph of recent frame times with its top-left corner at `(x, y)`
+    pub fn render(&self, x: f32, y: f32) {
+        const WIDTH: f32 = 240.0;
+        const GRAPH_HEIGHT: f32 = 60.0;
+        // Bars are scaled so this frame time (30 FPS) reaches the top of the graph
+        const GRAPH_MAX_TIME: f32 = 1.0 / 30.0;
+        
+        draw_rectangle(x, y, WIDTH, GRAPH_HEIGHT + 50.0, Color::new(0.0, 0.0, 0.0, 0.7));
+        
+        let stats = match (self.average_fps(), self.one_percent_low_fps(), self.max_frame_time()) {
+            (Some(fps), Some(low), Some(worst)) => format!("FPS {:.0}  1% LOW {:.0}  MAX {:.1}ms", fps, low, worst * 1000.0),
+            _ => "FPS --".to_string(),
+        };
+        draw_text(&stats, x + 6.0, y + 18.0, 18.0, WHITE);
+        if let (Some(best), Some(average)) = (self.min_frame_time(), self.average_frame_time()) {
+            let times = format!("MIN {:.1}ms  AVG {:.1}ms", best * 1000.0, average * 1000.0);
+            draw_text(&times, x + 6.0, y + 36.0, 18.0, LIGHTGRAY);
+        }
+        
+        let graph_top = y + 44.0;
+        let bar_width = WIDTH / self.capacity as f32;
+        // 60 FPS reference line
+        let target_y = graph_top + GRAPH_HEIGHT * (1.0 - (1.0 / 60.0) / GRAPH_MAX_TIME);
+        draw_line(x, target_y, x + WIDTH, target_y, 1.0, Color::new(0.3, 1.0, 0.3, 0.5));
+        
+        for (index, &frame_time) in self.frame_times.iter().enumerate() {
+            let height = (frame_time / GRAPH_MAX_TIME).min(1.0) * GRAPH_HEIGHT;
+            let color = if frame_time > 1.0 / 30.0 {
+                RED
+            } else if frame_time > 1.0 / 55.0 {
+                YELLOW
+            } else {
+                GREEN
+            };
+            draw_rectangle(x + index as f32 * bar_width, graph_top + GRAPH_HEIGHT - height, bar_width.max(1.0), height, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_panel_piece_centered_at_custom_scale() {
+        let (panel_x, panel_y, panel_size) = (100.0, 50.0, 4.0 * CELL_SIZE);
+        
+        for piece_type in TetrominoType::all() {
+            let (positions, block_size) = panel_piece_blocks(piece_type, panel_x, panel_y, panel_size, 0.9);
+            assert_eq!(block_size, CELL_SIZE * 0.9);
+            
+            let left = positions.iter().map(|(x, _)| *x).fold(f32::MAX, f32::min);
+            let right = positions.iter().map(|(x, _)| *x + block_size).fold(f32::MIN, f32::max);
+            let top = positions.iter().map(|(_, y)| *y).fold(f32::MAX, f32::min);
+            let bottom = positions.iter().map(|(_, y)| *y + block_size).fold(f32::MIN, f32::max);
+            
+            let panel_center = (panel_x + panel_size / 2.0, panel_y + panel_size / 2.0);
+            assert!(((left + right) / 2.0 - panel_center.0).abs() < 0.01, "{:?} not centered horizontally", piece_type);
+            assert!(((top + bottom) / 2.0 - panel_center.1).abs() < 0.01, "{:?} not centered vertically", piece_type);
+            assert!(left >= panel_x && right <= panel_x + panel_size);
+        }
+    }
+    
+    #[test]
+    fn test_perf_monitor_stats() {
+        let mut monitor = PerfMonitor::new(200);
+        assert_eq!(monitor.average_fps(), None);
+        
+        // 198 smooth frames and two 100ms hitches
+        for _ in 0..198 {
+            monitor.record(0.01);
+        }
+        monitor.record(0.1);
+        monitor.record(0.1);
+        
+        let average = monitor.average_frame_time().unwrap();
+        assert!((average - 0.0109).abs() < 1e-5, "average {}", average);
+        // The slowest 1% of 200 frames is exactly the two hitches
+        assert!((monitor.one_percent_low_fps().unwrap() - 10.0).abs() < 1e-3);
+        assert_eq!(monitor.percentile_frame_time(50.0), Some(0.01));
+        assert_eq!((monitor.min_frame_time(), monitor.max_frame_time()), (Some(0.01), Some(0.1)));
+        
+        // Old frames roll out once the buffer is full
+        for _ in 0..200 {
+            monitor.record(0.02);
+        }
+        assert_eq!(monitor.len(), 200);
+        assert!((monitor.one_percent_low_fps().unwrap() - 50.0).abs() < 1e-3);
+    }
+}
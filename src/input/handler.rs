@@ -1,5 +1,105 @@
 //! Input handling logic
 
+use serde::{Serialize, Deserialize};
+
+/// Buttons held down this frame that act on a piece the moment it spawns
+///
+/// A rotation held through a spawn pre-rotates the new piece (IRS) and a held hold button
+/// swaps it straight into hold (IHS), so fast players can act before the piece appears.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HeldInputs {
+    /// A clockwise rotate button is down
+    pub rotate_clockwise: bool,
+    /// The counterclockwise rotate button is down
+    pub rotate_counterclockwise: bool,
+    /// The hold button is down
+    pub hold: bool,
+}
+
+/// A single button press queued for the game to act on at the start of its next update
+///
+/// Held movement and soft drop repeat on timers instead, so only one-shot actions are queued.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputAction {
+    RotateClockwise,
+    RotateCounterclockwise,
+    HardDrop,
+    SonicDrop,
+    Hold,
+}
+
+/// What it takes to commit a hard drop, to guard against misdrops
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum HardDropMode {
+    /// One press drops
+    #[default]
+    Instant,
+    /// A second press inside the confirmation window drops
+    DoubleTap,
+    /// Hard drop is off; sonic drop still works
+    Disabled,
+}
+
+impl HardDropMode {
+    /// All modes in settings order
+    pub fn all() -> [HardDropMode; 3] {
+        [HardDropMode::Instant, HardDropMode::DoubleTap, HardDropMode::Disabled]
+    }
+
+    /// Display name for menus
+    pub fn name(self) -> &'static str {
+        match self {
+            HardDropMode::Instant => "INSTANT",
+            HardDropMode::DoubleTap => "DOUBLE TAP",
+            HardDropMode::Disabled => "OFF",
+        }
+    }
+}
+
+/// Decides which hard drop presses go through, remembering the last unconfirmed tap
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct HardDropConfirm {
+    /// What it takes to drop
+    pub mode: HardDropMode,
+    /// Seconds the second tap of a double tap may follow the first
+    pub window: f64,
+    /// When the tap waiting for confirmation was pressed
+    last_press: Option<f64>,
+}
+
+impl HardDropConfirm {
+    /// A confirmer for `mode`, with `window` seconds allowed between double-tap presses
+    pub fn new(mode: HardDropMode, window: f64) -> Self {
+        Self { mode, window, last_press: None }
+    }
+
+    /// Register a hard drop press at `now` seconds, returning whether it should drop
+    pub fn press(&mut self, now: f64) -> bool {
+        match self.mode {
+            HardDropMode::Instant => true,
+            HardDropMode::Disabled => false,
+            HardDropMode::DoubleTap => match self.last_press.take() {
+                Some(last) if now - last <= self.window => true,
+                _ => {
+                    // Too slow (or the first tap): this press starts a new double tap
+                    self.last_press = Some(now);
+                    false
+                },
+            },
+        }
+    }
+
+    /// Forget any unconfirmed first tap, so it can't carry over to a new piece
+    pub fn reset(&mut self) {
+        self.last_press = None;
+    }
+
+    /// Whether a first tap is waiting for its confirming second tap at `now`
+    pub fn is_pending(&self, now: f64) -> bool {
+        self.last_press.is_some_and(|last| now - last <= self.window)
+    }
+}
+
 /// Input handler struct (placeholder for Phase 1)
 #[derive(Debug)]
 pub struct InputHandler {
@@ -17,4 +117,50 @@ impl Default for InputHandler {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_double_tap_hard_drop() {
+        let mut confirm = HardDropConfirm::new(HardDropMode::DoubleTap, 0.25);
+        // A single press only arms the drop
+        assert!(!confirm.press(1.0));
+        assert!(confirm.is_pending(1.1));
+
+        // The second press inside the window drops, and the next press starts over
+        assert!(confirm.press(1.2));
+        assert!(!confirm.is_pending(1.2));
+        assert!(!confirm.press(1.3));
+
+        // Presses spaced further apart than the window never drop
+        assert!(!confirm.press(2.0));
+        assert!(!confirm.press(2.5));
+        assert!(!confirm.press(3.0));
+        assert!(confirm.press(3.1));
+    }
+
+    #[test]
+    fn test_reset_disarms_a_pending_tap() {
+        let mut confirm = HardDropConfirm::new(HardDropMode::DoubleTap, 0.25);
+        assert!(!confirm.press(1.0));
+        // The piece locked by gravity and a new one spawned
+        confirm.reset();
+        assert!(!confirm.is_pending(1.1));
+        assert!(!confirm.press(1.1));
+        assert!(confirm.press(1.2));
+    }
+
+    #[test]
+    fn test_instant_and_disabled_hard_drop() {
+        let mut instant = HardDropConfirm::new(HardDropMode::Instant, 0.25);
+        assert!(instant.press(0.0));
+        assert!(instant.press(5.0));
+
+        let mut disabled = HardDropConfirm::new(HardDropMode::Disabled, 0.25);
+        assert!(!disabled.press(0.0));
+        assert!(!disabled.press(0.1));
+    }
+}
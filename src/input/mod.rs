@@ -2,4 +2,4 @@
 
 pub mod handler;
 
-pub use handler::InputHandler;
\ No newline at end of file
+pub use handler::{HardDropConfirm, HardDropMode, HeldInputs, InputAction, InputHandler};
\ No newline at end of file
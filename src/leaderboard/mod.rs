@@ -4,12 +4,14 @@ use serde::{Serialize, Deserialize};
 use std::fs;
 use std::path::Path;
 use chrono::{DateTime, Local};
+use crate::game::{format_game_time, Difficulty, GameMode, TimeFormat};
+use crate::tetromino::{RandomizerKind, TetrominoType};
 
 /// Maximum number of high score entries to keep
 pub const MAX_LEADERBOARD_ENTRIES: usize = 10;
 
 /// A single high score entry
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LeaderboardEntry {
     /// Player name
     pub name: String,
@@ -23,6 +25,28 @@ pub struct LeaderboardEntry {
     pub game_time: f64,
     /// When this score was achieved
     pub timestamp: DateTime<Local>,
+    /// Difficulty the game was played on
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    /// Seed the game's pieces were dealt from, so the run can be replayed (0 for entries
+    /// recorded before seeds were kept)
+    #[serde(default)]
+    pub seed: u64,
+    /// Mode the game was played in
+    #[serde(default)]
+    pub mode: GameMode,
+    /// Randomizer the game dealt pieces with
+    #[serde(default)]
+    pub randomizer: RandomizerKind,
+    /// Piece types the game was limited to (empty when every piece was dealt)
+    #[serde(default)]
+    pub practice_pieces: Vec<TetrominoType>,
+    /// Whether the first piece was kept from being an S or Z
+    #[serde(default)]
+    pub first_piece_no_sz: bool,
+    /// Level the game started at
+    #[serde(default = "crate::game::state::default_start_level")]
+    pub start_level: u32,
 }
 
 impl LeaderboardEntry {
@@ -35,14 +59,56 @@ impl LeaderboardEntry {
             lines_cleared,
             game_time,
             timestamp: Local::now(),
+            difficulty: Difficulty::default(),
+            seed: 0,
+            mode: GameMode::default(),
+            randomizer: RandomizerKind::default(),
+            practice_pieces: Vec::new(),
+            first_piece_no_sz: false,
+            start_level: 1,
+        }
+    }
+    
+    /// Tag this entry with the difficulty it was played on
+    pub fn with_difficulty(mut self, difficulty: Difficulty) -> Self {
+        self.difficulty = difficulty;
+        self
+    }
+    
+    /// Tag this entry with the seed and mode needed to deal the same pieces again
+    pub fn with_seed(mut self, seed: u64, mode: GameMode) -> Self {
+        self.seed = seed;
+        self.mode = mode;
+        self
+    }
+    
+    /// Tag this entry with how its pieces were dealt and the level it started at, so a replay
+    /// of the seed deals the same pieces whatever the current settings are
+    pub fn with_deal(mut self, randomizer: RandomizerKind, practice_pieces: Vec<TetrominoType>, first_piece_no_sz: bool, start_level: u32) -> Self {
+        self.randomizer = randomizer;
+        self.practice_pieces = practice_pieces;
+        self.first_piece_no_sz = first_piece_no_sz;
+        self.start_level = start_level.max(1);
+        self
+    }
+    
+    /// Whether this entry knows its seed (entries from before seeds were kept don't)
+    pub fn has_seed(&self) -> bool {
+        self.seed != 0
+    }
+    
+    /// Short code for the seed to show on the leaderboard, or a dash if it isn't known
+    pub fn seed_code(&self) -> String {
+        if self.has_seed() {
+            format!("{:06X}", self.seed & 0xFF_FFFF)
+        } else {
+            "-".to_string()
         }
     }
     
     /// Format the game time as minutes:seconds
     pub fn formatted_time(&self) -> String {
-        let minutes = (self.game_time / 60.0) as u32;
-        let seconds = (self.game_time % 60.0) as u32;
-        format!("{}:{:02}", minutes, seconds)
+        format_game_time(self.game_time, TimeFormat::MinutesSeconds)
     }
 }
 
@@ -111,6 +177,35 @@ impl Leaderboard {
         Some(position + 1) // Convert to 1-based indexing
     }
     
+    /// Remove the entry at `index` (0-based), returning it; out-of-range indices change nothing
+    pub fn remove_entry(&mut self, index: usize) -> Option<LeaderboardEntry> {
+        if index < self.entries.len() {
+            Some(self.entries.remove(index))
+        } else {
+            None
+        }
+    }
+    
+    /// Remove every entry
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+    
+    /// Get the number of pages needed to show all entries at the given page size
+    pub fn page_count(&self, page_size: usize) -> usize {
+        if page_size == 0 {
+            return 0;
+        }
+        self.entries.len().div_ceil(page_size)
+    }
+    
+    /// Get the entries on a page (0-based index); empty if the page is out of range
+    pub fn page(&self, index: usize, page_size: usize) -> &[LeaderboardEntry] {
+        let start = index.saturating_mul(page_size).min(self.entries.len());
+        let end = start.saturating_add(page_size).min(self.entries.len());
+        &self.entries[start..end]
+    }
+    
     /// Get the default leaderboard file path
     pub fn default_path() -> std::path::PathBuf {
         std::env::current_dir()
@@ -223,4 +318,61 @@ mod tests {
         // Score of 1500 should definitely qualify
         assert!(leaderboard.qualifies_for_leaderboard(1500));
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn test_paging_over_entries() {
+        let mut leaderboard = Leaderboard::new();
+        
+        // Push directly so the entry cap doesn't truncate the list
+        for i in 0..25u32 {
+            leaderboard.entries.push(LeaderboardEntry::new(format!("Player{}", i), 2500 - i * 100, 1, 1, 60.0));
+        }
+        
+        assert_eq!(leaderboard.page_count(10), 3);
+        assert_eq!(leaderboard.page(0, 10).len(), 10);
+        assert_eq!(leaderboard.page(1, 10).len(), 10);
+        assert_eq!(leaderboard.page(2, 10).len(), 5);
+        assert!(leaderboard.page(3, 10).is_empty());
+        
+        assert_eq!(leaderboard.page(0, 10)[0].name, "Player0");
+        assert_eq!(leaderboard.page(1, 10)[0].name, "Player10");
+        assert_eq!(leaderboard.page(2, 10)[4].name, "Player24");
+    }
+    
+    #[test]
+    fn test_remove_entry_and_clear() {
+        let mut leaderboard = Leaderboard::new();
+        for i in 1..=3u32 {
+            leaderboard.add_entry(LeaderboardEntry::new(format!("Player{}", i), i * 100, 1, 1, 60.0));
+        }
+        
+        let removed = leaderboard.remove_entry(1).unwrap();
+        assert_eq!(removed.score, 200);
+        assert_eq!(leaderboard.entries.iter().map(|entry| entry.score).collect::<Vec<_>>(), vec![300, 100]);
+        
+        // Out of range is a no-op
+        assert!(leaderboard.remove_entry(5).is_none());
+        assert_eq!(leaderboard.entries.len(), 2);
+        
+        leaderboard.clear();
+        assert!(leaderboard.entries.is_empty());
+        assert_eq!(leaderboard.page_count(10), 0);
+        assert!(leaderboard.qualifies_for_leaderboard(0));
+        assert_eq!(leaderboard.add_entry(LeaderboardEntry::new("Again".to_string(), 50, 1, 1, 60.0)), Some(1));
+    }
+    
+    #[test]
+    fn test_entries_keep_their_seed_and_old_files_still_load() {
+        let entry = LeaderboardEntry::new("Seeded".to_string(), 100, 1, 1, 60.0).with_seed(0xABC123456, GameMode::Sprint);
+        assert_eq!(entry.seed_code(), "123456");
+        assert!(entry.has_seed());
+        
+        // Written before seeds and modes were recorded
+        let old = r#"{"entries":[{"name":"OLD","score":500,"level":3,"lines_cleared":20,"game_time":90.0,"timestamp":"2024-01-01T12:00:00+00:00"}]}"#;
+        let leaderboard: Leaderboard = serde_json::from_str(old).unwrap();
+        let entry = &leaderboard.entries[0];
+        assert_eq!((entry.seed, entry.mode, entry.difficulty), (0, GameMode::Marathon, Difficulty::default()));
+        assert!(!entry.has_seed());
+        assert_eq!(entry.seed_code(), "-");
+    }
+}
@@ -2,10 +2,18 @@ use macroquad::prelude::*;
 use rust_tetris::game::config::*;
 use rust_tetris::graphics::colors::*;
 use rust_tetris::board::Board;
-use rust_tetris::game::{Game, GameState};
+use rust_tetris::game::{format_game_time, Game, GameEvent, GameState, GhostStyle, NotificationKind, Replay, ReplaySnapshot, SaveResult, TemplateOverlay, TimeFormat};
 use rust_tetris::tetromino::{Tetromino, TetrominoType};
-use rust_tetris::audio::system::{AudioSystem, SoundType};
-use rust_tetris::{MenuSystem, MenuAction};
+use rust_tetris::audio::system::{AudioSystem, SoundType, MUSIC_DUCKED_LEVEL, MUSIC_FADE_TIME, event_sounds};
+use rust_tetris::graphics::utils::{panel_piece_blocks, PerfMonitor, PERF_MONITOR_SAMPLES};
+use rust_tetris::graphics::frame::FrameStyle;
+use rust_tetris::graphics::glyphs::piece_glyph;
+use rust_tetris::graphics::grid::{GridMark, GridStyle};
+use rust_tetris::graphics::layout::{Layout, LayoutConfig};
+use rust_tetris::graphics::lock_cue::LockDelayCue;
+use rust_tetris::graphics::theme::ColorTheme;
+use rust_tetris::input::{HeldInputs, InputAction};
+use rust_tetris::{MenuSystem, MenuAction, GameSettings};
 
 /// Game application state
 #[derive(Debug, PartialEq)]
@@ -20,10 +28,12 @@ enum AppState {
 
 /// Window configuration for macroquad
 fn window_conf() -> Conf {
+    // The window opens at the saved display scale; everything is drawn in base units and scaled to fit
+    let layout = LayoutConfig::new(GameSettings::load_or_default(GameSettings::default_path()).render_scale);
     Conf {
         window_title: WINDOW_TITLE.to_owned(),
-        window_width: WINDOW_WIDTH,
-        window_height: WINDOW_HEIGHT,
+        window_width: layout.window_width.round() as i32,
+        window_height: layout.window_height.round() as i32,
         window_resizable: false,
         high_dpi: false,
         ..Default::default()
@@ -68,19 +78,35 @@ async fn main() {
     // Application state management
     let mut app_state = AppState::Menu;
     let mut game: Option<Game> = None;
-    let save_path = Game::default_save_path();
     
     let mut frame_count = 0u64;
     let mut last_fps_time = get_time();
     let mut fps = 0.0;
     let mut last_save_time = get_time();
-    let auto_save_interval = 30.0; // Auto-save every 30 seconds
     let mut last_game_state_hash = 0u64; // Track game state changes for performance
+    let mut window_was_stalled = false; // Only auto-pause once per focus loss
+    let mut perf_monitor = PerfMonitor::new(PERF_MONITOR_SAMPLES);
+    let mut show_perf_overlay = false;
+    let mut render_scale = LayoutConfig::new(menu_system.settings.render_scale).scale;
     
     // Main application loop
     loop {
         let delta_time = get_frame_time();
         frame_count += 1;
+        perf_monitor.record(delta_time);
+        
+        // Resize the window when the display scale setting changes
+        let layout_config = LayoutConfig::new(menu_system.settings.render_scale);
+        if layout_config.scale != render_scale {
+            render_scale = layout_config.scale;
+            request_new_screen_size(layout_config.window_width, layout_config.window_height);
+        }
+        // Draw in base units, scaled to whatever size the window is
+        set_camera(&LayoutConfig::base_camera((0.0, 0.0)));
+        
+        if is_key_pressed(KeyCode::F3) {
+            show_perf_overlay = !show_perf_overlay;
+        }
 
         // Calculate FPS
         let current_time = get_time();
@@ -94,8 +120,24 @@ async fn main() {
         audio_system.set_audio_enabled(menu_system.settings.sound_enabled);
         audio_system.set_master_volume(menu_system.settings.volume);
         
+        // Music stops while a game is paused, plays at full level only while one is running, and fades between states
+        let game_paused = matches!((&app_state, &game), (AppState::Playing, Some(current_game)) if current_game.state == GameState::Paused);
+        if game_paused {
+            audio_system.pause_music();
+        } else {
+            audio_system.resume_music();
+            let music_level = match (&app_state, &game) {
+                (AppState::Playing, Some(current_game)) if !current_game.state.has_ended() => 1.0,
+                _ => MUSIC_DUCKED_LEVEL,
+            };
+            audio_system.fade_music_to(music_level, MUSIC_FADE_TIME);
+        }
+        audio_system.update(delta_time);
+        
         match app_state {
             AppState::Menu => {
+                audio_system.set_danger_warning(false);
+                
                 // Update menu system
                 menu_system.update(delta_time as f64);
                 
@@ -103,26 +145,38 @@ async fn main() {
                 let action = menu_system.handle_input();
                 
                 match action {
-                    MenuAction::NewGame => {
-                        log::info!("Starting new game");
-                        game = Some(Game::new());
+                    MenuAction::StartGame { mode, difficulty, start_level } => {
+                        log::info!("Starting new {} game on {} from level {}", mode.name(), difficulty.name(), start_level);
+                        game = Some(menu_system.settings.create_game_with(mode, difficulty, start_level));
+                        app_state = AppState::Playing;
+                    },
+                    MenuAction::StartRecordedGame(record) => {
+                        log::info!("Starting {} game on {} from seed {}", record.mode.name(), record.difficulty.name(), record.seed);
+                        game = Some(menu_system.settings.create_recorded_game(&record));
                         app_state = AppState::Playing;
                     },
                     MenuAction::LoadGame => {
-                        log::info!("Loading saved game");
-                        match Game::load_from_file(&save_path) {
-                            Ok(loaded_game) => {
+                        log::info!("Loading saved game from slot {}", menu_system.settings.save_slot);
+                        match Game::load_from_file(menu_system.settings.save_path()) {
+                            Ok(mut loaded_game) => {
+                                menu_system.settings.apply_to_game(&mut loaded_game);
                                 game = Some(loaded_game);
                                 app_state = AppState::Playing;
                             },
                             Err(e) => {
                                 log::warn!("Failed to load save file: {}", e);
                                 // Fall back to new game
-                                game = Some(Game::new());
+                                game = Some(menu_system.settings.create_game());
                                 app_state = AppState::Playing;
                             }
                         }
                     },
+                    MenuAction::PlaySound(SoundType::BackgroundMusic) => {
+                        // Music loops, so start it over rather than layering a second copy
+                        audio_system.stop_background_music();
+                        audio_system.start_background_music();
+                    },
+                    MenuAction::PlaySound(sound) => audio_system.play_sound(sound),
                     MenuAction::Quit => {
                         log::info!("Quitting game");
                         std::process::exit(0);
@@ -138,44 +192,52 @@ async fn main() {
             
             AppState::Playing => {
                 if let Some(ref mut current_game) = game {
+                    // Heuristic focus detection: macroquad 0.4 exposes no focus query or focus
+                    // events, but an unfocused or minimized window usually stops presenting
+                    // frames, so one long frame is taken to mean the player was away. Any other
+                    // stall that long (a slow disk, a debugger) pauses the game too, and a
+                    // platform that keeps rendering unfocused windows never trips it. Pause
+                    // before that gap reaches update(); resuming is left to the player (P).
+                    let window_stalled = delta_time as f64 >= FOCUS_LOSS_FRAME_GAP;
+                    if window_stalled && !window_was_stalled && current_game.request_pause() {
+                        log::info!("Window lost focus, pausing game");
+                    }
+                    window_was_stalled = window_stalled;
+
                     // Handle game input
                     handle_game_input(current_game, &audio_system, &mut app_state, &mut menu_system);
                     
-                    // Store previous state for audio event detection
-                    let prev_score = current_game.score;
-                    let prev_level = current_game.level();
-                    let prev_lines_cleared = current_game.lines_cleared();
-                    let was_clearing_lines = current_game.is_clearing_lines();
+                    // Store previous state to notice the game ending this frame
                     let prev_state = current_game.state;
                     
                     // Update game logic
                     current_game.update(delta_time as f64);
+                    play_action_sounds(current_game, &audio_system);
                     
                     // Check for game over and high score
-                    if current_game.state == GameState::GameOver && prev_state != GameState::GameOver {
-                        // Game just ended - check for high score
-                        if menu_system.check_high_score(
-                            current_game.score,
-                            current_game.level(),
-                            current_game.lines_cleared(),
-                            current_game.game_time
-                        ) {
-                            app_state = AppState::GameOver;
-                        } else {
-                            // No high score, return to menu
-                            app_state = AppState::Menu;
+                    if current_game.state.has_ended() && !prev_state.has_ended() {
+                        menu_system.set_last_game_summary(current_game.summary());
+                        
+                        // Keep a finished sprint as the run to race if it beat the best
+                        if current_game.records_best_sprint() {
+                            match current_game.replay.save_to_file(Replay::best_sprint_path()) {
+                                Ok(()) => log::info!("New best sprint: {:.2}s", current_game.game_time),
+                                Err(e) => log::warn!("Failed to save best sprint: {}", e),
+                            }
                         }
+                        // The game over screen stays up until the player moves on
                     }
                     
-                    // Detect and play audio for game events
-                    detect_and_play_audio_events(current_game, &audio_system, prev_score, prev_level, prev_lines_cleared, was_clearing_lines, prev_state);
+                    // Play audio for this frame's game events
+                    let events = current_game.poll_events();
+                    play_event_sounds(current_game, &mut audio_system, &events);
                     
                     // Auto-save periodically during gameplay
-                    if current_game.state == GameState::Playing && current_time - last_save_time >= auto_save_interval {
+                    if current_game.state == GameState::Playing && menu_system.settings.auto_save_interval > 0.0 && current_time - last_save_time >= menu_system.settings.auto_save_interval {
                         let current_hash = current_game.get_state_hash();
                         if current_hash != last_game_state_hash {
                             // Only save if game state has actually changed
-                            if let Err(e) = current_game.save_to_file(&save_path) {
+                            if let Err(e) = current_game.save_with_feedback(menu_system.settings.save_path()) {
                                 log::warn!("Auto-save failed: {}", e);
                             } else {
                                 last_game_state_hash = current_hash;
@@ -188,7 +250,7 @@ async fn main() {
                     }
                     
                     // Render game
-                    render_game(current_game, &background_texture, fps);
+                    render_game(current_game, &menu_system.settings, &background_texture, fps);
                 } else {
                     // No game instance, return to menu
                     app_state = AppState::Menu;
@@ -196,13 +258,15 @@ async fn main() {
             },
             
             AppState::GameOver => {
+                audio_system.set_danger_warning(false);
+                
                 // Update menu system for name entry
                 menu_system.update(delta_time as f64);
                 
                 // Handle name entry input
                 let action = menu_system.handle_input();
                 
-                if action != MenuAction::None || !matches!(menu_system.state, rust_tetris::menu::MenuState::NameEntry { .. }) {
+                if action != MenuAction::None || !matches!(menu_system.state, rust_tetris::menu::MenuState::NameEntry(_)) {
                     // Name entry complete or cancelled, return to menu
                     app_state = AppState::Menu;
                 }
@@ -224,12 +288,39 @@ async fn main() {
             );
         }
         
+        // Frame time overlay for performance debugging (F3)
+        if show_perf_overlay {
+            perf_monitor.render(10.0, 10.0);
+        }
+        
         next_frame().await;
     }
 }
 
+/// Move on from a finished game: to name entry for a high score, otherwise to the menu
+fn leave_finished_game(game: &Game, app_state: &mut AppState, menu_system: &mut MenuSystem) {
+    if menu_system.check_high_score(game) {
+        *app_state = AppState::GameOver;
+    } else {
+        *app_state = AppState::Menu;
+    }
+}
+
 /// Handle game input and transitions back to menu
-fn handle_game_input(game: &mut Game, audio_system: &AudioSystem, app_state: &mut AppState, _menu_system: &mut MenuSystem) {
+fn handle_game_input(game: &mut Game, audio_system: &AudioSystem, app_state: &mut AppState, menu_system: &mut MenuSystem) {
+    // Leave the game over screen (ENTER skips the death-cam first)
+    if game.state.has_ended() {
+        let confirm = is_key_pressed(KeyCode::Enter) || is_key_pressed(KeyCode::Space);
+        if confirm && game.death_cam_frame().is_some() {
+            game.game_over_timer = f64::MAX;
+            return;
+        }
+        if confirm || is_key_pressed(KeyCode::Escape) {
+            leave_finished_game(game, app_state, menu_system);
+            return;
+        }
+    }
+    
     // Quit to menu
     if is_key_pressed(KeyCode::Escape) {
         *app_state = AppState::Menu;
@@ -238,10 +329,9 @@ fn handle_game_input(game: &mut Game, audio_system: &AudioSystem, app_state: &mu
     
     // Save game (S key) - available in any state
     if is_key_pressed(KeyCode::S) && is_key_down(KeyCode::LeftControl) {
-        let save_path = Game::default_save_path();
-        match game.save_to_file(&save_path) {
+        match game.save_with_feedback(menu_system.settings.save_path()) {
             Ok(_) => {
-                log::info!("Game saved manually");
+                log::info!("Game saved manually to slot {}", menu_system.settings.save_slot);
                 audio_system.play_sound_with_volume(SoundType::UiClick, 1.0);
             },
             Err(e) => {
@@ -251,9 +341,18 @@ fn handle_game_input(game: &mut Game, audio_system: &AudioSystem, app_state: &mu
         return;
     }
     
+    // Retry with the same piece sequence (T key) - offered once the game is over
+    if is_key_pressed(KeyCode::T) && game.state.has_ended() {
+        game.retry_same_seed();
+        menu_system.settings.apply_to_game(game);
+        audio_system.play_sound_with_volume(SoundType::UiClick, 1.0);
+        return;
+    }
+    
     // Reset game (R key) - available in any state
     if is_key_pressed(KeyCode::R) {
         game.reset();
+        menu_system.settings.apply_to_game(game);
         audio_system.play_sound_with_volume(SoundType::UiClick, 1.0);
         return;
     }
@@ -266,17 +365,43 @@ fn handle_game_input(game: &mut Game, audio_system: &AudioSystem, app_state: &mu
     }
     
     // Legacy mode toggle (L key) - available in any state except game over
-    if is_key_pressed(KeyCode::L) && game.state != GameState::GameOver {
+    if is_key_pressed(KeyCode::L) && !game.state.has_ended() {
         game.toggle_legacy_mode();
         audio_system.play_sound_with_volume(SoundType::UiClick, 1.0);
         return;
     }
     
+    // Practice template toggle (F4 key) - loads the template file, or hides the one shown
+    if is_key_pressed(KeyCode::F4) {
+        if game.template.take().is_none() {
+            if let Err(e) = game.load_template(TemplateOverlay::default_path()) {
+                log::warn!("Failed to load template: {}", e);
+            }
+        }
+        audio_system.play_sound_with_volume(SoundType::UiClick, 1.0);
+        return;
+    }
+    
+    // Any key skips the top-out animation
+    if matches!(game.state, GameState::ToppingOut { .. }) {
+        if get_last_key_pressed().is_some() {
+            game.skip_topout_animation();
+        }
+        return;
+    }
+    
     // Only handle game controls when playing
     if game.state != GameState::Playing {
         return;
     }
     
+    // Buttons held through a spawn act on the new piece straight away (IRS/IHS)
+    game.held_inputs = HeldInputs {
+        rotate_clockwise: is_key_down(KeyCode::Up) || is_key_down(KeyCode::X) || is_key_down(KeyCode::W),
+        rotate_counterclockwise: is_key_down(KeyCode::Z),
+        hold: is_key_down(KeyCode::C),
+    };
+    
     // Ghost block controls (available during normal play)
     if is_key_pressed(KeyCode::B) {
         if game.ghost_block_placement_mode {
@@ -331,34 +456,48 @@ fn handle_game_input(game: &mut Game, audio_system: &AudioSystem, app_state: &mu
     let soft_drop_held = is_key_down(KeyCode::Down) || is_key_down(KeyCode::S);
     game.update_soft_drop(soft_drop_held);
     
+    // One-shot presses are queued and applied at the start of the update, ahead of gravity
     // Rotation (Up/X/W for clockwise, Z for counterclockwise)
     if is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::X) || is_key_pressed(KeyCode::W) {
-        if game.rotate_piece_clockwise() {
-            audio_system.play_sound_with_volume(SoundType::UiClick, 0.8);
-        }
+        game.queue_action(InputAction::RotateClockwise);
     }
     if is_key_pressed(KeyCode::Z) {
-        if game.rotate_piece_counterclockwise() {
-            audio_system.play_sound_with_volume(SoundType::UiClick, 0.8);
-        }
+        game.queue_action(InputAction::RotateCounterclockwise);
+    }
+    
+    // Hard drop (Space), which may need a confirming second tap or be turned off in settings
+    if is_key_pressed(KeyCode::Space) && game.hard_drop_confirm.press(get_time()) {
+        game.queue_action(InputAction::HardDrop);
     }
     
-    // Hard drop (Space)
-    if is_key_pressed(KeyCode::Space) {
-        game.hard_drop();
-        audio_system.play_sound(SoundType::HardDrop);
+    // Sonic drop (V) - straight down, but leaves the piece free to slide
+    if is_key_pressed(KeyCode::V) {
+        game.queue_action(InputAction::SonicDrop);
     }
     
     // Hold piece (C key)
     if is_key_pressed(KeyCode::C) {
-        if game.hold_piece() {
-            audio_system.play_sound(SoundType::HoldPiece);
+        game.queue_action(InputAction::Hold);
+    }
+}
+
+/// Play feedback for the queued presses the last update applied
+fn play_action_sounds(game: &Game, audio_system: &AudioSystem) {
+    for &(action, applied) in &game.applied_actions {
+        match (action, applied) {
+            (InputAction::RotateClockwise | InputAction::RotateCounterclockwise, true) => {
+                audio_system.play_sound_with_volume(SoundType::UiClick, 0.8);
+            },
+            (InputAction::HardDrop, _) => audio_system.play_sound(SoundType::HardDrop),
+            (InputAction::SonicDrop, true) => audio_system.play_sound_with_volume(SoundType::UiClick, 0.6),
+            (InputAction::Hold, true) => audio_system.play_sound(SoundType::HoldPiece),
+            _ => {},
         }
     }
 }
 
 /// Render the game state
-fn render_game(game: &Game, background_texture: &Texture2D, fps: f64) {
+fn render_game(game: &Game, settings: &GameSettings, background_texture: &Texture2D, fps: f64) {
     // Clear screen with appropriate background based on mode
     if game.is_legacy_mode() {
         // Pure black background for authentic terminal look
@@ -385,34 +524,80 @@ fn render_game(game: &Game, background_texture: &Texture2D, fps: f64) {
         );
     }
 
+    let layout = Layout::default();
+    
+    // Shake the board and pieces, leaving the side panels still
+    let (shake_x, shake_y) = game.screen_shake_offset();
+    if shake_x != 0.0 || shake_y != 0.0 {
+        set_camera(&LayoutConfig::base_camera((shake_x, shake_y)));
+    }
+    
     // Draw Tetris board with appropriate style (legacy vs modern)
+    let grid_style = settings.effective_grid_style(game.is_legacy_mode());
     if game.is_legacy_mode() {
-        draw_legacy_board_with_data(&game.board);
+        draw_legacy_board_with_data(&layout, &game.board, grid_style);
     } else {
-        draw_enhanced_board_with_data(&game.board);
+        draw_enhanced_board_with_data(&layout, game, grid_style, settings.color_theme, settings.show_piece_glyphs);
+        draw_board_frame(&layout, settings.frame_style);
+    }
+    
+    if game.is_in_danger() {
+        draw_danger_border(&layout);
     }
     
     // Draw line clearing animation if active
     if game.is_clearing_lines() {
         draw_line_clear_animation(&game);
+    } else if settings.show_line_hints && !game.is_legacy_mode() {
+        draw_line_hints(&game.board);
+    }
+    
+    // Outline the setup being practiced
+    if let Some(template) = game.template.as_ref() {
+        draw_template_overlay(template, &game.board);
+    }
+    
+    // Afterimage of the last hard drop, drawn under the stack's new piece
+    if !game.is_legacy_mode() {
+        if let Some((cells, distance, piece_type)) = game.hard_drop_trail() {
+            let color = settings.color_theme.color_for(*piece_type);
+            draw_hard_drop_trail(cells, *distance, color, game.hard_drop_trail_alpha());
+        }
     }
     
     // Draw the current falling piece (only if not clearing lines)
     if !game.is_clearing_lines() {
         // Draw ghost piece first (behind the actual piece)
-        if let Some(ghost_piece) = game.calculate_ghost_piece() {
-            if game.is_legacy_mode() {
-                draw_legacy_ghost_piece(&ghost_piece);
-            } else {
-                draw_ghost_piece(&ghost_piece);
+        if game.ghost_style != GhostStyle::Off {
+            if let Some(ghost_piece) = game.calculate_ghost_piece() {
+                if game.is_legacy_mode() {
+                    // The terminal ghost is already just a hollow glyph
+                    draw_legacy_ghost_piece(&ghost_piece);
+                } else if game.ghost_style == GhostStyle::Outline {
+                    draw_ghost_outline(&ghost_piece, settings.color_theme);
+                } else {
+                    draw_ghost_piece(&ghost_piece, settings.color_theme);
+                }
             }
         }
         
+        if !game.is_legacy_mode() && game.current_piece.is_some() {
+            draw_next_spawn_preview(&game.next_spawn_cells(), game.next_piece_type(), settings.color_theme);
+        }
+        
         if let Some(ref piece) = game.current_piece {
             if game.is_legacy_mode() {
                 draw_legacy_falling_piece(piece);
             } else {
-                draw_falling_piece(piece);
+                let brighten = match (settings.lock_delay_cue, game.lock_delay_progress()) {
+                    (LockDelayCue::Brighten, Some(progress)) => LockDelayCue::brighten_amount(progress),
+                    _ => 0.0,
+                };
+                draw_falling_piece(piece, settings.color_theme, game.spawn_fade_alpha(), settings.show_piece_glyphs, brighten);
+            }
+            
+            if let Some(progress) = game.lock_delay_progress() {
+                draw_lock_delay_cue(piece, progress, settings.lock_delay_cue);
             }
         }
     }
@@ -422,25 +607,40 @@ fn render_game(game: &Game, background_texture: &Texture2D, fps: f64) {
         draw_ghost_block_cursor(&game);
     }
     
+    set_camera(&LayoutConfig::base_camera((0.0, 0.0)));
+    
     // Draw next piece preview with appropriate style
     if game.is_legacy_mode() {
-        draw_legacy_next_piece_preview(&game.next_piece);
+        draw_legacy_next_piece_preview(&layout, &game.next_piece_type());
     } else {
-        draw_next_piece_preview(&game.next_piece);
+        draw_next_piece_preview(&layout, &game.next_piece_type(), settings.panel_piece_scale, settings.color_theme);
     }
     
     // Draw hold piece with appropriate style
     if game.is_legacy_mode() {
-        draw_legacy_hold_piece(&game.held_piece, game.can_hold());
+        draw_legacy_hold_piece(&layout, &game.held_piece, game.can_hold(), game.hold_enabled);
     } else {
-        draw_hold_piece(&game.held_piece, game.can_hold());
+        let hold_cooldown = game.hold_cooldown
+            .filter(|_| game.hold_cooldown_remaining() > 0)
+            .map(|cooldown| (1.0 - game.hold_cooldown_remaining() as f32 / cooldown as f32, game.hold_available_next_piece()));
+        draw_hold_piece(&layout, &game.held_piece, game.can_hold(), game.hold_enabled, hold_cooldown, settings.panel_piece_scale, settings.color_theme);
     }
     
     // Draw title with enhanced styling
     if game.is_legacy_mode() {
-        draw_legacy_ui(&game);
+        draw_legacy_ui(&layout, &game, settings.time_format);
     } else {
-        draw_enhanced_ui(&game);
+        draw_enhanced_ui(&layout, &game, settings.time_format);
+    }
+    
+    // Draw combo indicator
+    if game.combo_display >= 1 && !game.is_legacy_mode() {
+        draw_combo_indicator(&layout, game);
+    }
+    
+    // B2B / T-spin / REN callouts stacked under the combo counter
+    if !game.is_legacy_mode() && !game.active_notifications().is_empty() {
+        draw_notifications(&layout, game);
     }
     
     // Draw TETRIS celebration if active
@@ -448,15 +648,40 @@ fn render_game(game: &Game, background_texture: &Texture2D, fps: f64) {
         draw_tetris_celebration(&game);
     }
     
-    // Draw ghost throw animation if active
-    if game.is_ghost_throw_active() {
+    // Race bar against the best sprint
+    if !game.is_legacy_mode() && game.ghost_race.is_some() {
+        draw_ghost_race_bar(&game);
+    }
+    
+    // Flash the outcome of the latest save
+    if !game.is_legacy_mode() {
+        draw_save_toast(&game);
+    }
+    
+    // Draw LEVEL N banner after a level up
+    if game.level_up_banner_active() {
+        draw_level_up_banner(&game);
+    }
+    
+    // Draw PERFECT CLEAR celebration if active
+    if game.is_perfect_clear_celebration_active() {
+        draw_perfect_clear_celebration(&game);
+    }
+    
+    // Draw ghost throw animation if active (with reduced motion the block just appears)
+    if game.is_ghost_throw_active() && !game.reduce_motion {
         draw_ghost_throw_animation(&game);
     }
     
     // Draw game state overlays
     match game.state {
-        GameState::GameOver => draw_game_over_overlay(&game),
+        GameState::GameOver | GameState::Finished => draw_game_over_overlay(&game, settings.time_format, settings.color_theme),
+        GameState::ToppingOut { .. } => draw_topout_animation(game.topout_progress().unwrap_or(1.0)),
         GameState::Paused => draw_pause_overlay(&game),
+        GameState::Countdown { remaining } => draw_countdown_overlay(&format!("{}", remaining.ceil() as u32), remaining.fract() as f32),
+        GameState::Playing if game.game_time < GO_DISPLAY_TIME => {
+            draw_countdown_overlay("GO!", (1.0 - game.game_time / GO_DISPLAY_TIME) as f32);
+        },
         _ => {}, // No overlay for Playing or Menu
     }
     
@@ -572,136 +797,17 @@ fn create_chess_background() -> Image {
     image
 }
 
-/// Handle player input with audio feedback
-fn handle_input(game: &mut Game, audio_system: &AudioSystem) {
-    // Quit game
-    if is_key_pressed(KeyCode::Escape) {
-        std::process::exit(0);
-    }
-    
-    // Save game (S key) - available in any state
-    if is_key_pressed(KeyCode::S) && is_key_down(KeyCode::LeftControl) {
-        let save_path = Game::default_save_path();
-        match game.save_to_file(&save_path) {
-            Ok(_) => {
-                log::info!("Game saved manually");
-                audio_system.play_sound_with_volume(SoundType::UiClick, 1.0);
-            },
-            Err(e) => {
-                log::warn!("Manual save failed: {}", e);
-            }
-        }
-        return;
-    }
-    
-    // Reset game (R key) - available in any state
-    if is_key_pressed(KeyCode::R) {
-        game.reset();
-        audio_system.play_sound_with_volume(SoundType::UiClick, 1.0);
-        return;
-    }
-    
-    // Pause toggle (P key) - available when playing or paused
-    if is_key_pressed(KeyCode::P) && (game.state == GameState::Playing || game.state == GameState::Paused) {
-        game.toggle_pause();
-        audio_system.play_sound(SoundType::Pause);
-        return;
-    }
-    
-    // Legacy mode toggle (L key) - available in any state except game over
-    if is_key_pressed(KeyCode::L) && game.state != GameState::GameOver {
-        game.toggle_legacy_mode();
-        audio_system.play_sound_with_volume(SoundType::UiClick, 1.0);
-        return;
-    }
-    
-    // Only handle game controls when playing
-    if game.state != GameState::Playing {
-        return;
-    }
-    
-    // Ghost block controls (available during normal play)
-    if is_key_pressed(KeyCode::B) {
-        if game.ghost_block_placement_mode {
-            // B to place block when in placement mode
-            game.place_ghost_block();
-        } else {
-            // B to activate ghost block placement mode
-            game.toggle_ghost_block_mode();
-        }
-    }
-    
-    // Ghost block cursor movement (only when in placement mode)
-    if game.ghost_block_placement_mode {
-        if is_key_pressed(KeyCode::M) {
-            // M for next smart position
-            game.next_smart_position();
-        }
-        if is_key_pressed(KeyCode::N) {
-            // N for previous smart position
-            game.previous_smart_position();
-        }
-        // Also allow arrow keys for manual fine-tuning
-        if is_key_pressed(KeyCode::Up) {
-            game.move_ghost_block_cursor(0, -1);
-        }
-        if is_key_pressed(KeyCode::Down) {
-            game.move_ghost_block_cursor(0, 1);
-        }
-        if is_key_pressed(KeyCode::Left) {
-            game.move_ghost_block_cursor(-1, 0);
-        }
-        if is_key_pressed(KeyCode::Right) {
-            game.move_ghost_block_cursor(1, 0);
-        }
-        return; // Skip normal game controls when in placement mode
-    }
-    
-    // Continuous horizontal movement (Arrow keys + WASD)
-    let left_held = is_key_down(KeyCode::Left) || is_key_down(KeyCode::A);
-    let right_held = is_key_down(KeyCode::Right) || is_key_down(KeyCode::D);
-    
-    // Play movement sound on initial press only
-    if (is_key_pressed(KeyCode::Left) || is_key_pressed(KeyCode::A)) ||
-       (is_key_pressed(KeyCode::Right) || is_key_pressed(KeyCode::D)) {
-        audio_system.play_sound_with_volume(SoundType::UiClick, 0.6);
-    }
-    
-    game.update_left_movement(left_held);
-    game.update_right_movement(right_held);
-    
-    // Continuous soft drop (Down arrow + S key)
-    let soft_drop_held = is_key_down(KeyCode::Down) || is_key_down(KeyCode::S);
-    game.update_soft_drop(soft_drop_held);
-    
-    // Rotation (Up/X/W for clockwise, Z for counterclockwise)
-    if is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::X) || is_key_pressed(KeyCode::W) {
-        if game.rotate_piece_clockwise() {
-            audio_system.play_sound_with_volume(SoundType::UiClick, 0.8);
-        }
-    }
-    if is_key_pressed(KeyCode::Z) {
-        if game.rotate_piece_counterclockwise() {
-            audio_system.play_sound_with_volume(SoundType::UiClick, 0.8);
-        }
-    }
-    
-    // Hard drop (Space)
-    if is_key_pressed(KeyCode::Space) {
-        game.hard_drop();
-        audio_system.play_sound(SoundType::HardDrop);
-    }
+/// Draw the currently falling piece, blended `brighten` of the way to white
+fn draw_falling_piece(piece: &Tetromino, theme: ColorTheme, alpha: f32, show_glyphs: bool, brighten: f32) {
+    let base = theme.color_for(piece.piece_type);
+    let mut color = Color::new(
+        base.r + (1.0 - base.r) * brighten,
+        base.g + (1.0 - base.g) * brighten,
+        base.b + (1.0 - base.b) * brighten,
+        base.a,
+    );
+    color.a *= alpha;
     
-    // Hold piece (C key)
-    if is_key_pressed(KeyCode::C) {
-        if game.hold_piece() {
-            audio_system.play_sound(SoundType::HoldPiece);
-        }
-    }
-}
-
-/// Draw the currently falling piece
-fn draw_falling_piece(piece: &Tetromino) {
     for (x, y) in piece.absolute_blocks() {
         // Only draw blocks that are in the visible area
         if y >= BUFFER_HEIGHT as i32 {
@@ -715,7 +821,7 @@ fn draw_falling_piece(piece: &Tetromino) {
                 cell_y + 1.0,
                 CELL_SIZE - 2.0,
                 CELL_SIZE - 2.0,
-                piece.color(),
+                color,
             );
             
             // Draw subtle highlight for 3D effect
@@ -724,7 +830,7 @@ fn draw_falling_piece(piece: &Tetromino) {
                 cell_y + 2.0,
                 CELL_SIZE - 4.0,
                 6.0,
-                Color::new(1.0, 1.0, 1.0, 0.3),
+                Color::new(1.0, 1.0, 1.0, 0.3 * alpha),
             );
             
             // Draw subtle shadow at bottom
@@ -733,14 +839,93 @@ fn draw_falling_piece(piece: &Tetromino) {
                 cell_y + CELL_SIZE - 6.0,
                 CELL_SIZE - 4.0,
                 4.0,
-                Color::new(0.0, 0.0, 0.0, 0.2),
+                Color::new(0.0, 0.0, 0.0, 0.2 * alpha),
             );
+            
+            if show_glyphs {
+                draw_piece_glyph(piece.piece_type, cell_x, cell_y, alpha);
+            }
+        }
+    }
+}
+
+/// Draw a piece's letter centered on one of its blocks
+fn draw_piece_glyph(piece_type: TetrominoType, cell_x: f32, cell_y: f32, alpha: f32) {
+    let glyph = piece_glyph(piece_type).to_string();
+    let font_size = CELL_SIZE * 0.6;
+    let dimensions = measure_text(&glyph, None, font_size as u16, 1.0);
+    draw_text(
+        &glyph,
+        cell_x + (CELL_SIZE - dimensions.width) / 2.0,
+        cell_y + (CELL_SIZE + dimensions.offset_y) / 2.0,
+        font_size,
+        Color::new(0.0, 0.0, 0.0, 0.6 * alpha),
+    );
+}
+
+/// Draw the lock delay cue over a grounded piece
+fn draw_lock_delay_cue(piece: &Tetromino, progress: f32, cue: LockDelayCue) {
+    let visible_blocks: Vec<(i32, i32)> = piece.absolute_blocks()
+        .into_iter()
+        .filter(|&(_, y)| y >= BUFFER_HEIGHT as i32)
+        .collect();
+    if visible_blocks.is_empty() {
+        return;
+    }
+    
+    match cue {
+        // Brighten is drawn as part of the piece itself
+        LockDelayCue::Off | LockDelayCue::Brighten => {},
+        LockDelayCue::Pulse => {
+            let strength = LockDelayCue::pulse_strength(progress, get_time());
+            for (x, y) in visible_blocks {
+                draw_rectangle(
+                    BOARD_OFFSET_X + x as f32 * CELL_SIZE + 1.0,
+                    BOARD_OFFSET_Y + (y - BUFFER_HEIGHT as i32) as f32 * CELL_SIZE + 1.0,
+                    CELL_SIZE - 2.0,
+                    CELL_SIZE - 2.0,
+                    Color::new(1.0, 1.0, 1.0, strength),
+                );
+            }
+        },
+        LockDelayCue::Bar => {
+            // Span the piece's columns just under its lowest block
+            let min_x = visible_blocks.iter().map(|&(x, _)| x).min().unwrap_or(0);
+            let max_x = visible_blocks.iter().map(|&(x, _)| x).max().unwrap_or(0);
+            let max_y = visible_blocks.iter().map(|&(_, y)| y).max().unwrap_or(0);
+            
+            let bar_x = BOARD_OFFSET_X + min_x as f32 * CELL_SIZE + 2.0;
+            let bar_y = BOARD_OFFSET_Y + (max_y + 1 - BUFFER_HEIGHT as i32) as f32 * CELL_SIZE - 5.0;
+            let bar_w = (max_x - min_x + 1) as f32 * CELL_SIZE - 4.0;
+            
+            draw_rectangle(bar_x, bar_y, bar_w, 4.0, Color::new(0.0, 0.0, 0.0, 0.6));
+            let fill = Color::new(1.0, 1.0 - progress * 0.8, 0.2, 0.9);
+            draw_rectangle(bar_x, bar_y, bar_w * progress, 4.0, fill);
+        },
+    }
+}
+
+/// Draw fading copies of a hard-dropped piece along its path, brightest near where it landed
+fn draw_hard_drop_trail(start_cells: &[(i32, i32)], distance: i32, color: Color, alpha: f32) {
+    for step in 0..distance {
+        let strength = alpha * 0.35 * (step + 1) as f32 / (distance + 1) as f32;
+        for &(x, y) in start_cells {
+            let y = y + step;
+            if y >= BUFFER_HEIGHT as i32 {
+                draw_rectangle(
+                    BOARD_OFFSET_X + x as f32 * CELL_SIZE + 2.0,
+                    BOARD_OFFSET_Y + (y - BUFFER_HEIGHT as i32) as f32 * CELL_SIZE + 2.0,
+                    CELL_SIZE - 4.0,
+                    CELL_SIZE - 4.0,
+                    Color::new(color.r, color.g, color.b, strength),
+                );
+            }
         }
     }
 }
 
 /// Draw the ghost piece (shadow piece showing where current piece will land)
-fn draw_ghost_piece(ghost_piece: &Tetromino) {
+fn draw_ghost_piece(ghost_piece: &Tetromino, theme: ColorTheme) {
     for (x, y) in ghost_piece.absolute_blocks() {
         // Only draw blocks that are in the visible area
         if y >= BUFFER_HEIGHT as i32 {
@@ -748,7 +933,7 @@ fn draw_ghost_piece(ghost_piece: &Tetromino) {
             let cell_x = BOARD_OFFSET_X + (x as f32 * CELL_SIZE);
             let cell_y = BOARD_OFFSET_Y + (visible_y as f32 * CELL_SIZE);
             
-            let base_color = ghost_piece.color();
+            let base_color = theme.color_for(ghost_piece.piece_type);
             
             // Enhanced ghost piece visibility:
             // 1. Brighter, thicker outer border for better contrast
@@ -832,6 +1017,75 @@ fn draw_ghost_piece(ghost_piece: &Tetromino) {
     }
 }
 
+/// Draw the ghost piece as a thin colored outline only
+fn draw_ghost_outline(ghost_piece: &Tetromino, theme: ColorTheme) {
+    let base_color = theme.color_for(ghost_piece.piece_type);
+    let outline_color = Color::new(base_color.r, base_color.g, base_color.b, 0.8);
+    
+    for (x, y) in ghost_piece.absolute_blocks() {
+        // Only draw blocks that are in the visible area
+        if y >= BUFFER_HEIGHT as i32 {
+            let visible_y = y - BUFFER_HEIGHT as i32;
+            let cell_x = BOARD_OFFSET_X + (x as f32 * CELL_SIZE);
+            let cell_y = BOARD_OFFSET_Y + (visible_y as f32 * CELL_SIZE);
+            draw_rectangle_lines(cell_x + 1.0, cell_y + 1.0, CELL_SIZE - 2.0, CELL_SIZE - 2.0, 1.0, outline_color);
+        }
+    }
+}
+
+/// Draw a faint outline of where the next piece will spawn
+///
+/// Spawn cells sit in the hidden buffer rows, so the shape is drawn against the top of the
+/// visible board, keeping its columns.
+fn draw_next_spawn_preview(cells: &[(i32, i32)], piece_type: TetrominoType, theme: ColorTheme) {
+    let Some(top) = cells.iter().map(|&(_, y)| y).min() else {
+        return;
+    };
+    let base_color = theme.color_for(piece_type);
+    let outline_color = Color::new(base_color.r, base_color.g, base_color.b, 0.25);
+    
+    for &(x, y) in cells {
+        let cell_x = BOARD_OFFSET_X + (x as f32 * CELL_SIZE);
+        let cell_y = BOARD_OFFSET_Y + ((y - top) as f32 * CELL_SIZE);
+        draw_rectangle_lines(cell_x + 3.0, cell_y + 3.0, CELL_SIZE - 6.0, CELL_SIZE - 6.0, 1.0, outline_color);
+    }
+}
+
+/// Glow the empty cells of visible rows that are only a cell or two from clearing
+fn draw_line_hints(board: &Board) {
+    let pulse = ((get_time() * 3.0).sin() * 0.5 + 0.5) as f32;
+    let glow = Color::new(1.0, 0.95, 0.6, 0.08 + pulse * 0.14);
+    
+    for y in board.rows_missing_at_most(LINE_HINT_MAX_GAPS) {
+        if y < BUFFER_HEIGHT {
+            continue;
+        }
+        let cell_y = BOARD_OFFSET_Y + ((y - BUFFER_HEIGHT) as f32 * CELL_SIZE);
+        for x in 0..BOARD_WIDTH {
+            if board.is_position_valid(x as i32, y as i32) {
+                let cell_x = BOARD_OFFSET_X + (x as f32 * CELL_SIZE);
+                draw_rectangle(cell_x + 2.0, cell_y + 2.0, CELL_SIZE - 4.0, CELL_SIZE - 4.0, glow);
+            }
+        }
+    }
+}
+
+/// Outline the template's empty target cells and show how much of it is built
+fn draw_template_overlay(template: &TemplateOverlay, board: &Board) {
+    let outline = Color::new(0.7, 0.9, 1.0, 0.35);
+    for &(x, y) in &template.cells {
+        if y < BUFFER_HEIGHT as i32 || !board.is_position_valid(x, y) {
+            continue;
+        }
+        let cell_x = BOARD_OFFSET_X + (x as f32 * CELL_SIZE);
+        let cell_y = BOARD_OFFSET_Y + ((y - BUFFER_HEIGHT as i32) as f32 * CELL_SIZE);
+        draw_rectangle_lines(cell_x + 3.0, cell_y + 3.0, CELL_SIZE - 6.0, CELL_SIZE - 6.0, 2.0, outline);
+    }
+    
+    let progress = format!("{} {}/{}", template.name, template.filled_count(board), template.cells.len());
+    draw_text(&progress, BOARD_OFFSET_X + 6.0, BOARD_OFFSET_Y + 18.0, 18.0, Color::new(0.7, 0.9, 1.0, 0.6));
+}
+
 /// Draw the ghost block cursor for placement with rainbow clockwise animation
 fn draw_ghost_block_cursor(game: &Game) {
     let (cursor_x, cursor_y) = game.ghost_block_cursor;
@@ -1610,6 +1864,15 @@ fn draw_line_clear_animation(game: &Game) {
     let progress = game.get_clear_animation_progress();
     let clearing_lines = game.get_clearing_lines();
     
+    // Reduced motion: a plain fading flash over the rows, no particles
+    if game.reduce_motion {
+        for &line_y in clearing_lines.iter().filter(|&&line_y| line_y >= BUFFER_HEIGHT) {
+            let y = BOARD_OFFSET_Y + (line_y - BUFFER_HEIGHT) as f32 * CELL_SIZE;
+            draw_rectangle(BOARD_OFFSET_X, y, BOARD_WIDTH as f32 * CELL_SIZE, CELL_SIZE, Color::new(1.0, 1.0, 1.0, 0.8 * (1.0 - progress as f32)));
+        }
+        return;
+    }
+    
     for (line_idx, &line_y) in clearing_lines.iter().enumerate() {
         // Only animate lines in visible area
         if line_y >= BUFFER_HEIGHT {
@@ -1749,25 +2012,26 @@ fn draw_line_clear_animation(game: &Game) {
 }
 
 /// Draw the next piece preview
-fn draw_next_piece_preview(next_piece_type: &TetrominoType) {
-    let preview_x = PREVIEW_OFFSET_X;
-    let preview_y = PREVIEW_OFFSET_Y;
+fn draw_next_piece_preview(layout: &Layout, next_piece_type: &TetrominoType, piece_scale: f32, theme: ColorTheme) {
+    let panel = layout.next_preview();
+    let (preview_x, preview_y) = (panel.x, panel.y);
+    let frame = Layout::panel_frame(panel);
     
     // Draw preview panel background - retro style
     draw_rectangle(
-        preview_x - 10.0,
-        preview_y - 30.0,
-        PREVIEW_SIZE + 20.0,
-        PREVIEW_SIZE + 40.0,
+        frame.x,
+        frame.y,
+        frame.w,
+        frame.h,
         Color::new(0.0, 0.0, 0.2, 0.8), // Dark blue retro background
     );
     
     // Draw preview panel border - cyan retro
     draw_rectangle_lines(
-        preview_x - 10.0,
-        preview_y - 30.0,
-        PREVIEW_SIZE + 20.0,
-        PREVIEW_SIZE + 40.0,
+        frame.x,
+        frame.y,
+        frame.w,
+        frame.h,
         2.0,
         Color::new(0.0, 1.0, 1.0, 0.8), // Cyan border
     );
@@ -1781,27 +2045,19 @@ fn draw_next_piece_preview(next_piece_type: &TetrominoType) {
         Color::new(1.0, 1.0, 0.0, 1.0), // Yellow retro style
     );
     
-    // Create a temporary piece for preview
-    let preview_piece = Tetromino::new(*next_piece_type);
-    let blocks = preview_piece.blocks;
-    
-    // Center the piece in the preview area
-    let center_x = preview_x + PREVIEW_SIZE / 2.0;
-    let center_y = preview_y + PREVIEW_SIZE / 2.0;
+    // Center the piece in the preview area at the configured scale
+    let (blocks, block_size) = panel_piece_blocks(*next_piece_type, preview_x, preview_y, panel.w, piece_scale);
     
     // Draw the piece blocks
-    for (dx, dy) in blocks {
-        let block_x = center_x + (dx as f32 * CELL_SIZE * 0.7); // Smaller size for preview
-        let block_y = center_y + (dy as f32 * CELL_SIZE * 0.7);
-        let block_size = CELL_SIZE * 0.7;
-        
+    for (block_x, block_y) in blocks {
+
         // Draw filled cell
         draw_rectangle(
             block_x,
             block_y,
             block_size - 1.0,
             block_size - 1.0,
-            next_piece_type.color(),
+            theme.color_for(*next_piece_type),
         );
         
         // Draw highlight
@@ -1816,27 +2072,31 @@ fn draw_next_piece_preview(next_piece_type: &TetrominoType) {
 }
 
 /// Draw the hold piece preview
-fn draw_hold_piece(held_piece: &Option<TetrominoType>, can_hold: bool) {
-    let hold_x = HOLD_OFFSET_X;
-    let hold_y = HOLD_OFFSET_Y;
+///
+/// `cooldown` is the fraction of a hold cooldown already served and whether hold returns with
+/// the next piece; while it runs the held piece is drawn in gray under a lock icon.
+fn draw_hold_piece(layout: &Layout, held_piece: &Option<TetrominoType>, can_hold: bool, hold_enabled: bool, cooldown: Option<(f32, bool)>, piece_scale: f32, theme: ColorTheme) {
+    let panel = layout.hold;
+    let (hold_x, hold_y) = (panel.x, panel.y);
+    let frame = Layout::panel_frame(panel);
     
     // Draw hold panel background - retro style
     let bg_alpha = if can_hold { 0.8 } else { 0.4 }; // Dimmed when can't hold
     draw_rectangle(
-        hold_x - 10.0,
-        hold_y - 30.0,
-        HOLD_SIZE + 20.0,
-        HOLD_SIZE + 40.0,
+        frame.x,
+        frame.y,
+        frame.w,
+        frame.h,
         Color::new(0.0, 0.0, 0.2, bg_alpha), // Dark blue retro background
     );
     
     // Draw hold panel border - retro cyan
     let border_alpha = if can_hold { 0.8 } else { 0.4 };
     draw_rectangle_lines(
-        hold_x - 10.0,
-        hold_y - 30.0,
-        HOLD_SIZE + 20.0,
-        HOLD_SIZE + 40.0,
+        frame.x,
+        frame.y,
+        frame.w,
+        frame.h,
         2.0,
         Color::new(0.0, 1.0, 1.0, border_alpha), // Cyan border
     );
@@ -1858,29 +2118,27 @@ fn draw_hold_piece(held_piece: &Option<TetrominoType>, can_hold: bool) {
     
     // Draw the held piece if there is one
     if let Some(piece_type) = held_piece {
-        // Create a temporary piece for preview
-        let hold_piece = Tetromino::new(*piece_type);
-        let blocks = hold_piece.blocks;
-        
-        // Center the piece in the hold area
-        let center_x = hold_x + HOLD_SIZE / 2.0;
-        let center_y = hold_y + HOLD_SIZE / 2.0;
+        // Center the piece in the hold area at the configured scale
+        let (blocks, block_size) = panel_piece_blocks(*piece_type, hold_x, hold_y, panel.w, piece_scale);
         
         // Draw the piece blocks
         let piece_alpha = if can_hold { 1.0 } else { 0.5 };
-        for (dx, dy) in blocks {
-            let block_x = center_x + (dx as f32 * CELL_SIZE * 0.7); // Smaller size for hold
-            let block_y = center_y + (dy as f32 * CELL_SIZE * 0.7);
-            let block_size = CELL_SIZE * 0.7;
-            
+        for (block_x, block_y) in blocks {
+
             // Get piece color and apply alpha based on hold availability
-            let base_color = piece_type.color();
-            let final_color = Color::new(
-                base_color.r,
-                base_color.g,
-                base_color.b,
-                piece_alpha,
-            );
+            let base_color = theme.color_for(*piece_type);
+            let final_color = if cooldown.is_some() {
+                // Grayed out while the cooldown runs
+                let gray = (base_color.r + base_color.g + base_color.b) / 3.0 * 0.6;
+                Color::new(gray, gray, gray, piece_alpha)
+            } else {
+                Color::new(
+                    base_color.r,
+                    base_color.g,
+                    base_color.b,
+                    piece_alpha,
+                )
+            };
             
             // Draw filled cell
             draw_rectangle(
@@ -1910,43 +2168,68 @@ fn draw_hold_piece(held_piece: &Option<TetrominoType>, can_hold: bool) {
             Color::new(0.5, 0.5, 0.5, 0.5)
         };
         
+        let (hint_top, hint_bottom) = if hold_enabled { ("Press C", "to hold") } else { ("Hold", "disabled") };
         draw_text(
-            "Press C",
+            hint_top,
             hold_x + 5.0,
-            hold_y + HOLD_SIZE / 2.0 - 5.0,
+            hold_y + panel.w / 2.0 - 5.0,
             TEXT_SIZE * 0.7,
             hint_color,
         );
         draw_text(
-            "to hold",
+            hint_bottom,
             hold_x + 8.0,
-            hold_y + HOLD_SIZE / 2.0 + 15.0,
+            hold_y + panel.w / 2.0 + 15.0,
             TEXT_SIZE * 0.7,
             hint_color,
         );
     }
+    
+    // Padlock and progress bar while a hold cooldown runs
+    if let Some((progress, ready_next)) = cooldown {
+        let lock_color = if ready_next {
+            Color::new(1.0, 1.0, 0.0, 0.8)
+        } else {
+            Color::new(0.7, 0.7, 0.8, 0.7)
+        };
+        let lock_x = hold_x + panel.w - 10.0;
+        let lock_y = hold_y - 24.0;
+        draw_circle_lines(lock_x + 5.0, lock_y + 6.0, 4.0, 1.5, lock_color);
+        draw_rectangle(lock_x, lock_y + 6.0, 10.0, 8.0, lock_color);
+        
+        let bar_y = hold_y + panel.w + 2.0;
+        draw_rectangle(hold_x, bar_y, panel.w, 4.0, Color::new(0.2, 0.2, 0.3, 0.8));
+        draw_rectangle(hold_x, bar_y, panel.w * progress.clamp(0.0, 1.0), 4.0, lock_color);
+    }
+    
+    // Cross out the panel when the ruleset forbids holding
+    if !hold_enabled {
+        draw_line(frame.x, frame.y, frame.right(), frame.bottom(), 2.0, Color::new(1.0, 0.3, 0.3, 0.6));
+        draw_line(frame.right(), frame.y, frame.x, frame.bottom(), 2.0, Color::new(1.0, 0.3, 0.3, 0.6));
+    }
 }
 
 /// Draw legacy-style next piece preview using ASCII characters
-fn draw_legacy_next_piece_preview(next_piece_type: &TetrominoType) {
-    let preview_x = PREVIEW_OFFSET_X;
-    let preview_y = PREVIEW_OFFSET_Y;
+fn draw_legacy_next_piece_preview(layout: &Layout, next_piece_type: &TetrominoType) {
+    let panel = layout.next_preview();
+    let (preview_x, preview_y) = (panel.x, panel.y);
+    let frame = Layout::panel_frame(panel);
     
     // Draw preview panel background - terminal style
     draw_rectangle(
-        preview_x - 10.0,
-        preview_y - 30.0,
-        PREVIEW_SIZE + 20.0,
-        PREVIEW_SIZE + 40.0,
+        frame.x,
+        frame.y,
+        frame.w,
+        frame.h,
         Color::new(0.05, 0.05, 0.1, 0.9), // Very dark terminal background
     );
     
     // Draw simple border
     draw_rectangle_lines(
-        preview_x - 10.0,
-        preview_y - 30.0,
-        PREVIEW_SIZE + 20.0,
-        PREVIEW_SIZE + 40.0,
+        frame.x,
+        frame.y,
+        frame.w,
+        frame.h,
         1.0,
         Color::new(0.4, 0.6, 0.6, 0.8), // Dim cyan border
     );
@@ -1962,16 +2245,15 @@ fn draw_legacy_next_piece_preview(next_piece_type: &TetrominoType) {
     
     // Create a temporary piece for preview
     let preview_piece = Tetromino::new(*next_piece_type);
-    let blocks = preview_piece.blocks;
     
-    // Center the piece in the preview area
-    let center_x = preview_x + PREVIEW_SIZE / 2.0;
-    let center_y = preview_y + PREVIEW_SIZE / 2.0;
+    // Center the piece's bounding box in the preview area
+    let center_x = preview_x + panel.w / 2.0;
+    let center_y = preview_y + panel.w / 2.0;
     
     // Draw the piece using ASCII blocks
-    for (dx, dy) in blocks {
-        let block_x = center_x + (dx as f32 * CELL_SIZE * 0.7); // Smaller size for preview
-        let block_y = center_y + (dy as f32 * CELL_SIZE * 0.7);
+    for (dx, dy) in preview_piece.centered_preview_offsets() {
+        let block_x = center_x + dx * CELL_SIZE * 0.7; // Smaller size for preview
+        let block_y = center_y + dy * CELL_SIZE * 0.7;
         
             // Draw ASCII block character in terminal green
             let block_char = "█"; // Full block character
@@ -1989,27 +2271,28 @@ fn draw_legacy_next_piece_preview(next_piece_type: &TetrominoType) {
 }
 
 /// Draw legacy-style hold piece preview using ASCII characters
-fn draw_legacy_hold_piece(held_piece: &Option<TetrominoType>, can_hold: bool) {
-    let hold_x = HOLD_OFFSET_X;
-    let hold_y = HOLD_OFFSET_Y;
+fn draw_legacy_hold_piece(layout: &Layout, held_piece: &Option<TetrominoType>, can_hold: bool, hold_enabled: bool) {
+    let panel = layout.hold;
+    let (hold_x, hold_y) = (panel.x, panel.y);
+    let frame = Layout::panel_frame(panel);
     
     // Draw hold panel background - terminal style
     let bg_alpha = if can_hold { 0.9 } else { 0.4 }; // Dimmed when can't hold
     draw_rectangle(
-        hold_x - 10.0,
-        hold_y - 30.0,
-        HOLD_SIZE + 20.0,
-        HOLD_SIZE + 40.0,
+        frame.x,
+        frame.y,
+        frame.w,
+        frame.h,
         Color::new(0.05, 0.05, 0.1, bg_alpha), // Very dark terminal background
     );
     
     // Draw simple border
     let border_alpha = if can_hold { 0.8 } else { 0.4 };
     draw_rectangle_lines(
-        hold_x - 10.0,
-        hold_y - 30.0,
-        HOLD_SIZE + 20.0,
-        HOLD_SIZE + 40.0,
+        frame.x,
+        frame.y,
+        frame.w,
+        frame.h,
         1.0,
         Color::new(0.4, 0.6, 0.6, border_alpha), // Dim cyan border
     );
@@ -2033,17 +2316,16 @@ fn draw_legacy_hold_piece(held_piece: &Option<TetrominoType>, can_hold: bool) {
     if let Some(piece_type) = held_piece {
         // Create a temporary piece for preview
         let hold_piece = Tetromino::new(*piece_type);
-        let blocks = hold_piece.blocks;
         
-        // Center the piece in the hold area
-        let center_x = hold_x + HOLD_SIZE / 2.0;
-        let center_y = hold_y + HOLD_SIZE / 2.0;
+        // Center the piece's bounding box in the hold area
+        let center_x = hold_x + panel.w / 2.0;
+        let center_y = hold_y + panel.w / 2.0;
         
         // Draw the piece using ASCII blocks
         let piece_alpha = if can_hold { 1.0 } else { 0.5 };
-        for (dx, dy) in blocks {
-            let block_x = center_x + (dx as f32 * CELL_SIZE * 0.7); // Smaller size for hold
-            let block_y = center_y + (dy as f32 * CELL_SIZE * 0.7);
+        for (dx, dy) in hold_piece.centered_preview_offsets() {
+            let block_x = center_x + dx * CELL_SIZE * 0.7; // Smaller size for hold
+            let block_y = center_y + dy * CELL_SIZE * 0.7;
             
             // Draw ASCII block character
             let block_char = "█"; // Full block character
@@ -2074,21 +2356,28 @@ fn draw_legacy_hold_piece(held_piece: &Option<TetrominoType>, can_hold: bool) {
             Color::new(0.4, 0.4, 0.4, 0.5)
         };
         
+        let (hint_top, hint_bottom) = if hold_enabled { ("Press C", "to hold") } else { ("Hold", "disabled") };
         draw_text(
-            "Press C",
+            hint_top,
             hold_x + 5.0,
-            hold_y + HOLD_SIZE / 2.0 - 5.0,
+            hold_y + panel.w / 2.0 - 5.0,
             TEXT_SIZE * 0.7,
             hint_color,
         );
         draw_text(
-            "to hold",
+            hint_bottom,
             hold_x + 8.0,
-            hold_y + HOLD_SIZE / 2.0 + 15.0,
+            hold_y + panel.w / 2.0 + 15.0,
             TEXT_SIZE * 0.7,
             hint_color,
         );
     }
+    
+    // Cross out the panel when the ruleset forbids holding
+    if !hold_enabled {
+        draw_line(frame.x, frame.y, frame.right(), frame.bottom(), 2.0, Color::new(0.0, 0.6, 0.0, 0.6));
+        draw_line(frame.right(), frame.y, frame.x, frame.bottom(), 2.0, Color::new(0.0, 0.6, 0.0, 0.6));
+    }
 }
 
 /// Draw legacy-style falling piece using ASCII block characters
@@ -2152,12 +2441,12 @@ fn draw_legacy_ghost_piece(ghost_piece: &Tetromino) {
 }
 
 /// Draw authentic terminal-style Tetris board like the original
-fn draw_legacy_board_with_data(board: &Board) {
+fn draw_legacy_board_with_data(layout: &Layout, board: &Board, grid_style: GridStyle) {
     let terminal_green = Color::new(0.0, 1.0, 0.0, 1.0); // Bright terminal green
     
     // Use the same positioning as modern board for consistency
-    let board_start_x = BOARD_OFFSET_X;
-    let board_start_y = BOARD_OFFSET_Y;
+    let board_start_x = layout.board.x;
+    let board_start_y = layout.board.y;
     let char_width = CELL_SIZE; // Same width as modern cells
     let char_height = CELL_SIZE; // Same height as modern cells
     let char_size = CELL_SIZE * 0.8; // Font size relative to cell size
@@ -2172,6 +2461,8 @@ fn draw_legacy_board_with_data(board: &Board) {
         terminal_green,
     );
     
+    draw_board_grid(board, grid_style, true);
+    
     // Draw the game board with borders
     for y in 0..VISIBLE_HEIGHT {
         // Left border (moved further from board content)
@@ -2191,34 +2482,15 @@ fn draw_legacy_board_with_data(board: &Board) {
             let cell_x = board_start_x + (x as f32 * char_width) + char_width * 0.25;
             let cell_y = board_start_y + (y as f32 * char_height) + char_height * 0.7;
             
-            if let Some(cell) = board.get_cell(board_x, board_y) {
-                if cell.color().is_some() {
-                    // Use original terminal blocks
-                    draw_text(
-                        "█", // Full block for authentic look
-                        cell_x,
-                        cell_y,
-                        char_size,
-                        terminal_green,
-                    );
-                } else {
-                    // Empty space with subtle dot
-                    draw_text(
-                        "·",
-                        cell_x,
-                        cell_y,
-                        char_size,
-                        Color::new(0.0, 0.25, 0.0, 0.8), // More subtle dim green for dots
-                    );
-                }
-            } else {
-                // Empty space with subtle dot
+            // Empty cells are left to the grid
+            if board.get_cell(board_x, board_y).is_some_and(|cell| cell.is_filled()) {
+                // Use original terminal blocks
                 draw_text(
-                    "·",
+                    "█", // Full block for authentic look
                     cell_x,
                     cell_y,
                     char_size,
-                    Color::new(0.0, 0.25, 0.0, 0.8), // More subtle dim green for dots
+                    terminal_green,
                 );
             }
         }
@@ -2254,148 +2526,297 @@ fn draw_legacy_board_with_data(board: &Board) {
     );
 }
 
+/// Draw the decorative frame around the playfield
+fn draw_board_frame(layout: &Layout, style: FrameStyle) {
+    let board = layout.board;
+    let outer = style.outer_rect(board);
+    let thickness = style.thickness();
+    
+    match style {
+        FrameStyle::Plain => {
+            draw_rectangle_lines(outer.x, outer.y, outer.w, outer.h, thickness, Color::new(0.6, 0.7, 0.9, 0.6));
+        },
+        FrameStyle::Metal => {
+            // Four bands of brushed steel around the board
+            let steel = Color::new(0.45, 0.48, 0.52, 1.0);
+            draw_rectangle(outer.x, outer.y, outer.w, thickness, steel);
+            draw_rectangle(outer.x, board.bottom(), outer.w, thickness, steel);
+            draw_rectangle(outer.x, board.y, thickness, board.h, steel);
+            draw_rectangle(board.right(), board.y, thickness, board.h, steel);
+            
+            // Bevel: light outer top-left and inner bottom-right edges, dark opposite edges
+            let light = Color::new(0.8, 0.82, 0.86, 1.0);
+            let dark = Color::new(0.18, 0.2, 0.23, 1.0);
+            draw_line(outer.x, outer.y, outer.right(), outer.y, 2.0, light);
+            draw_line(outer.x, outer.y, outer.x, outer.bottom(), 2.0, light);
+            draw_line(outer.x, outer.bottom(), outer.right(), outer.bottom(), 2.0, dark);
+            draw_line(outer.right(), outer.y, outer.right(), outer.bottom(), 2.0, dark);
+            draw_line(board.x, board.y, board.right(), board.y, 2.0, dark);
+            draw_line(board.x, board.y, board.x, board.bottom(), 2.0, dark);
+            draw_line(board.x, board.bottom(), board.right(), board.bottom(), 2.0, light);
+            draw_line(board.right(), board.y, board.right(), board.bottom(), 2.0, light);
+        },
+        FrameStyle::Neon => {
+            // Soft glow layers behind a bright core tube
+            let pulse = ((get_time() * 2.0).sin() * 0.15 + 0.85) as f32;
+            for (spread, alpha) in [(6.0, 0.12), (3.0, 0.25)] {
+                draw_rectangle_lines(
+                    outer.x - spread,
+                    outer.y - spread,
+                    outer.w + spread * 2.0,
+                    outer.h + spread * 2.0,
+                    thickness + spread,
+                    Color::new(1.0, 0.2, 0.9, alpha * pulse),
+                );
+            }
+            draw_rectangle_lines(outer.x, outer.y, outer.w, outer.h, thickness / 2.0, Color::new(1.0, 0.6, 1.0, pulse));
+        },
+    }
+}
+
+/// Fill the board in with grey rows from the bottom up as the top-out animation runs
+fn draw_topout_animation(progress: f32) {
+    let filled_rows = (progress * BOARD_HEIGHT as f32).ceil() as usize;
+    for row in 0..filled_rows.min(BOARD_HEIGHT) {
+        let y = BOARD_OFFSET_Y + (BOARD_HEIGHT - 1 - row) as f32 * CELL_SIZE;
+        for x in 0..BOARD_WIDTH {
+            draw_rectangle(
+                BOARD_OFFSET_X + x as f32 * CELL_SIZE + 1.0,
+                y + 1.0,
+                CELL_SIZE - 2.0,
+                CELL_SIZE - 2.0,
+                Color::new(0.45, 0.45, 0.5, 0.85),
+            );
+        }
+    }
+}
+
+/// Draw a pulsing red border around the board while the stack is dangerously high
+fn draw_danger_border(layout: &Layout) {
+    let playfield = layout.board;
+    let pulse = ((get_time() * 6.0).sin() * 0.5 + 0.5) as f32;
+    let color = Color::new(1.0, 0.15, 0.1, 0.35 + 0.5 * pulse);
+    
+    for (inset, thickness) in [(3.0, 4.0), (7.0, 2.0)] {
+        draw_rectangle_lines(
+            playfield.x - inset,
+            playfield.y - inset,
+            playfield.w + inset * 2.0,
+            playfield.h + inset * 2.0,
+            thickness,
+            Color::new(color.r, color.g, color.b, color.a / (inset / 3.0)),
+        );
+    }
+}
+
+/// Draw the board background grid in the given style, tinted for legacy or modern mode
+fn draw_board_grid(board: &Board, grid_style: GridStyle, legacy: bool) {
+    let (line_color, dot_color, tile_color) = if legacy {
+        let dim_green = Color::new(0.0, 0.25, 0.0, 0.8);
+        (dim_green, dim_green, Color::new(0.0, 0.12, 0.0, 1.0))
+    } else {
+        (GRID_LINE_COLOR, GRID_LINE_COLOR, Color::new(1.0, 1.0, 1.0, 0.04))
+    };
+    
+    for mark in grid_style.marks(board) {
+        match mark {
+            GridMark::Line { x1, y1, x2, y2 } => draw_line(x1, y1, x2, y2, GRID_LINE_WIDTH, line_color),
+            GridMark::Dot { x, y } => {
+                if legacy {
+                    // Terminal-style middle dot, positioned like the block characters
+                    draw_text("·", x - CELL_SIZE * 0.25, y + CELL_SIZE * 0.2, CELL_SIZE * 0.8, dot_color);
+                } else {
+                    draw_circle(x, y, 1.5, dot_color);
+                }
+            },
+            GridMark::Tile { x, y, size } => draw_rectangle(x, y, size, size, tile_color),
+        }
+    }
+}
+
+/// Shade the open space above each column, darker the taller the column, as a depth cue
+fn draw_column_shadows(playfield: Rect, board: &Board) {
+    for (x, &height) in board.column_heights().iter().enumerate() {
+        let visible_height = (height as usize).min(BOARD_HEIGHT);
+        if visible_height == 0 {
+            continue;
+        }
+        let open_rows = (BOARD_HEIGHT - visible_height) as f32;
+        let strength = 0.15 * visible_height as f32 / BOARD_HEIGHT as f32;
+        draw_rectangle(
+            playfield.x + x as f32 * CELL_SIZE,
+            playfield.y,
+            CELL_SIZE,
+            open_rows * CELL_SIZE,
+            Color::new(0.0, 0.0, 0.0, strength),
+        );
+    }
+}
+
 /// Draw enhanced Tetris board with modern styling and real data
-fn draw_enhanced_board_with_data(board: &Board) {
+fn draw_enhanced_board_with_data(layout: &Layout, game: &Game, grid_style: GridStyle, theme: ColorTheme, show_glyphs: bool) {
+    let playfield = layout.board;
+    let board = &game.board;
+    
     // Draw board shadow
     draw_rectangle(
-        BOARD_OFFSET_X + 5.0,
-        BOARD_OFFSET_Y + 5.0,
-        BOARD_WIDTH_PX,
-        BOARD_HEIGHT_PX,
+        playfield.x + 5.0,
+        playfield.y + 5.0,
+        playfield.w,
+        playfield.h,
         BOARD_SHADOW,
     );
     
     // Draw board background with gradient effect
     draw_rectangle(
-        BOARD_OFFSET_X,
-        BOARD_OFFSET_Y,
-        BOARD_WIDTH_PX,
-        BOARD_HEIGHT_PX,
+        playfield.x,
+        playfield.y,
+        playfield.w,
+        playfield.h,
         BOARD_BACKGROUND,
     );
     
     // Draw subtle inner glow
     draw_rectangle_lines(
-        BOARD_OFFSET_X - 1.0,
-        BOARD_OFFSET_Y - 1.0,
-        BOARD_WIDTH_PX + 2.0,
-        BOARD_HEIGHT_PX + 2.0,
+        playfield.x - 1.0,
+        playfield.y - 1.0,
+        playfield.w + 2.0,
+        playfield.h + 2.0,
         1.0,
         Color::new(0.6, 0.7, 0.9, 0.3),
     );
     
-    // Draw grid lines with improved styling
-    for x in 0..=BOARD_WIDTH {
-        let line_x = BOARD_OFFSET_X + (x as f32 * CELL_SIZE);
-        draw_line(
-            line_x,
-            BOARD_OFFSET_Y,
-            line_x,
-            BOARD_OFFSET_Y + BOARD_HEIGHT_PX,
-            GRID_LINE_WIDTH,
-            GRID_LINE_COLOR,
+    draw_board_grid(board, grid_style, false);
+    draw_column_shadows(playfield, board);
+    
+    // Draw filled cells from the board data, skipping the empty ones and the buffer rows
+    for (board_x, board_y, cell) in board.iter_filled_cells() {
+        if board_y < BUFFER_HEIGHT as i32 {
+            continue;
+        }
+        let Some(mut color) = theme.cell_color(cell) else {
+            continue;
+        };
+        let (x, y) = (board_x as usize, board_y as usize - BUFFER_HEIGHT);
+        // Fade locked blocks in invisible mode
+        let alpha = game.cell_visibility_alpha(board_x, board_y);
+        if alpha <= 0.0 {
+            continue;
+        }
+        color.a *= alpha;
+        
+        let cell_x = playfield.x + (x as f32 * CELL_SIZE);
+        let cell_y = playfield.y + (y as f32 * CELL_SIZE);
+        
+        // Draw filled cell with border
+        draw_rectangle(
+            cell_x + 1.0,
+            cell_y + 1.0,
+            CELL_SIZE - 2.0,
+            CELL_SIZE - 2.0,
+            color,
         );
-    }
-
-    for y in 0..=VISIBLE_HEIGHT {
-        let line_y = BOARD_OFFSET_Y + (y as f32 * CELL_SIZE);
-        draw_line(
-            BOARD_OFFSET_X,
-            line_y,
-            BOARD_OFFSET_X + BOARD_WIDTH_PX,
-            line_y,
-            GRID_LINE_WIDTH,
-            GRID_LINE_COLOR,
+        
+        // Draw subtle highlight for 3D effect
+        draw_rectangle(
+            cell_x + 2.0,
+            cell_y + 2.0,
+            CELL_SIZE - 4.0,
+            6.0,
+            Color::new(1.0, 1.0, 1.0, 0.3 * alpha),
         );
-    }
-    
-    // Draw filled cells from the board data
-    for y in 0..VISIBLE_HEIGHT {
-        for x in 0..BOARD_WIDTH {
-            // Convert to board coordinates (includes buffer rows)
-            let board_y = (y + BUFFER_HEIGHT) as i32;
-            let board_x = x as i32;
-            
-            if let Some(cell) = board.get_cell(board_x, board_y) {
-                if let Some(color) = cell.color() {
-                    let cell_x = BOARD_OFFSET_X + (x as f32 * CELL_SIZE);
-                    let cell_y = BOARD_OFFSET_Y + (y as f32 * CELL_SIZE);
-                    
-                    // Draw filled cell with border
-                    draw_rectangle(
-                        cell_x + 1.0,
-                        cell_y + 1.0,
-                        CELL_SIZE - 2.0,
-                        CELL_SIZE - 2.0,
-                        color,
-                    );
-                    
-                    // Draw subtle highlight for 3D effect
-                    draw_rectangle(
-                        cell_x + 2.0,
-                        cell_y + 2.0,
-                        CELL_SIZE - 4.0,
-                        6.0,
-                        Color::new(1.0, 1.0, 1.0, 0.3),
-                    );
-                    
-                    // Draw subtle shadow at bottom
-                    draw_rectangle(
-                        cell_x + 2.0,
-                        cell_y + CELL_SIZE - 6.0,
-                        CELL_SIZE - 4.0,
-                        4.0,
-                        Color::new(0.0, 0.0, 0.0, 0.2),
-                    );
-                }
+        
+        // Draw subtle shadow at bottom
+        draw_rectangle(
+            cell_x + 2.0,
+            cell_y + CELL_SIZE - 6.0,
+            CELL_SIZE - 4.0,
+            4.0,
+            Color::new(0.0, 0.0, 0.0, 0.2 * alpha),
+        );
+        
+        if show_glyphs {
+            if let Some(piece_type) = cell.piece_type() {
+                draw_piece_glyph(piece_type, cell_x, cell_y, alpha);
             }
         }
+        
+        // Brighten the piece that just locked
+        let flash = game.lock_flash_alpha(board_x, board_y);
+        if flash > 0.0 {
+            draw_rectangle(
+                cell_x + 1.0,
+                cell_y + 1.0,
+                CELL_SIZE - 2.0,
+                CELL_SIZE - 2.0,
+                Color::new(1.0, 1.0, 1.0, 0.6 * flash),
+            );
+        }
     }
 
     // Draw enhanced border with multiple layers
     draw_rectangle_lines(
-        BOARD_OFFSET_X,
-        BOARD_OFFSET_Y,
-        BOARD_WIDTH_PX,
-        BOARD_HEIGHT_PX,
+        playfield.x,
+        playfield.y,
+        playfield.w,
+        playfield.h,
         BOARD_BORDER_WIDTH,
         BOARD_BORDER_COLOR,
     );
 }
 
 
-/// Detect and play audio for game events
-fn detect_and_play_audio_events(
-    game: &Game,
-    audio_system: &AudioSystem,
-    _prev_score: u32,
-    prev_level: u32,
-    _prev_lines_cleared: u32,
-    was_clearing_lines: bool,
-    prev_state: GameState,
-) {
-    // Don't play any gameplay sounds during game over state to prevent spam
-    if game.state == GameState::GameOver {
-        // Only play game over sound when transitioning to game over
-        if prev_state == GameState::Playing {
-            audio_system.play_sound(SoundType::GameOver);
-        }
-        return; // Exit early to prevent other sounds during game over
-    }
+/// Draw the pulsing combo counter below the hold panel, fading out once the combo ends
+fn draw_combo_indicator(layout: &Layout, game: &Game) {
+    let alpha = if game.current_combo() >= 1 {
+        1.0
+    } else {
+        (1.0 - game.combo_fade_timer / COMBO_FADE_TIME).max(0.0) as f32
+    };
     
-    // Line clearing sound (when lines start clearing)
-    if !was_clearing_lines && game.is_clearing_lines() {
-        audio_system.play_sound(SoundType::LineClear);
-    }
+    // Pulse faster and bigger as the combo grows
+    let pulse_speed = 6.0 + game.combo_display.min(10) as f64;
+    let pulse = ((game.game_time * pulse_speed).sin() * 0.5 + 0.5) as f32;
+    let font_size = TEXT_SIZE * (1.2 + 0.15 * pulse);
+    
+    let Vec2 { x, y } = layout.combo;
+    let combo_text = format!("COMBO x{}", game.combo_display);
     
-    // Piece lock sound (when a piece was just locked, but not during line clearing)
-    if game.piece_just_locked && !game.is_clearing_lines() {
-        audio_system.play_sound_with_volume(SoundType::PieceSnap, 0.8);
+    // Glow behind the text
+    draw_text(&combo_text, x + 2.0, y + 2.0, font_size, Color::new(0.0, 0.0, 0.0, 0.6 * alpha));
+    draw_text(&combo_text, x, y, font_size, Color::new(1.0, 0.6 + 0.4 * pulse, 0.1, alpha));
+}
+
+/// Draw the stack of recent callouts under the combo counter, newest at the bottom, each
+/// drifting upward and fading out over its lifetime
+fn draw_notifications(layout: &Layout, game: &Game) {
+    let font_size = TEXT_SIZE * 1.1;
+    let spacing = font_size + 8.0;
+    let base_y = layout.combo.y + 50.0;
+    
+    for (index, notification) in game.active_notifications().iter().enumerate() {
+        let progress = notification.progress() as f32;
+        // Pop in quickly, hold, then fade over the last 40%
+        let alpha = (progress / 0.1).min(1.0) * ((1.0 - progress) / 0.4).min(1.0);
+        let y = base_y + index as f32 * spacing - progress * 20.0;
+        let color = match notification.kind {
+            NotificationKind::BackToBack => Color::new(1.0, 0.85, 0.2, alpha),
+            NotificationKind::Combo => Color::new(1.0, 0.5, 0.2, alpha),
+            NotificationKind::Clear => Color::new(0.8, 0.5, 1.0, alpha),
+        };
+        
+        draw_text(&notification.text, layout.combo.x + 2.0, y + 2.0, font_size, Color::new(0.0, 0.0, 0.0, 0.6 * alpha));
+        draw_text(&notification.text, layout.combo.x, y, font_size, color);
     }
+}
+
+/// Play the sounds for this frame's game events
+fn play_event_sounds(game: &Game, audio_system: &mut AudioSystem, events: &[GameEvent]) {
+    // Loop the warning tone only while actively playing with a high stack
+    audio_system.set_danger_warning(game.state == GameState::Playing && game.is_in_danger());
     
-    // Level up sound
-    if game.level() > prev_level {
-        audio_system.play_sound(SoundType::LevelComplete);
+    for (sound, volume) in event_sounds(events) {
+        audio_system.play_sound_with_volume(sound, volume);
     }
 }
 
@@ -2499,8 +2920,16 @@ fn draw_retro_tetris_logo() {
     }
 }
 
+/// Level line for the stats panel, with the lines left until the next one
+fn level_stat(game: &Game) -> String {
+    match game.lines_until_next_level() {
+        Some(lines) => format!("Level: {} ({} to next)", game.level(), lines),
+        None => format!("Level: {} (max)", game.level()),
+    }
+}
+
 /// Draw enhanced UI elements with retro theme
-fn draw_enhanced_ui(game: &Game) {
+fn draw_enhanced_ui(layout: &Layout, game: &Game, time_format: TimeFormat) {
     // Draw retro TETRIS title logo
     draw_retro_tetris_logo();
     
@@ -2523,6 +2952,7 @@ fn draw_enhanced_ui(game: &Game) {
         "↓ S - Soft Drop",
         "↑ X W / Z - Rotate",
         "SPACE - Hard Drop",
+        "V - Sonic Drop",
         "C - Hold Piece",
         "P - Pause / R - Reset",
         "Ctrl+S - Save Game",
@@ -2567,8 +2997,8 @@ fn draw_enhanced_ui(game: &Game) {
     }
     
     // Game statistics panel with retro styling - position on right side (consistent with preview spacing)
-    let stats_x = PREVIEW_OFFSET_X; // Use same x position as preview panel
-    let mut stats_y = PREVIEW_OFFSET_Y + PREVIEW_SIZE + 60.0; // Below the Next piece panel
+    let stats_x = layout.stats.x; // Lined up with the preview panel
+    let mut stats_y = layout.stats.y; // Below the Next piece panel
     
     // Stats background - retro dark blue
     draw_rectangle(
@@ -2600,17 +3030,36 @@ fn draw_enhanced_ui(game: &Game) {
     stats_y += 15.0;
     
     // Individual stats
-    let stats = vec![
+    let mut stats = vec![
         format!("Score: {}", game.score),
-        format!("Level: {}", game.level()),
+        level_stat(game),
         format!("Lines: {}", game.lines_cleared()),
-        format!("Ghost Blocks: {}", game.ghost_blocks_available),
         format!("State: {:?}", game.state),
-        format!("Time: {:.0}s", game.game_time),
+        format!("Time: {}", format_game_time(game.game_time, time_format)),
     ];
+    // Ghost blocks are left out entirely when the feature is off
+    let ghost_row = if game.ghost_blocks_enabled {
+        stats.insert(3, format!("Ghost Blocks: {}", game.ghost_blocks_available));
+        Some(3)
+    } else {
+        None
+    };
+    if let Some(remaining) = game.pieces_remaining() {
+        stats.push(format!("Pieces Left: {}", remaining));
+    }
+    if let Some(remaining) = game.lines_remaining() {
+        stats.push(format!("Lines Left: {}", remaining));
+    }
+    if let Some(remaining) = game.cheese_remaining() {
+        stats.push(format!("Cheese Left: {}", remaining));
+    }
+    if let (true, Some(cleared), Some(rate)) = (game.mode.refills_cheese(), game.cheese_cleared_total(), game.cheese_rate()) {
+        stats.push(format!("Cheese Dug: {} ({:.1}/min)", cleared, rate));
+    }
+    stats.push(format!("Finesse Faults: {}", game.finesse_faults));
     
     for (i, stat) in stats.iter().enumerate() {
-        let color = if i == 3 && game.ghost_blocks_available > 0 {
+        let color = if Some(i) == ghost_row && game.ghost_blocks_available > 0 {
             // Highlight ghost blocks count with pulsing effect when available
             let pulse = (game.game_time * 3.0).sin() as f32 * 0.3 + 0.7;
             Color::new(0.8, 0.8, 1.0, pulse) // Light blue pulsing
@@ -2682,7 +3131,7 @@ fn draw_enhanced_ui(game: &Game) {
 }
 
 /// Draw legacy-style UI with terminal-style text and minimal styling
-fn draw_legacy_ui(game: &Game) {
+fn draw_legacy_ui(layout: &Layout, game: &Game, time_format: TimeFormat) {
     let terminal_green = Color::new(0.0, 1.0, 0.0, 1.0);
     
     // Simple title in terminal green (same position as modern title)
@@ -2706,6 +3155,7 @@ fn draw_legacy_ui(game: &Game) {
         "↓ S - Soft Drop",
         "↑ X W / Z - Rotate",
         "SPACE - Hard Drop",
+        "V - Sonic Drop",
         "C - Hold Piece",
         "P - Pause / R - Reset",
         "L - Modern Mode", // Changed from original
@@ -2732,8 +3182,8 @@ fn draw_legacy_ui(game: &Game) {
     }
     
     // Game statistics - consistent positioning with preview panel
-    let stats_x = PREVIEW_OFFSET_X; // Use same x position as preview panel
-    let mut stats_y = PREVIEW_OFFSET_Y + PREVIEW_SIZE + 60.0; // Same as modern UI
+    let stats_x = layout.stats.x; // Lined up with the preview panel
+    let mut stats_y = layout.stats.y; // Same as modern UI
     
     // Stats title
     draw_text(
@@ -2746,17 +3196,36 @@ fn draw_legacy_ui(game: &Game) {
     stats_y += 15.0;
     
     // Individual stats (same format as modern UI)
-    let stats = vec![
+    let mut stats = vec![
         format!("Score: {}", game.score),
-        format!("Level: {}", game.level()),
+        level_stat(game),
         format!("Lines: {}", game.lines_cleared()),
-        format!("Ghost Blocks: {}", game.ghost_blocks_available),
         format!("State: {:?}", game.state),
-        format!("Time: {:.0}s", game.game_time),
+        format!("Time: {}", format_game_time(game.game_time, time_format)),
     ];
+    // Ghost blocks are left out entirely when the feature is off
+    let ghost_row = if game.ghost_blocks_enabled {
+        stats.insert(3, format!("Ghost Blocks: {}", game.ghost_blocks_available));
+        Some(3)
+    } else {
+        None
+    };
+    if let Some(remaining) = game.pieces_remaining() {
+        stats.push(format!("Pieces Left: {}", remaining));
+    }
+    if let Some(remaining) = game.lines_remaining() {
+        stats.push(format!("Lines Left: {}", remaining));
+    }
+    if let Some(remaining) = game.cheese_remaining() {
+        stats.push(format!("Cheese Left: {}", remaining));
+    }
+    if let (true, Some(cleared), Some(rate)) = (game.mode.refills_cheese(), game.cheese_cleared_total(), game.cheese_rate()) {
+        stats.push(format!("Cheese Dug: {} ({:.1}/min)", cleared, rate));
+    }
+    stats.push(format!("Finesse Faults: {}", game.finesse_faults));
     
     for (i, stat) in stats.iter().enumerate() {
-        let color = if i == 3 && game.ghost_blocks_available > 0 {
+        let color = if Some(i) == ghost_row && game.ghost_blocks_available > 0 {
             // Highlight ghost blocks count (terminal green instead of blue)
             terminal_green
         } else {
@@ -2819,8 +3288,18 @@ fn draw_legacy_ui(game: &Game) {
     }
 }
 
+/// Headline for a finished goal-based game, e.g. "40 LINES IN 1:23.45!"
+fn finish_message(game: &Game, time_format: TimeFormat) -> String {
+    let time = format_game_time(game.game_time, time_format);
+    match (game.line_goal, game.piece_limit) {
+        (Some(goal), _) => format!("{} LINES IN {}!", goal, time),
+        (None, Some(limit)) => format!("{} PIECES: {} POINTS!", limit, game.score),
+        (None, None) => "FINISHED!".to_string(),
+    }
+}
+
 /// Draw Game Over overlay
-fn draw_game_over_overlay(game: &Game) {
+fn draw_game_over_overlay(game: &Game, time_format: TimeFormat, theme: ColorTheme) {
     // Semi-transparent dark overlay
     draw_rectangle(
         0.0,
@@ -2830,9 +3309,18 @@ fn draw_game_over_overlay(game: &Game) {
         Color::new(0.0, 0.0, 0.0, 0.7),
     );
     
-    // Game Over message
-    let message = "GAME OVER";
-    let font_size = 60.0;
+    // Game Over message, or the result for a finished goal-based mode
+    let (message, message_color) = if game.state == GameState::Finished {
+        (finish_message(game, time_format), Color::new(0.3, 1.0, 0.4, 1.0))
+    } else {
+        ("GAME OVER".to_string(), Color::new(1.0, 0.2, 0.2, 1.0))
+    };
+    let message = message.as_str();
+    // Shrink long results to fit the window
+    let mut font_size = 60.0;
+    while font_size > 24.0 && measure_text(message, None, font_size as u16, 1.0).width > WINDOW_WIDTH as f32 - 80.0 {
+        font_size -= 4.0;
+    }
     let text_width = measure_text(message, None, font_size as u16, 1.0).width;
     let center_x = (WINDOW_WIDTH as f32 - text_width) / 2.0;
     let center_y = WINDOW_HEIGHT as f32 / 2.0 - 80.0;
@@ -2853,21 +3341,42 @@ fn draw_game_over_overlay(game: &Game) {
         }
     }
     
-    // Main text in bright red
+    // Main text in bright red (green for a finished run)
     draw_text(
         message,
         center_x,
         center_y,
         font_size,
-        Color::new(1.0, 0.2, 0.2, 1.0),
+        message_color,
     );
     
+    // Replay the final seconds before showing the stats
+    if let Some(frame) = game.death_cam_frame() {
+        draw_death_cam(frame, theme, center_y + 40.0);
+        return;
+    }
+    
+    // Explain how the game ended
+    if game.state == GameState::GameOver {
+        if let Some(reason) = game.game_over_reason {
+            let reason_text = format!("{} - {}", reason.title(), reason.description());
+            let reason_width = measure_text(&reason_text, None, 22, 1.0).width;
+            draw_text(
+                &reason_text,
+                (WINDOW_WIDTH as f32 - reason_width) / 2.0,
+                center_y + 32.0,
+                22.0,
+                Color::new(1.0, 0.6, 0.4, 1.0),
+            );
+        }
+    }
+    
     // Final stats
     let stats_lines = vec![
         format!("Final Score: {}", game.score),
         format!("Level Reached: {}", game.level()),
         format!("Lines Cleared: {}", game.lines_cleared()),
-        format!("Time Played: {:.0}s", game.game_time),
+        format!("Time Played: {}", format_game_time(game.game_time, time_format)),
     ];
     
     let stats_y_start = center_y + 60.0;
@@ -2901,7 +3410,7 @@ fn draw_game_over_overlay(game: &Game) {
     }
     
     // Instructions
-    let instruction = "Press R to restart or ESC to quit";
+    let instruction = "ENTER to continue • R to restart • T to retry (same pieces)";
     let inst_width = measure_text(instruction, None, 20, 1.0).width;
     let inst_x = (WINDOW_WIDTH as f32 - inst_width) / 2.0;
     let inst_y = stats_y_start + 180.0;
@@ -2930,6 +3439,39 @@ fn draw_game_over_overlay(game: &Game) {
     );
 }
 
+/// Draw one death-cam frame as a small board below the game over message
+fn draw_death_cam(frame: &ReplaySnapshot, theme: ColorTheme, top: f32) {
+    const CELL: f32 = 14.0;
+    let width = BOARD_WIDTH as f32 * CELL;
+    let left = (WINDOW_WIDTH as f32 - width) / 2.0;
+    
+    draw_rectangle(left, top, width, VISIBLE_HEIGHT as f32 * CELL, BOARD_BACKGROUND);
+    draw_rectangle_lines(left - 2.0, top - 2.0, width + 4.0, VISIBLE_HEIGHT as f32 * CELL + 4.0, 2.0, Color::new(1.0, 0.3, 0.3, 0.9));
+    
+    let draw_cell = |x: i32, y: i32, color: Color| {
+        if y >= BUFFER_HEIGHT as i32 {
+            let cell_y = top + (y - BUFFER_HEIGHT as i32) as f32 * CELL;
+            draw_rectangle(left + x as f32 * CELL + 1.0, cell_y + 1.0, CELL - 2.0, CELL - 2.0, color);
+        }
+    };
+    for y in 0..(BOARD_HEIGHT + BUFFER_HEIGHT) as i32 {
+        for x in 0..BOARD_WIDTH as i32 {
+            if let Some(color) = frame.board.get_cell(x, y).and_then(|cell| theme.cell_color(cell)) {
+                draw_cell(x, y, color);
+            }
+        }
+    }
+    if let Some(ref piece) = frame.piece {
+        for (x, y) in piece.absolute_blocks() {
+            draw_cell(x, y, theme.color_for(piece.piece_type));
+        }
+    }
+    
+    let caption = "REPLAY • ENTER to skip";
+    let caption_width = measure_text(caption, None, 18, 1.0).width;
+    draw_text(caption, (WINDOW_WIDTH as f32 - caption_width) / 2.0, top + VISIBLE_HEIGHT as f32 * CELL + 24.0, 18.0, Color::new(0.8, 0.8, 0.9, 1.0));
+}
+
 /// Draw Pause overlay
 fn draw_pause_overlay(_game: &Game) {
     // Semi-transparent dark overlay
@@ -3003,6 +3545,27 @@ fn draw_pause_overlay(_game: &Game) {
     );
 }
 
+/// Draw the big centered countdown number (or "GO!"), fading and shrinking as `strength` falls
+fn draw_countdown_overlay(message: &str, strength: f32) {
+    let strength = strength.clamp(0.0, 1.0);
+    let font_size = 90.0 + 50.0 * strength;
+    let text_width = measure_text(message, None, font_size as u16, 1.0).width;
+    let x = BOARD_OFFSET_X + (BOARD_WIDTH_PX - text_width) / 2.0;
+    let y = BOARD_OFFSET_Y + BOARD_HEIGHT_PX / 2.0;
+    let alpha = 0.4 + 0.6 * strength;
+    
+    // Draw outline for better visibility
+    for offset_x in [-3.0, 0.0, 3.0] {
+        for offset_y in [-3.0, 0.0, 3.0] {
+            if offset_x != 0.0 || offset_y != 0.0 {
+                draw_text(message, x + offset_x, y + offset_y, font_size, Color::new(0.0, 0.0, 0.0, 0.9 * alpha));
+            }
+        }
+    }
+    
+    draw_text(message, x, y, font_size, Color::new(1.0, 0.9, 0.2, alpha));
+}
+
 /// Show startup menu with load/new game options
 async fn show_startup_menu(save_path: &std::path::Path) -> Game {
     // Create background texture once (same as main game)
@@ -3218,6 +3781,112 @@ async fn show_startup_menu(save_path: &std::path::Path) -> Game {
     }
 }
 
+/// Draw progress bars for this run and the raced best run under the board
+fn draw_ghost_race_bar(game: &Game) {
+    let (Some(best), Some(goal), Some(delta)) = (game.ghost_race.as_ref(), game.line_goal, game.ghost_race_delta()) else {
+        return;
+    };
+    
+    let x = BOARD_OFFSET_X;
+    let y = BOARD_OFFSET_Y + BOARD_HEIGHT_PX + 16.0;
+    let width = BOARD_WIDTH_PX - 56.0;
+    let fraction = |lines: u32| (lines.min(goal) as f32 / goal.max(1) as f32) * width;
+    
+    draw_rectangle(x, y, width, 12.0, Color::new(0.1, 0.1, 0.15, 0.8));
+    draw_rectangle(x, y, fraction(game.lines_cleared()), 5.0, Color::new(0.3, 0.8, 1.0, 0.9));
+    draw_rectangle(x, y + 7.0, fraction(best.lines_at_time(game.game_time)), 5.0, Color::new(1.0, 1.0, 1.0, 0.35));
+    
+    let (label, color) = match delta {
+        d if d > 0 => (format!("+{}", d), Color::new(0.4, 1.0, 0.5, 1.0)),
+        d if d < 0 => (format!("{}", d), Color::new(1.0, 0.4, 0.4, 1.0)),
+        _ => ("±0".to_string(), TEXT_COLOR),
+    };
+    draw_text(&label, x + width + 8.0, y + 12.0, 20.0, color);
+}
+
+/// Draw the fading toast for the latest save
+fn draw_save_toast(game: &Game) {
+    let Some(saved_at) = game.last_save_result.time() else {
+        return;
+    };
+    let elapsed = game.game_time - saved_at;
+    if !(0.0..SAVE_TOAST_TIME).contains(&elapsed) {
+        return;
+    }
+    
+    let alpha = (1.0 - elapsed / SAVE_TOAST_TIME) as f32;
+    let (text, color) = match game.last_save_result {
+        SaveResult::Failed(_) => ("SAVE FAILED", Color::new(1.0, 0.35, 0.35, alpha)),
+        _ => ("SAVED", Color::new(0.5, 1.0, 0.6, alpha)),
+    };
+    
+    // Just under the board's top edge, drifting up as it fades
+    let size = measure_text(text, None, 22, 1.0);
+    let x = BOARD_OFFSET_X + BOARD_WIDTH_PX - size.width - 8.0;
+    let y = BOARD_OFFSET_Y + 24.0 - elapsed as f32 * 8.0;
+    draw_rectangle(x - 6.0, y - size.height - 4.0, size.width + 12.0, size.height + 10.0, Color::new(0.0, 0.0, 0.0, 0.6 * alpha));
+    draw_text(text, x, y, 22.0, color);
+}
+
+/// Draw the LEVEL N banner sliding across the top of the board
+fn draw_level_up_banner(game: &Game) {
+    let progress = game.get_level_up_banner_progress();
+    
+    // Fade in over the first 15%, out over the last 30%
+    let alpha = if progress <= 0.15 {
+        (progress / 0.15) as f32
+    } else if progress <= 0.7 {
+        1.0
+    } else {
+        (1.0 - (progress - 0.7) / 0.3) as f32
+    };
+    
+    let text = format!("LEVEL {}", game.level());
+    let font_size = 48.0;
+    let size = measure_text(&text, None, font_size as u16, 1.0);
+    let x = BOARD_OFFSET_X + (BOARD_WIDTH_PX - size.width) / 2.0;
+    // Drop in from above the banner's resting place
+    let settle = (progress / 0.15).min(1.0) as f32;
+    let y = BOARD_OFFSET_Y + BOARD_HEIGHT_PX * 0.3 - (1.0 - settle) * 30.0;
+    
+    draw_rectangle(BOARD_OFFSET_X, y - size.height - 12.0, BOARD_WIDTH_PX, size.height + 28.0, Color::new(0.0, 0.0, 0.0, 0.5 * alpha));
+    draw_text(&text, x + 3.0, y + 3.0, font_size, Color::new(0.0, 0.0, 0.0, alpha * 0.8));
+    draw_text(&text, x, y, font_size, Color::new(0.4, 1.0, 0.6, alpha));
+}
+
+/// Draw the PERFECT CLEAR celebration message
+fn draw_perfect_clear_celebration(game: &Game) {
+    let progress = game.get_perfect_clear_celebration_progress();
+    
+    // Fade in over the first 15%, out over the last 25%
+    let alpha = if progress <= 0.15 {
+        (progress / 0.15) as f32
+    } else if progress <= 0.75 {
+        1.0
+    } else {
+        (1.0 - (progress - 0.75) / 0.25) as f32
+    };
+    
+    let pulse = (progress * 10.0).sin() as f32 * 0.08 + 1.0;
+    let font_size = 64.0 * pulse;
+    let center_y = WINDOW_HEIGHT as f32 / 2.0 + 40.0;
+    
+    // Cycle the two lines through the piece colors
+    let colors = [TETROMINO_I, TETROMINO_O, TETROMINO_T, TETROMINO_S, TETROMINO_Z, TETROMINO_J, TETROMINO_L];
+    let color_index = (progress * 14.0) as usize;
+    for (line, text) in ["PERFECT", "CLEAR!"].iter().enumerate() {
+        let mut color = colors[(color_index + line * 3) % colors.len()];
+        color.a = alpha;
+        let size = measure_text(text, None, font_size as u16, 1.0);
+        let x = BOARD_OFFSET_X + (BOARD_WIDTH_PX - size.width) / 2.0;
+        let y = center_y + line as f32 * font_size * 0.9;
+        
+        // Dark outline keeps the text readable over the board
+        draw_text(text, x + 3.0, y + 3.0, font_size, Color::new(0.0, 0.0, 0.0, alpha * 0.8));
+        draw_text(text, x, y, font_size, color);
+    }
+}
+
 /// Draw animated TETRIS celebration message with rainbow colors and effects
 fn draw_tetris_celebration(game: &Game) {
     let progress = game.get_tetris_celebration_progress();
@@ -0,0 +1,45 @@
+//! Key bindings listed on the controls screen
+
+/// One key binding and what it does
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ControlBinding {
+    /// Keys that trigger the action, as shown to the player
+    pub keys: &'static str,
+    /// What the keys do
+    pub action: &'static str,
+}
+
+impl ControlBinding {
+    const fn new(keys: &'static str, action: &'static str) -> Self {
+        Self { keys, action }
+    }
+}
+
+/// The bindings the game currently uses, grouped under section headings
+///
+/// Keys are fixed for now, so this is the single place the controls screen reads them from.
+pub fn control_bindings() -> Vec<(&'static str, Vec<ControlBinding>)> {
+    vec![
+        ("GAMEPLAY", vec![
+            ControlBinding::new("← / → or A / D", "Move piece"),
+            ControlBinding::new("↓ or S", "Soft drop"),
+            ControlBinding::new("↑, X or W", "Rotate clockwise"),
+            ControlBinding::new("Z", "Rotate counterclockwise"),
+            ControlBinding::new("SPACE", "Hard drop"),
+            ControlBinding::new("V", "Sonic drop"),
+            ControlBinding::new("C", "Hold piece"),
+            ControlBinding::new("B, M / N", "Ghost block spell"),
+        ]),
+        ("SYSTEM", vec![
+            ControlBinding::new("P", "Pause"),
+            ControlBinding::new("R", "Restart"),
+            ControlBinding::new("T (game over)", "Retry same pieces"),
+            ControlBinding::new("CTRL + S", "Save game"),
+            ControlBinding::new("L", "Toggle legacy mode"),
+            ControlBinding::new("F2", "Export last game summary"),
+            ControlBinding::new("F3", "Frame time overlay"),
+            ControlBinding::new("F4", "Show / hide practice template"),
+            ControlBinding::new("ESC", "Back / quit"),
+        ]),
+    ]
+}
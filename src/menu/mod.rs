@@ -1,13 +1,42 @@
 //! Enhanced start menu system for the Tetris game
 
+pub mod controls;
+
 use macroquad::prelude::*;
 use crate::game::config::*;
-use crate::leaderboard::Leaderboard;
+use crate::audio::system::SoundType;
+use crate::leaderboard::{Leaderboard, LeaderboardEntry};
 use crate::Game;
+use crate::board::board::default_lines_per_level;
+use crate::game::state::{default_ghost_block_every_n_lines, default_ghost_block_max, default_true};
+use crate::game::{format_game_time, Difficulty, GameMode, GameSummary, GhostStyle, GravityCurve, Replay, SaveMeta, TimeFormat, TopOutBehavior, SAVE_SLOT_COUNT};
+use crate::graphics::frame::FrameStyle;
+use crate::graphics::grid::GridStyle;
+use crate::graphics::lock_cue::LockDelayCue;
+use crate::graphics::theme::ColorTheme;
+use crate::input::{HardDropConfirm, HardDropMode};
+use crate::tetromino::{FilteredGenerator, Randomizer, RandomizerKind, TetrominoType};
+use self::controls::control_bindings;
+use crate::graphics::utils::{DEFAULT_PANEL_PIECE_SCALE, MIN_PANEL_PIECE_SCALE, MAX_PANEL_PIECE_SCALE};
 use serde::{Serialize, Deserialize};
 use std::fs;
 use std::path::Path;
 
+/// Number of leaderboard entries shown at once
+pub const LEADERBOARD_PAGE_SIZE: usize = 7;
+
+/// Longest leaderboard name, in characters (not bytes, so accented names get the full length)
+pub const MAX_NAME_CHARS: usize = 20;
+
+/// Main menu rows, in display order
+const MAIN_NEW_GAME: usize = 0;
+const MAIN_DIFFICULTY: usize = 1;
+const MAIN_CONTINUE: usize = 2;
+const MAIN_LEADERBOARD: usize = 3;
+const MAIN_SETTINGS: usize = 4;
+const MAIN_CONTROLS: usize = 5;
+const MAIN_QUIT: usize = 6;
+
 /// Different states the menu system can be in
 #[derive(Debug, Clone, PartialEq)]
 pub enum MenuState {
@@ -17,8 +46,25 @@ pub enum MenuState {
     Leaderboard,
     /// Settings/options menu
     Settings,
-    /// High score name entry screen
-    NameEntry { score: u32, level: u32, lines_cleared: u32, game_time: f64 },
+    /// Save slot selection for continuing a game
+    SaveSlots,
+    /// Key bindings and how to play
+    Controls,
+    /// Every game sound, to preview or check that it loaded
+    SoundTest,
+    /// Which piece types new games deal, for practicing particular pieces
+    PracticePieces,
+    /// High score name entry screen, holding the record waiting for its name
+    NameEntry(LeaderboardEntry),
+}
+
+/// Leaderboard change waiting for the player to confirm it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeaderboardConfirm {
+    /// Delete the entry at this index
+    RemoveEntry(usize),
+    /// Delete every entry
+    ClearAll,
 }
 
 /// Game settings that persist across sessions
@@ -28,6 +74,247 @@ pub struct GameSettings {
     pub sound_enabled: bool,
     /// Master volume (0.0 to 1.0)
     pub volume: f32,
+    /// What happens when the stack tops out mid-animation
+    #[serde(default)]
+    pub topout_behavior: TopOutBehavior,
+    /// Fill the board in before showing the game-over screen
+    #[serde(default)]
+    pub topout_animation: bool,
+    /// Replace particle-heavy effects with minimal flashes, for players they bother
+    #[serde(default)]
+    pub reduce_motion: bool,
+    /// Level new games start at
+    #[serde(default = "crate::game::state::default_start_level")]
+    pub start_level: u32,
+    /// Mode new games are played in
+    #[serde(default)]
+    pub game_mode: GameMode,
+    /// Difficulty preset new games use
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    /// Size of next/hold panel pieces relative to board cells
+    #[serde(default = "default_panel_piece_scale")]
+    pub panel_piece_scale: f32,
+    /// Size of the whole window relative to the base layout, for high-DPI screens
+    #[serde(default = "default_render_scale")]
+    pub render_scale: f32,
+    /// Board grid style; `None` uses the display mode's own grid
+    #[serde(default)]
+    pub grid_style: Option<GridStyle>,
+    /// Whether holding refreshes lock delay
+    #[serde(default = "default_hold_resets_lock")]
+    pub hold_resets_lock: bool,
+    /// Whether held pieces keep their rotation
+    #[serde(default)]
+    pub hold_preserves_rotation: bool,
+    /// Pieces that must spawn between holds (`None` = hold every piece)
+    #[serde(default)]
+    pub hold_cooldown: Option<u32>,
+    /// Seconds new pieces hang before gravity takes them
+    #[serde(default)]
+    pub spawn_grace: f64,
+    /// Whether ghost blocks are awarded at all
+    #[serde(default = "default_true")]
+    pub ghost_blocks_enabled: bool,
+    /// Lines cleared per ghost block awarded
+    #[serde(default = "default_ghost_block_every_n_lines")]
+    pub ghost_block_every_n_lines: u32,
+    /// Most ghost blocks stored at once
+    #[serde(default = "default_ghost_block_max")]
+    pub ghost_block_max: u32,
+    /// Decorative frame drawn around the board
+    #[serde(default)]
+    pub frame_style: FrameStyle,
+    /// Palette pieces are drawn with
+    #[serde(default)]
+    pub color_theme: ColorTheme,
+    /// Draw each piece's letter on its blocks so pieces can be told apart without color
+    #[serde(default)]
+    pub show_piece_glyphs: bool,
+    /// Glow the gaps in rows that are one or two cells from clearing, as a training aid
+    #[serde(default)]
+    pub show_line_hints: bool,
+    /// Randomizer new games deal pieces from
+    #[serde(default)]
+    pub randomizer: RandomizerKind,
+    /// How fast pieces fall at each level, in modes that don't set their own
+    #[serde(default)]
+    pub gravity_curve: GravityCurve,
+    /// Lines cleared per level in new games
+    #[serde(default = "default_lines_per_level")]
+    pub lines_per_level: u32,
+    /// How game time is shown
+    #[serde(default)]
+    pub time_format: TimeFormat,
+    /// Cue drawn while a grounded piece waits to lock
+    #[serde(default)]
+    pub lock_delay_cue: LockDelayCue,
+    /// How the ghost piece is drawn
+    #[serde(default)]
+    pub ghost_style: GhostStyle,
+    /// Keep games from opening with an S or Z piece
+    #[serde(default = "default_first_piece_no_sz")]
+    pub first_piece_no_sz: bool,
+    /// Save slot (1-based) the current game loads from and saves to
+    #[serde(default = "default_save_slot")]
+    pub save_slot: usize,
+    /// Seconds between auto-saves during play (0 turns auto-save off)
+    #[serde(default = "default_auto_save_interval")]
+    pub auto_save_interval: f64,
+    /// Replay the final seconds of a lost game on the game over screen
+    #[serde(default = "default_death_cam")]
+    pub death_cam: bool,
+    /// Leave a fading afterimage along each hard drop
+    #[serde(default = "default_hard_drop_trail")]
+    pub hard_drop_trail: bool,
+    /// What it takes to commit a hard drop
+    #[serde(default)]
+    pub hard_drop_mode: HardDropMode,
+    /// Seconds allowed between the two taps of a double-tap hard drop
+    #[serde(default = "default_hard_drop_window")]
+    pub hard_drop_window: f64,
+    /// Piece types new games deal, in `TetrominoType::all` order (all seven plays normally)
+    #[serde(default = "default_practice_pieces")]
+    pub practice_pieces: Vec<TetrominoType>,
+}
+
+/// Default panel piece scale for settings files that predate it
+fn default_panel_piece_scale() -> f32 {
+    DEFAULT_PANEL_PIECE_SCALE
+}
+
+/// Unscaled window for settings files that predate render scaling
+fn default_render_scale() -> f32 {
+    1.0
+}
+
+/// Hold refreshes lock delay unless a settings file says otherwise
+fn default_hold_resets_lock() -> bool {
+    true
+}
+
+/// First save slot for settings files that predate slots
+fn default_save_slot() -> usize {
+    1
+}
+
+/// Auto-save interval for settings files that predate it
+fn default_auto_save_interval() -> f64 {
+    DEFAULT_AUTO_SAVE_INTERVAL
+}
+
+/// Death-cam is on unless a settings file says otherwise
+fn default_death_cam() -> bool {
+    true
+}
+
+/// Hard drop trail is on unless a settings file says otherwise
+fn default_hard_drop_trail() -> bool {
+    true
+}
+
+/// Double-tap window for settings files that predate it
+fn default_hard_drop_window() -> f64 {
+    HARD_DROP_DOUBLE_TAP_WINDOW
+}
+
+/// Every piece is dealt unless a settings file says otherwise
+fn default_practice_pieces() -> Vec<TetrominoType> {
+    TetrominoType::all().to_vec()
+}
+
+/// Auto-save intervals offered on the settings screen, in seconds (0 = off)
+const AUTO_SAVE_INTERVALS: [f64; 5] = [0.0, 15.0, 30.0, 60.0, 120.0];
+
+/// Hold cooldowns offered on the settings screen, in pieces
+const HOLD_COOLDOWNS: [Option<u32>; 4] = [None, Some(2), Some(3), Some(5)];
+
+/// Double-tap hard drop windows offered on the settings screen, in seconds
+const HARD_DROP_WINDOWS: [f64; 4] = [0.15, 0.25, 0.35, 0.5];
+
+/// Lines per level offered on the settings screen
+const LINES_PER_LEVEL_CHOICES: [u32; 4] = [5, 10, 15, 20];
+
+/// Randomizer for a game dealing `kind` from `seed`, limited to `practice_pieces` unless that
+/// is empty or every piece
+fn deal_randomizer(kind: RandomizerKind, practice_pieces: &[TetrominoType], first_piece_no_sz: bool, seed: u64) -> Randomizer {
+    if practice_pieces.is_empty() || practice_pieces.len() >= TetrominoType::all().len() {
+        kind.create_with(seed, first_piece_no_sz)
+    } else {
+        Randomizer::Filtered(FilteredGenerator::new(seed, practice_pieces.to_vec()))
+    }
+}
+
+/// Spawn grace periods offered on the settings screen, in seconds (0 = off)
+const SPAWN_GRACES: [f64; 4] = [0.0, 0.1, 0.2, 0.3];
+
+/// Ghost block cadences offered on the settings screen, in lines (`None` = ghost blocks off)
+const GHOST_BLOCK_CADENCES: [Option<u32>; 5] = [None, Some(2), Some(4), Some(8), Some(10)];
+
+/// Ghost block caps offered on the settings screen
+const GHOST_BLOCK_MAXES: [u32; 4] = [1, 3, 5, 10];
+
+/// Window scales offered on the settings screen
+const RENDER_SCALES: [f32; 6] = [0.75, 1.0, 1.25, 1.5, 2.0, 2.5];
+
+/// The choice after (or before, going back) `current`, wrapping around the ends
+///
+/// A value that isn't one of the choices, such as one edited into a settings file by hand,
+/// steps from `choices[default_index]` instead.
+fn cycle<T: PartialEq + Copy>(choices: &[T], current: T, default_index: usize, forward: bool) -> T {
+    let index = choices.iter().position(|choice| *choice == current).unwrap_or(default_index);
+    let next = if forward { index + 1 } else { index + choices.len() - 1 };
+    choices[next % choices.len()]
+}
+
+/// Guideline S/Z-free openings unless a settings file says otherwise
+fn default_first_piece_no_sz() -> bool {
+    true
+}
+
+/// Rows on the settings screen, in display order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingsOption {
+    Sound,
+    Volume,
+    TopOut,
+    Mode,
+    Gravity,
+    LinesPerLevel,
+    PanelScale,
+    Grid,
+    HoldLockReset,
+    Frame,
+    Theme,
+    Glyphs,
+    ReduceMotion,
+    LineHints,
+    Randomizer,
+    Clock,
+    NoSzStart,
+    PracticePieces,
+    LockCue,
+    Ghost,
+    AutoSave,
+    HoldRotation,
+    DeathCam,
+    HoldCooldown,
+    SpawnGrace,
+    SoundTest,
+    GhostBlocks,
+    GhostBlockMax,
+    DropTrail,
+    HardDrop,
+    HardDropWindow,
+    TopOutAnimation,
+    DisplayScale,
+}
+
+impl SettingsOption {
+    /// All settings options in display order
+    pub fn all() -> &'static [SettingsOption] {
+        &[SettingsOption::Sound, SettingsOption::Volume, SettingsOption::SoundTest, SettingsOption::TopOut, SettingsOption::TopOutAnimation, SettingsOption::Mode, SettingsOption::Gravity, SettingsOption::LinesPerLevel, SettingsOption::PanelScale, SettingsOption::DisplayScale, SettingsOption::Grid, SettingsOption::Ghost, SettingsOption::LineHints, SettingsOption::HoldLockReset, SettingsOption::HoldRotation, SettingsOption::HoldCooldown, SettingsOption::SpawnGrace, SettingsOption::GhostBlocks, SettingsOption::GhostBlockMax, SettingsOption::LockCue, SettingsOption::DropTrail, SettingsOption::HardDrop, SettingsOption::HardDropWindow, SettingsOption::Frame, SettingsOption::Theme, SettingsOption::Glyphs, SettingsOption::ReduceMotion, SettingsOption::Randomizer, SettingsOption::NoSzStart, SettingsOption::PracticePieces, SettingsOption::Clock, SettingsOption::AutoSave, SettingsOption::DeathCam]
+    }
 }
 
 impl GameSettings {
@@ -36,7 +323,266 @@ impl GameSettings {
         Self {
             sound_enabled: true,
             volume: 0.7,
+            topout_behavior: TopOutBehavior::default(),
+            topout_animation: false,
+            reduce_motion: false,
+            start_level: 1,
+            game_mode: GameMode::default(),
+            difficulty: Difficulty::default(),
+            panel_piece_scale: DEFAULT_PANEL_PIECE_SCALE,
+            render_scale: default_render_scale(),
+            grid_style: None,
+            hold_resets_lock: true,
+            hold_preserves_rotation: false,
+            hold_cooldown: None,
+            spawn_grace: 0.0,
+            ghost_blocks_enabled: true,
+            ghost_block_every_n_lines: GHOST_BLOCK_EVERY_N_LINES,
+            ghost_block_max: GHOST_BLOCK_MAX,
+            frame_style: FrameStyle::default(),
+            color_theme: ColorTheme::default(),
+            show_piece_glyphs: false,
+            show_line_hints: false,
+            randomizer: RandomizerKind::default(),
+            gravity_curve: GravityCurve::default(),
+            lines_per_level: default_lines_per_level(),
+            time_format: TimeFormat::default(),
+            lock_delay_cue: LockDelayCue::default(),
+            ghost_style: GhostStyle::default(),
+            first_piece_no_sz: default_first_piece_no_sz(),
+            save_slot: default_save_slot(),
+            auto_save_interval: default_auto_save_interval(),
+            death_cam: default_death_cam(),
+            hard_drop_trail: default_hard_drop_trail(),
+            hard_drop_mode: HardDropMode::default(),
+            hard_drop_window: default_hard_drop_window(),
+            practice_pieces: default_practice_pieces(),
+        }
+    }
+    
+    /// Display text for a settings row
+    pub fn label(&self, option: SettingsOption) -> String {
+        match option {
+            SettingsOption::Sound => format!("🔊 SOUND: {}", if self.sound_enabled { "ON" } else { "OFF" }),
+            SettingsOption::Volume => format!("🎵 VOLUME: {:.0}%", self.volume * 100.0),
+            SettingsOption::TopOut => format!("💀 TOP OUT: {}", match self.topout_behavior {
+                TopOutBehavior::FinishAnimation => "FINISH ANIMATION",
+                TopOutBehavior::Immediate => "IMMEDIATE",
+            }),
+            SettingsOption::Mode => format!("🎲 MODE: {}", self.game_mode.name()),
+            SettingsOption::Gravity => format!("🪂 GRAVITY: {}", self.gravity_curve.name()),
+            SettingsOption::LinesPerLevel => format!("📶 LINES PER LEVEL: {}", self.lines_per_level),
+            SettingsOption::PanelScale => format!("🔍 PREVIEW SIZE: {:.0}%", self.panel_piece_scale * 100.0),
+            SettingsOption::Grid => format!("▦ GRID: {}", self.grid_style.map_or("AUTO", |style| style.name())),
+            SettingsOption::Ghost => format!("👻 GHOST: {}", self.ghost_style.name()),
+            SettingsOption::HoldLockReset => format!("🔒 HOLD RESETS LOCK: {}", if self.hold_resets_lock { "ON" } else { "OFF" }),
+            SettingsOption::HoldRotation => format!("🔄 HOLD KEEPS ROTATION: {}", if self.hold_preserves_rotation { "ON" } else { "OFF" }),
+            SettingsOption::HoldCooldown => match self.hold_cooldown {
+                Some(pieces) => format!("⏲️ HOLD COOLDOWN: {} PIECES", pieces),
+                None => "⏲️ HOLD COOLDOWN: OFF".to_string(),
+            },
+            SettingsOption::SpawnGrace => if self.spawn_grace > 0.0 {
+                format!("🕊️ SPAWN GRACE: {:.1}s", self.spawn_grace)
+            } else {
+                "🕊️ SPAWN GRACE: OFF".to_string()
+            },
+            SettingsOption::HardDrop => format!("⏬ HARD DROP: {}", self.hard_drop_mode.name()),
+            SettingsOption::HardDropWindow => format!("👆 DOUBLE TAP WINDOW: {:.2}s", self.hard_drop_window),
+            SettingsOption::Frame => format!("🖼️ FRAME: {}", self.frame_style.name()),
+            SettingsOption::Theme => format!("🎨 COLORS: {}", self.color_theme.name()),
+            SettingsOption::Glyphs => format!("🔤 PIECE LETTERS: {}", if self.show_piece_glyphs { "ON" } else { "OFF" }),
+            SettingsOption::ReduceMotion => format!("🌀 REDUCE MOTION: {}", if self.reduce_motion { "ON" } else { "OFF" }),
+            SettingsOption::LineHints => format!("💡 LINE HINTS: {}", if self.show_line_hints { "ON" } else { "OFF" }),
+            SettingsOption::Randomizer => format!("🎰 RANDOMIZER: {}", self.randomizer.name()),
+            SettingsOption::LockCue => format!("⏳ LOCK CUE: {}", self.lock_delay_cue.name()),
+            SettingsOption::NoSzStart => format!("🚫 NO S/Z START: {}", if self.first_piece_no_sz { "ON" } else { "OFF" }),
+            SettingsOption::Clock => format!("⏱️ CLOCK: {}", self.time_format.name()),
+            SettingsOption::AutoSave => if self.auto_save_interval > 0.0 {
+                format!("💾 AUTO-SAVE: {:.0}s", self.auto_save_interval)
+            } else {
+                "💾 AUTO-SAVE: OFF".to_string()
+            },
+            SettingsOption::DeathCam => format!("🎥 DEATH-CAM: {}", if self.death_cam { "ON" } else { "OFF" }),
+            SettingsOption::TopOutAnimation => format!("🧱 TOP-OUT ANIMATION: {}", if self.topout_animation { "ON" } else { "OFF" }),
+            SettingsOption::DisplayScale => format!("🖥 DISPLAY SCALE: {:.0}%", self.render_scale * 100.0),
+            SettingsOption::DropTrail => format!("💨 DROP TRAIL: {}", if self.hard_drop_trail { "ON" } else { "OFF" }),
+            SettingsOption::SoundTest => "🎧 SOUND TEST...".to_string(),
+            SettingsOption::PracticePieces => format!("🧩 PRACTICE PIECES: {}", if self.practice_pieces.len() >= TetrominoType::all().len() {
+                "ALL".to_string()
+            } else {
+                self.practice_pieces.iter().map(|piece| &piece.name()[..1]).collect::<Vec<_>>().join(" ")
+            }),
+            SettingsOption::GhostBlocks => if self.ghost_blocks_enabled {
+                format!("🧱 GHOST BLOCKS: EVERY {} LINES", self.ghost_block_every_n_lines)
+            } else {
+                "🧱 GHOST BLOCKS: OFF".to_string()
+            },
+            SettingsOption::GhostBlockMax => format!("📦 GHOST BLOCK LIMIT: {}", self.ghost_block_max),
+        }
+    }
+    
+    /// Include or leave out a piece type for practice, refusing to leave out the last one
+    ///
+    /// Returns whether the selection changed.
+    pub fn toggle_practice_piece(&mut self, piece: TetrominoType) -> bool {
+        let included = self.practice_pieces.contains(&piece);
+        if included && self.practice_pieces.len() <= 1 {
+            return false;
         }
+        self.practice_pieces = TetrominoType::all()
+            .into_iter()
+            .filter(|&other| if other == piece { !included } else { self.practice_pieces.contains(&other) })
+            .collect();
+        true
+    }
+    
+    /// Change a setting one step forwards or backwards
+    pub fn adjust(&mut self, option: SettingsOption, forward: bool) {
+        match option {
+            SettingsOption::Sound => self.sound_enabled = !self.sound_enabled,
+            SettingsOption::Volume => {
+                let step = if forward { 0.1 } else { -0.1 };
+                self.volume = (self.volume + step).clamp(0.0, 1.0);
+            },
+            SettingsOption::TopOut => {
+                self.topout_behavior = match self.topout_behavior {
+                    TopOutBehavior::FinishAnimation => TopOutBehavior::Immediate,
+                    TopOutBehavior::Immediate => TopOutBehavior::FinishAnimation,
+                };
+            },
+            SettingsOption::Mode => self.game_mode = cycle(&GameMode::all(), self.game_mode, 0, forward),
+            SettingsOption::LinesPerLevel => self.lines_per_level = cycle(&LINES_PER_LEVEL_CHOICES, self.lines_per_level, 1, forward),
+            SettingsOption::Gravity => {
+                let curves = GravityCurve::all();
+                let index = curves.iter().position(|curve| std::mem::discriminant(curve) == std::mem::discriminant(&self.gravity_curve)).unwrap_or(0);
+                let next = if forward { index + 1 } else { index + curves.len() - 1 };
+                self.gravity_curve = curves[next % curves.len()];
+            },
+            SettingsOption::PanelScale => {
+                let step = if forward { 0.1 } else { -0.1 };
+                self.panel_piece_scale = (self.panel_piece_scale + step).clamp(MIN_PANEL_PIECE_SCALE, MAX_PANEL_PIECE_SCALE);
+            },
+            SettingsOption::Grid => {
+                // Cycle AUTO followed by every explicit style
+                let choices: Vec<Option<GridStyle>> = std::iter::once(None)
+                    .chain(GridStyle::all().into_iter().map(Some))
+                    .collect();
+                self.grid_style = cycle(&choices, self.grid_style, 0, forward);
+            },
+            SettingsOption::Ghost => self.ghost_style = cycle(&GhostStyle::all(), self.ghost_style, 0, forward),
+            SettingsOption::HoldLockReset => self.hold_resets_lock = !self.hold_resets_lock,
+            SettingsOption::HoldRotation => self.hold_preserves_rotation = !self.hold_preserves_rotation,
+            SettingsOption::NoSzStart => self.first_piece_no_sz = !self.first_piece_no_sz,
+            SettingsOption::Glyphs => self.show_piece_glyphs = !self.show_piece_glyphs,
+            SettingsOption::ReduceMotion => self.reduce_motion = !self.reduce_motion,
+            SettingsOption::LineHints => self.show_line_hints = !self.show_line_hints,
+            SettingsOption::DeathCam => self.death_cam = !self.death_cam,
+            SettingsOption::DropTrail => self.hard_drop_trail = !self.hard_drop_trail,
+            SettingsOption::TopOutAnimation => self.topout_animation = !self.topout_animation,
+            // Open screens rather than holding a value
+            SettingsOption::SoundTest | SettingsOption::PracticePieces => {},
+            SettingsOption::Frame => self.frame_style = cycle(&FrameStyle::all(), self.frame_style, 0, forward),
+            SettingsOption::Theme => self.color_theme = cycle(&ColorTheme::all(), self.color_theme, 0, forward),
+            SettingsOption::Randomizer => self.randomizer = cycle(&RandomizerKind::all(), self.randomizer, 0, forward),
+            SettingsOption::LockCue => self.lock_delay_cue = cycle(&LockDelayCue::all(), self.lock_delay_cue, 0, forward),
+            SettingsOption::Clock => self.time_format = cycle(&TimeFormat::all(), self.time_format, 0, forward),
+            SettingsOption::DisplayScale => self.render_scale = cycle(&RENDER_SCALES, self.render_scale, 1, forward),
+            SettingsOption::AutoSave => self.auto_save_interval = cycle(&AUTO_SAVE_INTERVALS, self.auto_save_interval, 2, forward),
+            SettingsOption::HoldCooldown => self.hold_cooldown = cycle(&HOLD_COOLDOWNS, self.hold_cooldown, 0, forward),
+            SettingsOption::GhostBlocks => {
+                let current = Some(self.ghost_block_every_n_lines).filter(|_| self.ghost_blocks_enabled);
+                match cycle(&GHOST_BLOCK_CADENCES, current, 2, forward) {
+                    Some(lines) => {
+                        self.ghost_blocks_enabled = true;
+                        self.ghost_block_every_n_lines = lines;
+                    },
+                    None => self.ghost_blocks_enabled = false,
+                }
+            },
+            SettingsOption::GhostBlockMax => self.ghost_block_max = cycle(&GHOST_BLOCK_MAXES, self.ghost_block_max, 2, forward),
+            SettingsOption::HardDrop => self.hard_drop_mode = cycle(&HardDropMode::all(), self.hard_drop_mode, 0, forward),
+            SettingsOption::HardDropWindow => self.hard_drop_window = cycle(&HARD_DROP_WINDOWS, self.hard_drop_window, 1, forward),
+            SettingsOption::SpawnGrace => self.spawn_grace = cycle(&SPAWN_GRACES, self.spawn_grace, 0, forward),
+        }
+    }
+    
+    /// Grid style to draw, falling back to the display mode's own look
+    pub fn effective_grid_style(&self, legacy_mode: bool) -> GridStyle {
+        self.grid_style.unwrap_or_else(|| GridStyle::preset(legacy_mode))
+    }
+    
+    /// Create a new game using the selected starting level and mode
+    pub fn create_game(&self) -> Game {
+        self.create_game_with(self.game_mode, self.difficulty, self.start_level)
+    }
+    
+    /// Create a new game in a given mode, difficulty and starting level, with the rest of
+    /// these settings applied
+    pub fn create_game_with(&self, mode: GameMode, difficulty: Difficulty, start_level: u32) -> Game {
+        self.create_seeded_game(mode, difficulty, start_level, ::rand::random())
+    }
+    
+    /// Create a new game like `create_game_with`, dealing pieces from `seed`
+    pub fn create_seeded_game(&self, mode: GameMode, difficulty: Difficulty, start_level: u32, seed: u64) -> Game {
+        let randomizer = deal_randomizer(self.randomizer, &self.practice_pieces, self.first_piece_no_sz, seed);
+        self.create_dealt_game(mode, difficulty, start_level, randomizer, self.first_piece_no_sz)
+    }
+    
+    /// Create a game that deals a leaderboard record's pieces again, from the randomizer,
+    /// piece filter and starting level it was set with rather than the current ones
+    pub fn create_recorded_game(&self, entry: &LeaderboardEntry) -> Game {
+        let randomizer = deal_randomizer(entry.randomizer, &entry.practice_pieces, entry.first_piece_no_sz, entry.seed);
+        self.create_dealt_game(entry.mode, entry.difficulty, entry.start_level, randomizer, entry.first_piece_no_sz)
+    }
+    
+    /// Set up a game in `mode` dealing from `randomizer`, with the rest of these settings applied
+    fn create_dealt_game(&self, mode: GameMode, difficulty: Difficulty, start_level: u32, randomizer: Randomizer, first_piece_no_sz: bool) -> Game {
+        let mut game = Game::new_with_randomizer(randomizer);
+        game.first_piece_no_sz = first_piece_no_sz;
+        game.start_level = start_level.max(1);
+        game.mode = mode;
+        game.hold_enabled = mode.hold_enabled();
+        game.set_rotation_system(mode.rotation_system());
+        game.mirror_first_piece_for_mode();
+        game.gravity_curve = mode.gravity_curve().unwrap_or(self.gravity_curve);
+        game.board.set_lines_per_level(self.lines_per_level);
+        game.replay = Replay::new(mode);
+        match mode {
+            GameMode::ScoreAttack => game.piece_limit = Some(SCORE_ATTACK_PIECES),
+            GameMode::Cheese { rows } | GameMode::CheeseDrill { rows } => game.add_cheese_rows(rows),
+            GameMode::Sprint => {
+                game.line_goal = Some(SPRINT_LINES);
+                // Race the best run so far, if there is one
+                game.ghost_race = Replay::load_from_file(Replay::best_sprint_path()).ok();
+            },
+            _ => {},
+        }
+        game.set_difficulty(difficulty);
+        self.apply_to_game(&mut game);
+        game
+    }
+    
+    /// Copy gameplay preferences onto a game
+    pub fn apply_to_game(&self, game: &mut Game) {
+        game.topout_behavior = self.topout_behavior;
+        game.topout_animation = self.topout_animation;
+        game.hold_resets_lock = self.hold_resets_lock;
+        game.hold_preserves_rotation = self.hold_preserves_rotation;
+        game.hold_cooldown = self.hold_cooldown;
+        game.spawn_grace = self.spawn_grace;
+        game.ghost_blocks_enabled = self.ghost_blocks_enabled;
+        game.ghost_block_every_n_lines = self.ghost_block_every_n_lines;
+        game.ghost_block_max = self.ghost_block_max;
+        game.ghost_style = self.ghost_style;
+        game.reduce_motion = self.reduce_motion;
+        game.record_snapshots = self.death_cam;
+        game.hard_drop_trail_enabled = self.hard_drop_trail;
+        game.hard_drop_confirm = HardDropConfirm::new(self.hard_drop_mode, self.hard_drop_window);
+    }
+    
+    /// Save file for the active slot
+    pub fn save_path(&self) -> std::path::PathBuf {
+        Game::save_path_for_slot(self.save_slot.clamp(1, SAVE_SLOT_COUNT))
     }
     
     /// Get the default settings file path
@@ -88,8 +634,16 @@ pub struct MenuSystem {
     pub name_input: String,
     /// Leaderboard scroll position
     pub leaderboard_scroll: usize,
+    /// Leaderboard deletion waiting on a yes/no
+    pub leaderboard_confirm: Option<LeaderboardConfirm>,
     /// Animation timer for various effects
     pub animation_timer: f64,
+    /// Summaries of each save slot, refreshed when the slot screen opens
+    pub save_slots: Vec<Option<SaveMeta>>,
+    /// Report of the most recently finished game, offered for export
+    pub last_game_summary: Option<GameSummary>,
+    /// Result of the last summary export, shown in place of the export hint
+    pub summary_export_status: Option<String>,
 }
 
 impl MenuSystem {
@@ -105,10 +659,48 @@ impl MenuSystem {
             selected_option: 0,
             name_input: String::new(),
             leaderboard_scroll: 0,
+            leaderboard_confirm: None,
             animation_timer: 0.0,
+            save_slots: Vec::new(),
+            last_game_summary: None,
+            summary_export_status: None,
         }
     }
     
+    /// Offer a finished game's summary for export
+    pub fn set_last_game_summary(&mut self, summary: GameSummary) {
+        self.last_game_summary = Some(summary);
+        self.summary_export_status = None;
+    }
+    
+    /// Write the last finished game's summary to the working directory
+    fn export_last_summary(&mut self) {
+        let Some(summary) = &self.last_game_summary else {
+            return;
+        };
+        
+        let path = GameSummary::default_path();
+        self.summary_export_status = Some(match summary.save_to_file(&path) {
+            Ok(()) => format!("Summary exported to {}", path.file_name().unwrap_or_default().to_string_lossy()),
+            Err(e) => {
+                log::warn!("Failed to export game summary: {}", e);
+                "Summary export failed".to_string()
+            },
+        });
+    }
+    
+    /// Re-read the summary of every save slot
+    pub fn refresh_save_slots(&mut self) {
+        self.save_slots = (1..=SAVE_SLOT_COUNT)
+            .map(|slot| Game::save_metadata(Game::save_path_for_slot(slot)))
+            .collect();
+    }
+    
+    /// Check if any slot holds a saved game
+    fn any_save_exists() -> bool {
+        (1..=SAVE_SLOT_COUNT).any(|slot| Game::save_file_exists(Game::save_path_for_slot(slot)))
+    }
+    
     /// Update the menu system
     pub fn update(&mut self, delta_time: f64) {
         self.animation_timer += delta_time;
@@ -116,11 +708,20 @@ impl MenuSystem {
     
     /// Handle input for the current menu state
     pub fn handle_input(&mut self) -> MenuAction {
+        // The last game's summary can be exported from the screens shown right after it ends
+        if is_key_pressed(KeyCode::F2) && matches!(self.state, MenuState::Main | MenuState::NameEntry(_)) {
+            self.export_last_summary();
+        }
+        
         match self.state {
             MenuState::Main => self.handle_main_menu_input(),
             MenuState::Leaderboard => self.handle_leaderboard_input(),
             MenuState::Settings => self.handle_settings_input(),
-            MenuState::NameEntry { .. } => self.handle_name_entry_input(),
+            MenuState::SaveSlots => self.handle_save_slots_input(),
+            MenuState::Controls => self.handle_controls_input(),
+            MenuState::SoundTest => self.handle_sound_test_input(),
+            MenuState::PracticePieces => self.handle_practice_pieces_input(),
+            MenuState::NameEntry(_) => self.handle_name_entry_input(),
         }
     }
     
@@ -142,30 +743,37 @@ impl MenuSystem {
             self.selected_option = (self.selected_option + 1) % num_options;
         }
         
+        // Pick the starting level and difficulty for the next game
+        let left = is_key_pressed(KeyCode::Left) || is_key_pressed(KeyCode::A);
+        let right = is_key_pressed(KeyCode::Right) || is_key_pressed(KeyCode::D);
+        let previous_level = self.settings.start_level;
+        let previous_difficulty = self.settings.difficulty;
+        
+        if self.selected_option == MAIN_NEW_GAME {
+            if left {
+                self.settings.start_level = self.settings.start_level.saturating_sub(1).max(1);
+            }
+            if right {
+                self.settings.start_level = (self.settings.start_level + 1).min(MAX_START_LEVEL);
+            }
+        }
+        
+        if self.selected_option == MAIN_DIFFICULTY && (left || right) {
+            let difficulties = Difficulty::all();
+            let index = difficulties.iter().position(|d| *d == self.settings.difficulty).unwrap_or(1);
+            let next = if right { (index + 1).min(difficulties.len() - 1) } else { index.saturating_sub(1) };
+            self.settings.difficulty = difficulties[next];
+        }
+        
+        if self.settings.start_level != previous_level || self.settings.difficulty != previous_difficulty {
+            if let Err(e) = self.settings.save_to_file(GameSettings::default_path()) {
+                log::warn!("Failed to save settings: {}", e);
+            }
+        }
+        
         // Select option
         if is_key_pressed(KeyCode::Enter) || is_key_pressed(KeyCode::Space) {
-            match self.selected_option {
-                0 => MenuAction::NewGame,
-                1 => {
-                    if Game::save_file_exists(&Game::default_save_path()) {
-                        MenuAction::LoadGame
-                    } else {
-                        MenuAction::NewGame
-                    }
-                },
-                2 => {
-                    self.state = MenuState::Leaderboard;
-                    self.leaderboard_scroll = 0;
-                    MenuAction::None
-                },
-                3 => {
-                    self.state = MenuState::Settings;
-                    self.selected_option = 0;
-                    MenuAction::None
-                },
-                4 => MenuAction::Quit,
-                _ => MenuAction::None,
-            }
+            self.select_main_option(self.selected_option)
         } else if is_key_pressed(KeyCode::Escape) {
             MenuAction::Quit
         } else {
@@ -173,125 +781,361 @@ impl MenuSystem {
         }
     }
     
+    /// Act on a main menu row being chosen
+    fn select_main_option(&mut self, option: usize) -> MenuAction {
+        match option {
+            MAIN_NEW_GAME | MAIN_DIFFICULTY => self.start_game_action(),
+            MAIN_CONTINUE => {
+                self.refresh_save_slots();
+                self.state = MenuState::SaveSlots;
+                self.selected_option = self.settings.save_slot.clamp(1, SAVE_SLOT_COUNT) - 1;
+                MenuAction::None
+            },
+            MAIN_LEADERBOARD => {
+                self.state = MenuState::Leaderboard;
+                self.leaderboard_scroll = 0;
+                self.selected_option = 0;
+                self.leaderboard_confirm = None;
+                MenuAction::None
+            },
+            MAIN_SETTINGS => {
+                self.state = MenuState::Settings;
+                self.selected_option = 0;
+                MenuAction::None
+            },
+            MAIN_CONTROLS => {
+                self.state = MenuState::Controls;
+                MenuAction::None
+            },
+            MAIN_QUIT => MenuAction::Quit,
+            _ => MenuAction::None,
+        }
+    }
+    
+    /// Ask for a new game set up the way the menus are currently configured
+    fn start_game_action(&self) -> MenuAction {
+        MenuAction::StartGame {
+            mode: self.settings.game_mode,
+            difficulty: self.settings.difficulty,
+            start_level: self.settings.start_level,
+        }
+    }
+    
+    /// Handle input for the controls screen
+    fn handle_controls_input(&mut self) -> MenuAction {
+        if is_key_pressed(KeyCode::Escape) || is_key_pressed(KeyCode::Enter) {
+            self.close_controls();
+        }
+        
+        MenuAction::None
+    }
+    
+    /// Leave the controls screen for the main menu
+    fn close_controls(&mut self) {
+        self.state = MenuState::Main;
+        self.selected_option = MAIN_CONTROLS;
+    }
+    
     /// Handle input for the leaderboard screen
     fn handle_leaderboard_input(&mut self) -> MenuAction {
+        // A pending deletion takes every key until it is answered
+        if self.leaderboard_confirm.is_some() {
+            if is_key_pressed(KeyCode::Y) || is_key_pressed(KeyCode::Enter) {
+                if self.confirm_leaderboard_change() {
+                    if let Err(e) = self.leaderboard.save_to_file(Leaderboard::default_path()) {
+                        log::warn!("Failed to save leaderboard: {}", e);
+                    }
+                }
+            } else if is_key_pressed(KeyCode::N) || is_key_pressed(KeyCode::Escape) {
+                self.leaderboard_confirm = None;
+            }
+            return MenuAction::None;
+        }
+        
         if is_key_pressed(KeyCode::Escape) || is_key_pressed(KeyCode::Enter) {
             self.state = MenuState::Main;
-            self.selected_option = 2; // Return to leaderboard option
+            self.selected_option = MAIN_LEADERBOARD; // Return to leaderboard option
+            return MenuAction::None;
         }
         
-        // Scroll leaderboard if needed
-        if is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::W) {
-            if self.leaderboard_scroll > 0 {
-                self.leaderboard_scroll -= 1;
+        if is_key_pressed(KeyCode::Delete) || is_key_pressed(KeyCode::Backspace) {
+            if self.selected_option < self.leaderboard.entries.len() {
+                self.leaderboard_confirm = Some(LeaderboardConfirm::RemoveEntry(self.selected_option));
             }
+            return MenuAction::None;
+        }
+        
+        if is_key_pressed(KeyCode::C) && !self.leaderboard.entries.is_empty() {
+            self.leaderboard_confirm = Some(LeaderboardConfirm::ClearAll);
+            return MenuAction::None;
+        }
+        
+        if is_key_pressed(KeyCode::P) {
+            return self.play_leaderboard_seed();
+        }
+        
+        // Move the highlighted row, scrolling to keep it in view
+        if is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::W) {
+            self.move_leaderboard_selection(false);
         }
         
         if is_key_pressed(KeyCode::Down) || is_key_pressed(KeyCode::S) {
-            let max_scroll = self.leaderboard.entries.len().saturating_sub(7); // Show 7 entries at a time
-            if self.leaderboard_scroll < max_scroll {
-                self.leaderboard_scroll += 1;
-            }
+            self.move_leaderboard_selection(true);
+        }
+        
+        // Flip whole pages
+        let page_count = self.leaderboard.page_count(LEADERBOARD_PAGE_SIZE);
+        let current_page = self.leaderboard_page();
+        
+        if is_key_pressed(KeyCode::Left) || is_key_pressed(KeyCode::A) {
+            // Snap back to the start of the current page first if scrolled mid-page
+            let target_page = if self.leaderboard_scroll.is_multiple_of(LEADERBOARD_PAGE_SIZE) {
+                current_page.saturating_sub(1)
+            } else {
+                current_page
+            };
+            self.leaderboard_scroll = target_page * LEADERBOARD_PAGE_SIZE;
+        }
+        
+        if (is_key_pressed(KeyCode::Right) || is_key_pressed(KeyCode::D)) && current_page + 1 < page_count {
+            self.leaderboard_scroll = (current_page + 1) * LEADERBOARD_PAGE_SIZE;
+        }
+        
+        // Paging takes the highlight along with it
+        if self.selected_option < self.leaderboard_scroll || self.selected_option >= self.leaderboard_scroll + LEADERBOARD_PAGE_SIZE {
+            self.selected_option = self.leaderboard_scroll;
         }
         
         MenuAction::None
     }
     
+    /// Zero-based page the leaderboard is scrolled to, counted in whole pages from the top row shown
+    ///
+    /// Paging and the page indicator both use this, so the number shown is the page that
+    /// the arrows flip away from.
+    fn leaderboard_page(&self) -> usize {
+        self.leaderboard_scroll / LEADERBOARD_PAGE_SIZE
+    }
+    
+    /// Move the highlighted leaderboard row by one, scrolling to keep it on screen
+    fn move_leaderboard_selection(&mut self, down: bool) {
+        let count = self.leaderboard.entries.len();
+        if count == 0 {
+            self.selected_option = 0;
+            return;
+        }
+        self.selected_option = if down { (self.selected_option + 1).min(count - 1) } else { self.selected_option.saturating_sub(1) };
+        if self.selected_option < self.leaderboard_scroll {
+            self.leaderboard_scroll = self.selected_option;
+        } else if self.selected_option >= self.leaderboard_scroll + LEADERBOARD_PAGE_SIZE {
+            self.leaderboard_scroll = self.selected_option + 1 - LEADERBOARD_PAGE_SIZE;
+        }
+    }
+    
+    /// Carry out the pending leaderboard deletion, keeping the highlight and scroll in range
+    /// Returns true if the leaderboard changed and should be saved
+    fn confirm_leaderboard_change(&mut self) -> bool {
+        let changed = match self.leaderboard_confirm.take() {
+            Some(LeaderboardConfirm::RemoveEntry(index)) => self.leaderboard.remove_entry(index).is_some(),
+            Some(LeaderboardConfirm::ClearAll) => {
+                self.leaderboard.clear();
+                true
+            },
+            None => false,
+        };
+        
+        let count = self.leaderboard.entries.len();
+        self.selected_option = self.selected_option.min(count.saturating_sub(1));
+        self.leaderboard_scroll = self.leaderboard_scroll.min(count.saturating_sub(LEADERBOARD_PAGE_SIZE));
+        changed
+    }
+    
     /// Handle input for the settings screen
     fn handle_settings_input(&mut self) -> MenuAction {
         if is_key_pressed(KeyCode::Escape) {
             self.state = MenuState::Main;
-            self.selected_option = 3; // Return to settings option
+            self.selected_option = MAIN_SETTINGS; // Return to settings option
             // Save settings when leaving
             if let Err(e) = self.settings.save_to_file(&GameSettings::default_path()) {
                 log::warn!("Failed to save settings: {}", e);
             }
+            return MenuAction::None;
         }
         
+        let options = SettingsOption::all();
+        let num_options = options.len();
+        
         // Navigate settings
         if is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::W) {
-            self.selected_option = if self.selected_option == 0 { 1 } else { 0 };
+            self.selected_option = if self.selected_option == 0 { num_options - 1 } else { self.selected_option - 1 };
         }
         
         if is_key_pressed(KeyCode::Down) || is_key_pressed(KeyCode::S) {
-            self.selected_option = (self.selected_option + 1) % 2;
+            self.selected_option = (self.selected_option + 1) % num_options;
         }
         
-        // Modify settings
-        if is_key_pressed(KeyCode::Enter) || is_key_pressed(KeyCode::Space) {
-            match self.selected_option {
-                0 => {
-                    // Toggle sound
-                    self.settings.sound_enabled = !self.settings.sound_enabled;
-                },
-                1 => {
-                    // This could cycle through volume levels or we could add left/right for fine control
-                    if is_key_down(KeyCode::LeftShift) {
-                        self.settings.volume = (self.settings.volume - 0.1).max(0.0);
-                    } else {
-                        self.settings.volume = (self.settings.volume + 0.1).min(1.0);
-                    }
-                },
-                _ => {},
+        let option = options[self.selected_option.min(num_options - 1)];
+        
+        if option == SettingsOption::SoundTest {
+            if is_key_pressed(KeyCode::Enter) || is_key_pressed(KeyCode::Space) {
+                self.open_sound_test();
             }
+            return MenuAction::None;
         }
         
-        // Volume adjustment with left/right arrows
-        if self.selected_option == 1 {
-            if is_key_pressed(KeyCode::Left) || is_key_pressed(KeyCode::A) {
-                self.settings.volume = (self.settings.volume - 0.1).max(0.0);
+        if option == SettingsOption::PracticePieces {
+            if is_key_pressed(KeyCode::Enter) || is_key_pressed(KeyCode::Space) {
+                self.open_practice_pieces();
             }
-            if is_key_pressed(KeyCode::Right) || is_key_pressed(KeyCode::D) {
-                self.settings.volume = (self.settings.volume + 0.1).min(1.0);
+            return MenuAction::None;
+        }
+        
+        // Modify settings (Shift+Enter steps backwards)
+        if is_key_pressed(KeyCode::Enter) || is_key_pressed(KeyCode::Space) {
+            self.settings.adjust(option, !is_key_down(KeyCode::LeftShift));
+        }
+        
+        // Fine adjustment with left/right arrows
+        if is_key_pressed(KeyCode::Left) || is_key_pressed(KeyCode::A) {
+            self.settings.adjust(option, false);
+        }
+        if is_key_pressed(KeyCode::Right) || is_key_pressed(KeyCode::D) {
+            self.settings.adjust(option, true);
+        }
+        
+        MenuAction::None
+    }
+    
+    /// Handle input for the sound test screen
+    fn handle_sound_test_input(&mut self) -> MenuAction {
+        if is_key_pressed(KeyCode::Escape) {
+            self.close_sound_test();
+            return MenuAction::None;
+        }
+        
+        if is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::W) {
+            self.move_sound_test_selection(false);
+        }
+        if is_key_pressed(KeyCode::Down) || is_key_pressed(KeyCode::S) {
+            self.move_sound_test_selection(true);
+        }
+        
+        if is_key_pressed(KeyCode::Enter) || is_key_pressed(KeyCode::Space) {
+            return self.select_sound_test_entry();
+        }
+        
+        MenuAction::None
+    }
+    
+    /// Open the sound test from the settings screen
+    fn open_sound_test(&mut self) {
+        self.state = MenuState::SoundTest;
+        self.selected_option = 0;
+    }
+    
+    /// Leave the sound test for the settings screen, back on its row
+    fn close_sound_test(&mut self) {
+        self.state = MenuState::Settings;
+        self.selected_option = SettingsOption::all().iter().position(|option| *option == SettingsOption::SoundTest).unwrap_or(0);
+    }
+    
+    /// Move the sound test cursor one row, wrapping at either end
+    fn move_sound_test_selection(&mut self, down: bool) {
+        let count = SoundType::all().len();
+        self.selected_option = if down { (self.selected_option + 1) % count } else { (self.selected_option + count - 1) % count };
+    }
+    
+    /// Play the sound under the sound test cursor
+    fn select_sound_test_entry(&self) -> MenuAction {
+        SoundType::all().get(self.selected_option).map_or(MenuAction::None, |&sound| MenuAction::PlaySound(sound))
+    }
+    
+    /// Handle input for the practice piece screen
+    fn handle_practice_pieces_input(&mut self) -> MenuAction {
+        if is_key_pressed(KeyCode::Escape) {
+            self.close_practice_pieces();
+            return MenuAction::None;
+        }
+        
+        let count = TetrominoType::all().len();
+        if is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::W) {
+            self.selected_option = (self.selected_option + count - 1) % count;
+        }
+        if is_key_pressed(KeyCode::Down) || is_key_pressed(KeyCode::S) {
+            self.selected_option = (self.selected_option + 1) % count;
+        }
+        
+        if is_key_pressed(KeyCode::Enter) || is_key_pressed(KeyCode::Space) {
+            if let Some(&piece) = TetrominoType::all().get(self.selected_option) {
+                self.settings.toggle_practice_piece(piece);
             }
         }
         
         MenuAction::None
     }
     
-    /// Handle input for name entry screen
-    fn handle_name_entry_input(&mut self) -> MenuAction {
-        // Handle character input
-        if let Some(character) = get_char_pressed() {
-            if character.is_ascii_alphanumeric() || character == ' ' {
-                if self.name_input.len() < 20 { // Limit name length
-                    self.name_input.push(character.to_ascii_uppercase());
-                }
+    /// Open the practice piece selector from the settings screen
+    fn open_practice_pieces(&mut self) {
+        self.state = MenuState::PracticePieces;
+        self.selected_option = 0;
+    }
+    
+    /// Leave the practice piece selector for the settings screen, back on its row
+    fn close_practice_pieces(&mut self) {
+        self.state = MenuState::Settings;
+        self.selected_option = SettingsOption::all().iter().position(|option| *option == SettingsOption::PracticePieces).unwrap_or(0);
+    }
+    
+    /// Handle input for the save slot screen
+    fn handle_save_slots_input(&mut self) -> MenuAction {
+        if is_key_pressed(KeyCode::Escape) {
+            self.state = MenuState::Main;
+            self.selected_option = MAIN_CONTINUE;
+            return MenuAction::None;
+        }
+        
+        if is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::W) {
+            self.selected_option = if self.selected_option == 0 { SAVE_SLOT_COUNT - 1 } else { self.selected_option - 1 };
+        }
+        
+        if is_key_pressed(KeyCode::Down) || is_key_pressed(KeyCode::S) {
+            self.selected_option = (self.selected_option + 1) % SAVE_SLOT_COUNT;
+        }
+        
+        if is_key_pressed(KeyCode::Enter) || is_key_pressed(KeyCode::Space) {
+            let slot = self.selected_option.min(SAVE_SLOT_COUNT - 1) + 1;
+            self.settings.save_slot = slot;
+            if let Err(e) = self.settings.save_to_file(GameSettings::default_path()) {
+                log::warn!("Failed to save settings: {}", e);
             }
+            
+            let has_save = self.save_slots.get(slot - 1).is_some_and(|meta| meta.is_some());
+            self.state = MenuState::Main;
+            self.selected_option = MAIN_CONTINUE;
+            
+            // An empty slot starts a fresh game that will save into it
+            return if has_save { MenuAction::LoadGame } else { self.start_game_action() };
         }
         
+        MenuAction::None
+    }
+    
+    /// Handle input for name entry screen
+    fn handle_name_entry_input(&mut self) -> MenuAction {
+        // Drain every character typed this frame so none are left queued for later frames
+        let typed: Vec<char> = std::iter::from_fn(get_char_pressed).collect();
+        push_name_chars(&mut self.name_input, typed);
+        
         // Handle backspace
         if is_key_pressed(KeyCode::Backspace) {
             self.name_input.pop();
         }
         
         // Handle enter (submit name)
-        if is_key_pressed(KeyCode::Enter) {
-            if let MenuState::NameEntry { score, level, lines_cleared, game_time } = self.state {
-                let name = if self.name_input.is_empty() {
-                    "ANONYMOUS".to_string()
-                } else {
-                    self.name_input.clone()
-                };
-                
-                // Add to leaderboard
-                let entry = crate::leaderboard::LeaderboardEntry::new(
-                    name, score, level, lines_cleared, game_time
-                );
-                
-                if let Some(position) = self.leaderboard.add_entry(entry) {
-                    log::info!("New high score! Position: {}", position);
-                }
-                
-                // Save leaderboard
-                if let Err(e) = self.leaderboard.save_to_file(&Leaderboard::default_path()) {
-                    log::warn!("Failed to save leaderboard: {}", e);
-                }
-                
-                // Return to main menu
-                self.state = MenuState::Main;
-                self.selected_option = 0;
-                self.name_input.clear();
+        if is_key_pressed(KeyCode::Enter) && self.submit_name_entry() {
+            // Save leaderboard
+            if let Err(e) = self.leaderboard.save_to_file(Leaderboard::default_path()) {
+                log::warn!("Failed to save leaderboard: {}", e);
             }
         }
         
@@ -306,28 +1150,78 @@ impl MenuSystem {
     }
     
     /// Get the main menu options based on current state
-    fn get_main_menu_options(&self) -> Vec<&str> {
-        let mut options = vec!["🎮 NEW GAME"];
-        
-        if Game::save_file_exists(&Game::default_save_path()) {
-            options.push("💾 CONTINUE");
+    fn get_main_menu_options(&self) -> Vec<String> {
+        let mut options = vec![
+            format!("🎮 NEW GAME  ◀ LEVEL {} ▶", self.settings.start_level),
+            format!("🎚️ DIFFICULTY  ◀ {} ▶", self.settings.difficulty.name()),
+        ];
+        
+        if Self::any_save_exists() {
+            options.push("💾 CONTINUE".to_string());
         } else {
-            options.push("💾 CONTINUE (No Save)");
+            options.push("💾 CONTINUE (No Save)".to_string());
         }
         
-        options.extend_from_slice(&[
+        options.extend([
             "🏆 LEADERBOARD",
             "⚙️  SETTINGS",
+            "⌨️  CONTROLS",
             "❌ QUIT",
-        ]);
+        ].iter().map(|option| option.to_string()));
         
         options
     }
     
-    /// Check if a score qualifies for high score entry
-    pub fn check_high_score(&mut self, score: u32, level: u32, lines_cleared: u32, game_time: f64) -> bool {
-        if self.leaderboard.qualifies_for_leaderboard(score) {
-            self.state = MenuState::NameEntry { score, level, lines_cleared, game_time };
+    /// Add the typed name to the leaderboard with the pending score and return to the main menu
+    ///
+    /// Returns false (and changes nothing) outside name entry.
+    fn submit_name_entry(&mut self) -> bool {
+        let MenuState::NameEntry(pending) = &self.state else {
+            return false;
+        };
+        let mut entry = pending.clone();
+        let trimmed = self.name_input.trim_end();
+        entry.name = if trimmed.is_empty() {
+            "ANONYMOUS".to_string()
+        } else {
+            trimmed.to_string()
+        };
+        
+        // Add to leaderboard
+        
+        if let Some(position) = self.leaderboard.add_entry(entry) {
+            log::info!("New high score! Position: {}", position);
+        }
+        
+        // Return to main menu
+        self.state = MenuState::Main;
+        self.selected_option = 0;
+        self.name_input.clear();
+        true
+    }
+    
+    /// Start a game dealing the highlighted leaderboard entry's pieces again
+    fn play_leaderboard_seed(&self) -> MenuAction {
+        match self.leaderboard.entries.get(self.selected_option) {
+            Some(entry) if entry.has_seed() => MenuAction::StartRecordedGame(entry.clone()),
+            _ => MenuAction::None,
+        }
+    }
+    
+    /// Check if a finished game's score qualifies for high score entry
+    ///
+    /// Practice games that deal only some piece types aren't comparable with real games and never qualify.
+    pub fn check_high_score(&mut self, game: &Game) -> bool {
+        if matches!(game.randomizer, Randomizer::Filtered(_)) {
+            log::info!("Practice game with a piece filter - not recorded on the leaderboard");
+            return false;
+        }
+        if self.leaderboard.qualifies_for_leaderboard(game.score) {
+            let entry = LeaderboardEntry::new(String::new(), game.score, game.level(), game.lines_cleared(), game.game_time)
+                .with_difficulty(game.difficulty)
+                .with_seed(game.initial_seed, game.mode)
+                .with_deal(game.randomizer.kind().unwrap_or_default(), Vec::new(), game.first_piece_no_sz, game.start_level);
+            self.state = MenuState::NameEntry(entry);
             self.name_input.clear();
             true
         } else {
@@ -341,8 +1235,12 @@ impl MenuSystem {
             MenuState::Main => self.render_main_menu(background_texture),
             MenuState::Leaderboard => self.render_leaderboard(background_texture),
             MenuState::Settings => self.render_settings(background_texture),
-            MenuState::NameEntry { score, level, lines_cleared, game_time } => {
-                self.render_name_entry(background_texture, score, level, lines_cleared, game_time)
+            MenuState::SaveSlots => self.render_save_slots(background_texture),
+            MenuState::Controls => self.render_controls(background_texture),
+            MenuState::SoundTest => self.render_sound_test(background_texture),
+            MenuState::PracticePieces => self.render_practice_pieces(background_texture),
+            MenuState::NameEntry(ref entry) => {
+                self.render_name_entry(background_texture, entry.score, entry.level, entry.lines_cleared, entry.game_time)
             },
         }
     }
@@ -370,7 +1268,7 @@ impl MenuSystem {
         let options = self.get_main_menu_options();
         let option_size = 28.0;
         let option_y_start = 320.0;
-        let option_spacing = 55.0;
+        let option_spacing = 50.0;
         
         for (i, option) in options.iter().enumerate() {
             let is_selected = i == self.selected_option;
@@ -396,17 +1294,19 @@ impl MenuSystem {
                 Color::new(1.0, 1.0, 0.8, pulse as f32)
             } else {
                 match i {
-                    0 => Color::new(0.4, 1.0, 0.4, 0.9), // Green for new game
-                    1 => {
-                        if Game::save_file_exists(&Game::default_save_path()) {
+                    MAIN_NEW_GAME => Color::new(0.4, 1.0, 0.4, 0.9), // Green for new game
+                    MAIN_DIFFICULTY => Color::new(0.4, 0.9, 0.7, 0.9), // Teal for difficulty
+                    MAIN_CONTINUE => {
+                        if Self::any_save_exists() {
                             Color::new(0.4, 0.8, 1.0, 0.9) // Blue for continue
                         } else {
                             Color::new(0.6, 0.6, 0.6, 0.6) // Gray for no save
                         }
                     },
-                    2 => Color::new(1.0, 0.8, 0.2, 0.9), // Gold for leaderboard
-                    3 => Color::new(0.8, 0.4, 1.0, 0.9), // Purple for settings
-                    4 => Color::new(1.0, 0.4, 0.4, 0.9), // Red for quit
+                    MAIN_LEADERBOARD => Color::new(1.0, 0.8, 0.2, 0.9), // Gold for leaderboard
+                    MAIN_SETTINGS => Color::new(0.8, 0.4, 1.0, 0.9), // Purple for settings
+                    MAIN_CONTROLS => Color::new(0.4, 1.0, 1.0, 0.9), // Cyan for controls
+                    MAIN_QUIT => Color::new(1.0, 0.4, 0.4, 0.9), // Red for quit
                     _ => Color::new(0.8, 0.8, 0.8, 0.9),
                 }
             };
@@ -415,8 +1315,24 @@ impl MenuSystem {
             self.draw_text_with_outline(option, option_x, option_y, option_size, color);
         }
         
+        self.draw_summary_export_hint();
+        
         // Draw animated particles
-        self.draw_menu_particles();
+        if !self.settings.reduce_motion {
+            self.draw_menu_particles();
+        }
+    }
+    
+    /// Draw the summary export hint, or the outcome of the last export
+    fn draw_summary_export_hint(&self) {
+        if self.last_game_summary.is_none() {
+            return;
+        }
+        
+        let hint = self.summary_export_status.as_deref().unwrap_or("Press F2 to export a summary of your last game");
+        let hint_width = measure_text(hint, None, 20, 1.0).width;
+        let hint_x = (WINDOW_WIDTH as f32 - hint_width) / 2.0;
+        self.draw_text_with_outline(hint, hint_x, WINDOW_HEIGHT as f32 - 40.0, 20.0, Color::new(0.6, 0.8, 1.0, 0.8));
     }
     
     /// Render the leaderboard screen
@@ -466,6 +1382,8 @@ impl MenuSystem {
             let level_x = base_x + 320.0;
             let lines_x = base_x + 380.0;
             let time_x = base_x + 450.0;
+            let difficulty_x = base_x + 540.0;
+            let seed_x = base_x + 680.0;
             
             // Draw column headers
             self.draw_text_with_outline("RANK", rank_x, header_y, 18.0, Color::new(0.6, 0.8, 1.0, 1.0));
@@ -474,9 +1392,11 @@ impl MenuSystem {
             self.draw_text_with_outline("LVL", level_x, header_y, 18.0, Color::new(0.6, 0.8, 1.0, 1.0));
             self.draw_text_with_outline("LINES", lines_x, header_y, 18.0, Color::new(0.6, 0.8, 1.0, 1.0));
             self.draw_text_with_outline("TIME", time_x, header_y, 18.0, Color::new(0.6, 0.8, 1.0, 1.0));
+            self.draw_text_with_outline("DIFFICULTY", difficulty_x, header_y, 18.0, Color::new(0.6, 0.8, 1.0, 1.0));
+            self.draw_text_with_outline("SEED", seed_x, header_y, 18.0, Color::new(0.6, 0.8, 1.0, 1.0));
             
             // Draw entries (with scrolling)
-            let visible_entries = 7;
+            let visible_entries = LEADERBOARD_PAGE_SIZE;
             let start_idx = self.leaderboard_scroll;
             let end_idx = (start_idx + visible_entries).min(self.leaderboard.entries.len());
             
@@ -486,6 +1406,11 @@ impl MenuSystem {
                 
                 let entry_y = entry_y_start + (display_idx as f32 * entry_spacing);
                 
+                if entry_idx == self.selected_option {
+                    let pulse = (self.animation_timer * 3.0).sin() * 0.3 + 0.7;
+                    draw_rectangle(rank_x - 15.0, entry_y - entry_size - 5.0, WINDOW_WIDTH as f32 - 2.0 * (rank_x - 15.0), entry_size + 12.0, Color::new(0.2, 0.4, 1.0, 0.3 * pulse as f32));
+                }
+                
                 // Color based on rank
                 let color = match rank {
                     1 => Color::new(1.0, 0.85, 0.0, 1.0), // Gold
@@ -500,7 +1425,9 @@ impl MenuSystem {
                 self.draw_text_with_outline(&entry.score.to_string(), score_x, entry_y, entry_size, color);
                 self.draw_text_with_outline(&entry.level.to_string(), level_x, entry_y, entry_size, color);
                 self.draw_text_with_outline(&entry.lines_cleared.to_string(), lines_x, entry_y, entry_size, color);
-                self.draw_text_with_outline(&entry.formatted_time(), time_x, entry_y, entry_size, color);
+                self.draw_text_with_outline(&format_game_time(entry.game_time, self.settings.time_format), time_x, entry_y, entry_size, color);
+                self.draw_text_with_outline(entry.difficulty.name(), difficulty_x, entry_y, entry_size * 0.8, color);
+                self.draw_text_with_outline(&entry.seed_code(), seed_x, entry_y, entry_size * 0.8, color);
             }
             
             // Draw scroll indicators if needed
@@ -513,15 +1440,32 @@ impl MenuSystem {
                 let down_arrow = "▼ More below";
                 self.draw_text_with_outline(down_arrow, 80.0, entry_y_start + (visible_entries as f32 * entry_spacing) + 20.0, 16.0, Color::new(0.8, 0.8, 0.8, 0.7));
             }
+            
+            // Draw page indicator
+            let page_count = self.leaderboard.page_count(LEADERBOARD_PAGE_SIZE);
+            if page_count > 1 {
+                let page_text = format!("Page {}/{}  (◀ ▶ to flip)", self.leaderboard_page() + 1, page_count);
+                let page_width = measure_text(&page_text, None, 18, 1.0).width;
+                let page_x = (WINDOW_WIDTH as f32 - page_width) / 2.0;
+                let page_y = entry_y_start + (visible_entries as f32 * entry_spacing) + 45.0;
+                self.draw_text_with_outline(&page_text, page_x, page_y, 18.0, Color::new(0.6, 0.8, 1.0, 0.9));
+            }
         }
         
-        // Draw instructions
-        let instruction = "Press ESCAPE or ENTER to return to main menu";
-        let inst_width = measure_text(instruction, None, 20, 1.0).width;
+        // Draw instructions, or the question a pending deletion is waiting on
+        let (instruction, color) = match self.leaderboard_confirm {
+            Some(LeaderboardConfirm::RemoveEntry(index)) => {
+                let name = self.leaderboard.entries.get(index).map_or("", |entry| entry.name.as_str());
+                (format!("Delete #{} {}? Y to confirm • N to cancel", index + 1, name), Color::new(1.0, 0.5, 0.4, 1.0))
+            },
+            Some(LeaderboardConfirm::ClearAll) => ("Clear ALL high scores? Y to confirm • N to cancel".to_string(), Color::new(1.0, 0.5, 0.4, 1.0)),
+            None => ("P to play the seed • DELETE to remove • C to clear all • ESCAPE or ENTER to return".to_string(), Color::new(0.7, 0.7, 0.7, 0.8)),
+        };
+        let inst_width = measure_text(&instruction, None, 20, 1.0).width;
         let inst_x = (WINDOW_WIDTH as f32 - inst_width) / 2.0;
         let inst_y = WINDOW_HEIGHT as f32 - 50.0;
         
-        self.draw_text_with_outline(instruction, inst_x, inst_y, 20.0, Color::new(0.7, 0.7, 0.7, 0.8));
+        self.draw_text_with_outline(&instruction, inst_x, inst_y, 20.0, color);
     }
     
     /// Render the settings screen
@@ -548,73 +1492,53 @@ impl MenuSystem {
         
         self.draw_text_with_outline(title, title_x, title_y, title_size, Color::new(0.8, 0.4, 1.0, 1.0));
         
-        // Draw settings options
-        let option_size = 32.0;
-        let option_y_start = 280.0;
-        let option_spacing = 80.0;
-        
-        // Sound setting
-        let sound_text = format!("🔊 SOUND: {}", if self.settings.sound_enabled { "ON" } else { "OFF" });
-        let sound_x = (WINDOW_WIDTH as f32 - measure_text(&sound_text, None, option_size as u16, 1.0).width) / 2.0;
-        let sound_y = option_y_start;
-        let sound_selected = self.selected_option == 0;
-        
-        if sound_selected {
-            let pulse = (self.animation_timer * 3.0).sin() * 0.3 + 0.7;
-            draw_rectangle(
-                sound_x - 20.0,
-                sound_y - option_size - 5.0,
-                measure_text(&sound_text, None, option_size as u16, 1.0).width + 40.0,
-                option_size + 10.0,
-                Color::new(0.2, 0.4, 1.0, 0.3 * pulse as f32),
-            );
-        }
-        
-        let sound_color = if sound_selected {
-            let pulse = (self.animation_timer * 4.0).sin() * 0.2 + 0.8;
-            Color::new(1.0, 1.0, 0.8, pulse as f32)
-        } else {
-            if self.settings.sound_enabled {
-                Color::new(0.4, 1.0, 0.4, 0.9)
-            } else {
-                Color::new(1.0, 0.4, 0.4, 0.9)
+        // Draw settings options, shrinking rows to fit as more options are added
+        let options = SettingsOption::all();
+        let option_y_start = 250.0;
+        let available_height = WINDOW_HEIGHT as f32 - 150.0 - option_y_start;
+        let option_spacing = (available_height / options.len() as f32).min(80.0);
+        let option_size = (option_spacing * 0.45).clamp(20.0, 32.0);
+        
+        for (index, option) in options.iter().enumerate() {
+            let text = self.settings.label(*option);
+            let text_width = measure_text(&text, None, option_size as u16, 1.0).width;
+            let x = (WINDOW_WIDTH as f32 - text_width) / 2.0;
+            let y = option_y_start + index as f32 * option_spacing;
+            let selected = self.selected_option == index;
+            
+            if selected {
+                let pulse = (self.animation_timer * 3.0).sin() * 0.3 + 0.7;
+                draw_rectangle(
+                    x - 20.0,
+                    y - option_size - 5.0,
+                    text_width + 40.0,
+                    option_size + 10.0,
+                    Color::new(0.2, 0.4, 1.0, 0.3 * pulse as f32),
+                );
             }
-        };
-        
-        self.draw_text_with_outline(&sound_text, sound_x, sound_y, option_size, sound_color);
-        
-        // Volume setting
-        let volume_text = format!("🎵 VOLUME: {:.0}%", self.settings.volume * 100.0);
-        let volume_x = (WINDOW_WIDTH as f32 - measure_text(&volume_text, None, option_size as u16, 1.0).width) / 2.0;
-        let volume_y = option_y_start + option_spacing;
-        let volume_selected = self.selected_option == 1;
-        
-        if volume_selected {
-            let pulse = (self.animation_timer * 3.0).sin() * 0.3 + 0.7;
-            draw_rectangle(
-                volume_x - 20.0,
-                volume_y - option_size - 5.0,
-                measure_text(&volume_text, None, option_size as u16, 1.0).width + 40.0,
-                option_size + 10.0,
-                Color::new(0.2, 0.4, 1.0, 0.3 * pulse as f32),
-            );
+            
+            let color = if selected {
+                let pulse = (self.animation_timer * 4.0).sin() * 0.2 + 0.8;
+                Color::new(1.0, 1.0, 0.8, pulse as f32)
+            } else if *option == SettingsOption::Sound {
+                if self.settings.sound_enabled {
+                    Color::new(0.4, 1.0, 0.4, 0.9)
+                } else {
+                    Color::new(1.0, 0.4, 0.4, 0.9)
+                }
+            } else {
+                Color::new(0.4, 0.8, 1.0, 0.9)
+            };
+            
+            self.draw_text_with_outline(&text, x, y, option_size, color);
         }
         
-        let volume_color = if volume_selected {
-            let pulse = (self.animation_timer * 4.0).sin() * 0.2 + 0.8;
-            Color::new(1.0, 1.0, 0.8, pulse as f32)
-        } else {
-            Color::new(0.4, 0.8, 1.0, 0.9)
-        };
-        
-        self.draw_text_with_outline(&volume_text, volume_x, volume_y, option_size, volume_color);
-        
-        // Draw volume bar
-        if volume_selected {
+        // Draw volume bar below the list when volume is selected
+        let bar_y = WINDOW_HEIGHT as f32 - 130.0;
+        if options.get(self.selected_option) == Some(&SettingsOption::Volume) {
             let bar_width = 300.0;
             let bar_height = 10.0;
             let bar_x = (WINDOW_WIDTH as f32 - bar_width) / 2.0;
-            let bar_y = volume_y + 30.0;
             
             // Background bar
             draw_rectangle(bar_x, bar_y, bar_width, bar_height, Color::new(0.3, 0.3, 0.3, 0.8));
@@ -622,16 +1546,16 @@ impl MenuSystem {
             // Volume fill
             let fill_width = bar_width * self.settings.volume;
             draw_rectangle(bar_x, bar_y, fill_width, bar_height, Color::new(0.4, 0.8, 1.0, 0.9));
-            
-            // Instructions
-            let instruction = "Use LEFT/RIGHT arrows to adjust volume";
-            let inst_width = measure_text(instruction, None, 18, 1.0).width;
-            let inst_x = (WINDOW_WIDTH as f32 - inst_width) / 2.0;
-            let inst_y = bar_y + 40.0;
-            
-            self.draw_text_with_outline(instruction, inst_x, inst_y, 18.0, Color::new(0.7, 0.7, 0.7, 0.8));
         }
         
+        // Instructions
+        let instruction = "Use LEFT/RIGHT arrows or ENTER to change the selected setting";
+        let inst_width = measure_text(instruction, None, 18, 1.0).width;
+        let inst_x = (WINDOW_WIDTH as f32 - inst_width) / 2.0;
+        let inst_y = bar_y + 40.0;
+        
+        self.draw_text_with_outline(instruction, inst_x, inst_y, 18.0, Color::new(0.7, 0.7, 0.7, 0.8));
+        
         // Draw general instructions
         let instruction = "Press ESCAPE to return to main menu";
         let inst_width = measure_text(instruction, None, 20, 1.0).width;
@@ -641,6 +1565,187 @@ impl MenuSystem {
         self.draw_text_with_outline(instruction, inst_x, inst_y, 20.0, Color::new(0.7, 0.7, 0.7, 0.8));
     }
     
+    /// Render the save slot selection screen
+    fn render_save_slots(&self, background_texture: &Texture2D) {
+        clear_background(Color::new(0.02, 0.02, 0.08, 1.0));
+        draw_texture(background_texture, 0.0, 0.0, WHITE);
+        draw_rectangle(0.0, 0.0, WINDOW_WIDTH as f32, WINDOW_HEIGHT as f32, Color::new(0.0, 0.0, 0.0, 0.6));
+        
+        let title = "💾 CONTINUE 💾";
+        let title_size = 48.0;
+        let title_width = measure_text(title, None, title_size as u16, 1.0).width;
+        self.draw_text_with_outline(title, (WINDOW_WIDTH as f32 - title_width) / 2.0, 150.0, title_size, Color::new(0.4, 0.8, 1.0, 1.0));
+        
+        let option_size = 26.0;
+        let option_y_start = 280.0;
+        let option_spacing = 90.0;
+        
+        for index in 0..SAVE_SLOT_COUNT {
+            let meta = self.save_slots.get(index).and_then(|meta| meta.as_ref());
+            let heading = format!("SLOT {}{}", index + 1, if self.settings.save_slot == index + 1 { "  (ACTIVE)" } else { "" });
+            let details = match meta {
+                Some(meta) => {
+                    let saved = meta.saved_at
+                        .map(|time| time.format("%Y-%m-%d %H:%M").to_string())
+                        .unwrap_or_else(|| "unknown time".to_string());
+                    format!("Score: {}  •  Level: {}  •  Lines: {}  •  {}", meta.score, meta.level, meta.lines_cleared, saved)
+                },
+                None => "Empty - start a new game here".to_string(),
+            };
+            
+            let y = option_y_start + index as f32 * option_spacing;
+            let selected = self.selected_option == index;
+            let heading_width = measure_text(&heading, None, option_size as u16, 1.0).width;
+            let details_width = measure_text(&details, None, 20, 1.0).width;
+            let box_width = heading_width.max(details_width);
+            let x = (WINDOW_WIDTH as f32 - box_width) / 2.0;
+            
+            if selected {
+                let pulse = (self.animation_timer * 3.0).sin() * 0.3 + 0.7;
+                draw_rectangle(
+                    x - 20.0,
+                    y - option_size - 5.0,
+                    box_width + 40.0,
+                    option_size + 40.0,
+                    Color::new(0.2, 0.4, 1.0, 0.3 * pulse as f32),
+                );
+            }
+            
+            let color = if selected {
+                let pulse = (self.animation_timer * 4.0).sin() * 0.2 + 0.8;
+                Color::new(1.0, 1.0, 0.8, pulse as f32)
+            } else if meta.is_some() {
+                Color::new(0.4, 0.8, 1.0, 0.9)
+            } else {
+                Color::new(0.6, 0.6, 0.6, 0.6)
+            };
+            
+            self.draw_text_with_outline(&heading, (WINDOW_WIDTH as f32 - heading_width) / 2.0, y, option_size, color);
+            self.draw_text_with_outline(&details, (WINDOW_WIDTH as f32 - details_width) / 2.0, y + 28.0, 20.0, Color::new(0.8, 0.8, 0.8, 0.9));
+        }
+        
+        let instruction = "ENTER to play the selected slot  •  ESCAPE to return to main menu";
+        let inst_width = measure_text(instruction, None, 20, 1.0).width;
+        self.draw_text_with_outline(instruction, (WINDOW_WIDTH as f32 - inst_width) / 2.0, WINDOW_HEIGHT as f32 - 50.0, 20.0, Color::new(0.7, 0.7, 0.7, 0.8));
+    }
+    
+    /// Render the controls screen, one column per section of bindings
+    fn render_controls(&self, background_texture: &Texture2D) {
+        clear_background(Color::new(0.02, 0.02, 0.08, 1.0));
+        draw_texture(background_texture, 0.0, 0.0, WHITE);
+        draw_rectangle(0.0, 0.0, WINDOW_WIDTH as f32, WINDOW_HEIGHT as f32, Color::new(0.0, 0.0, 0.0, 0.6));
+        
+        let title = "⌨️ CONTROLS ⌨️";
+        let title_size = 48.0;
+        let title_width = measure_text(title, None, title_size as u16, 1.0).width;
+        self.draw_text_with_outline(title, (WINDOW_WIDTH as f32 - title_width) / 2.0, 120.0, title_size, Color::new(0.4, 1.0, 1.0, 1.0));
+        
+        let sections = control_bindings();
+        let column_width = WINDOW_WIDTH as f32 / sections.len().max(1) as f32;
+        let row_spacing = 40.0;
+        
+        for (column, (heading, bindings)) in sections.iter().enumerate() {
+            let x = column as f32 * column_width + 40.0;
+            self.draw_text_with_outline(heading, x, 200.0, 28.0, Color::new(1.0, 0.8, 0.2, 1.0));
+            
+            for (row, binding) in bindings.iter().enumerate() {
+                let y = 250.0 + row as f32 * row_spacing;
+                self.draw_text_with_outline(binding.keys, x, y, 20.0, Color::new(1.0, 1.0, 0.8, 0.95));
+                self.draw_text_with_outline(binding.action, x, y + 18.0, 18.0, Color::new(0.7, 0.7, 0.8, 0.9));
+            }
+        }
+        
+        let instruction = "ESCAPE to return to main menu";
+        let inst_width = measure_text(instruction, None, 20, 1.0).width;
+        self.draw_text_with_outline(instruction, (WINDOW_WIDTH as f32 - inst_width) / 2.0, WINDOW_HEIGHT as f32 - 50.0, 20.0, Color::new(0.7, 0.7, 0.7, 0.8));
+    }
+    
+    /// Render the sound test screen, two columns of sounds
+    fn render_sound_test(&self, background_texture: &Texture2D) {
+        clear_background(Color::new(0.02, 0.02, 0.08, 1.0));
+        draw_texture(background_texture, 0.0, 0.0, WHITE);
+        draw_rectangle(0.0, 0.0, WINDOW_WIDTH as f32, WINDOW_HEIGHT as f32, Color::new(0.0, 0.0, 0.0, 0.6));
+        
+        let title = "🎧 SOUND TEST 🎧";
+        let title_size = 48.0;
+        let title_width = measure_text(title, None, title_size as u16, 1.0).width;
+        self.draw_text_with_outline(title, (WINDOW_WIDTH as f32 - title_width) / 2.0, 120.0, title_size, Color::new(0.4, 1.0, 0.6, 1.0));
+        
+        let sounds = SoundType::all();
+        let rows_per_column = sounds.len().div_ceil(2);
+        let column_width = WINDOW_WIDTH as f32 / 2.0;
+        let option_size = 24.0;
+        let row_spacing = 44.0;
+        
+        for (index, sound) in sounds.iter().enumerate() {
+            let text = sound.name();
+            let text_width = measure_text(text, None, option_size as u16, 1.0).width;
+            let x = (index / rows_per_column) as f32 * column_width + (column_width - text_width) / 2.0;
+            let y = 210.0 + (index % rows_per_column) as f32 * row_spacing;
+            let selected = self.selected_option == index;
+            
+            if selected {
+                let pulse = (self.animation_timer * 3.0).sin() * 0.3 + 0.7;
+                draw_rectangle(x - 20.0, y - option_size - 5.0, text_width + 40.0, option_size + 10.0, Color::new(0.2, 0.4, 1.0, 0.3 * pulse as f32));
+            }
+            
+            let color = if selected {
+                let pulse = (self.animation_timer * 4.0).sin() * 0.2 + 0.8;
+                Color::new(1.0, 1.0, 0.8, pulse as f32)
+            } else {
+                Color::new(0.4, 0.8, 1.0, 0.9)
+            };
+            self.draw_text_with_outline(text, x, y, option_size, color);
+        }
+        
+        let instruction = "ENTER to play the selected sound  •  ESCAPE to return to settings";
+        let inst_width = measure_text(instruction, None, 20, 1.0).width;
+        self.draw_text_with_outline(instruction, (WINDOW_WIDTH as f32 - inst_width) / 2.0, WINDOW_HEIGHT as f32 - 50.0, 20.0, Color::new(0.7, 0.7, 0.7, 0.8));
+    }
+    
+    /// Render the practice piece selector, one row per piece type
+    fn render_practice_pieces(&self, background_texture: &Texture2D) {
+        clear_background(Color::new(0.02, 0.02, 0.08, 1.0));
+        draw_texture(background_texture, 0.0, 0.0, WHITE);
+        draw_rectangle(0.0, 0.0, WINDOW_WIDTH as f32, WINDOW_HEIGHT as f32, Color::new(0.0, 0.0, 0.0, 0.6));
+        
+        let title = "🧩 PRACTICE PIECES 🧩";
+        let title_size = 48.0;
+        let title_width = measure_text(title, None, title_size as u16, 1.0).width;
+        self.draw_text_with_outline(title, (WINDOW_WIDTH as f32 - title_width) / 2.0, 120.0, title_size, Color::new(0.4, 1.0, 0.6, 1.0));
+        
+        let option_size = 28.0;
+        let row_spacing = 50.0;
+        
+        for (index, piece) in TetrominoType::all().iter().enumerate() {
+            let included = self.settings.practice_pieces.contains(piece);
+            let text = format!("[{}] {}", if included { "X" } else { " " }, piece.name().to_uppercase());
+            let text_width = measure_text(&text, None, option_size as u16, 1.0).width;
+            let x = (WINDOW_WIDTH as f32 - text_width) / 2.0;
+            let y = 220.0 + index as f32 * row_spacing;
+            let selected = self.selected_option == index;
+            
+            if selected {
+                let pulse = (self.animation_timer * 3.0).sin() * 0.3 + 0.7;
+                draw_rectangle(x - 20.0, y - option_size - 5.0, text_width + 40.0, option_size + 10.0, Color::new(0.2, 0.4, 1.0, 0.3 * pulse as f32));
+            }
+            
+            let color = if selected {
+                let pulse = (self.animation_timer * 4.0).sin() * 0.2 + 0.8;
+                Color::new(1.0, 1.0, 0.8, pulse as f32)
+            } else if included {
+                piece.color()
+            } else {
+                Color::new(0.5, 0.5, 0.5, 0.8)
+            };
+            self.draw_text_with_outline(&text, x, y, option_size, color);
+        }
+        
+        let instruction = "ENTER to include or leave out a piece (at least one stays)  •  ESCAPE to return to settings";
+        let inst_width = measure_text(instruction, None, 20, 1.0).width;
+        self.draw_text_with_outline(instruction, (WINDOW_WIDTH as f32 - inst_width) / 2.0, WINDOW_HEIGHT as f32 - 50.0, 20.0, Color::new(0.7, 0.7, 0.7, 0.8));
+    }
+    
     /// Render the name entry screen
     fn render_name_entry(&self, background_texture: &Texture2D, score: u32, level: u32, lines_cleared: u32, game_time: f64) {
         // Clear screen and draw background
@@ -675,15 +1780,12 @@ impl MenuSystem {
         }
         
         // Draw score details
-        let minutes = (game_time / 60.0) as u32;
-        let seconds = (game_time % 60.0) as u32;
         let details = format!(
-            "Score: {}  •  Level: {}  •  Lines: {}  •  Time: {}:{:02}",
+            "Score: {}  •  Level: {}  •  Lines: {}  •  Time: {}",
             score,
             level,
             lines_cleared,
-            minutes,
-            seconds
+            format_game_time(game_time, self.settings.time_format)
         );
         
         let details_size = 24.0;
@@ -771,6 +1873,8 @@ impl MenuSystem {
             
             self.draw_text_with_outline(&rank_text, rank_x, rank_y, 22.0, Color::new(1.0, 0.9, 0.3, 1.0));
         }
+        
+        self.draw_summary_export_hint();
     }
     
     /// Draw animated title for main menu
@@ -912,10 +2016,18 @@ impl MenuSystem {
 pub enum MenuAction {
     /// Do nothing
     None,
-    /// Start a new game
-    NewGame,
+    /// Start a new game with the chosen setup
+    StartGame {
+        mode: GameMode,
+        difficulty: Difficulty,
+        start_level: u32,
+    },
+    /// Start a new game dealing a recorded run's pieces again
+    StartRecordedGame(LeaderboardEntry),
     /// Load saved game
     LoadGame,
+    /// Play a sound picked on the sound test screen
+    PlaySound(SoundType),
     /// Quit the application
     Quit,
 }
@@ -924,4 +2036,313 @@ impl Default for MenuSystem {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
+
+/// Whether a character may appear in a leaderboard name: letters (any script), digits,
+/// spaces and `-`, `_`, `.`
+pub fn is_name_char(character: char) -> bool {
+    character.is_alphanumeric() || matches!(character, ' ' | '-' | '_' | '.')
+}
+
+/// Add one frame's typed characters to a name, uppercased and within `MAX_NAME_CHARS`
+///
+/// A key reported more than once in the same frame is a repeat artifact, not a second
+/// keystroke, so back-to-back duplicates within `typed` are only entered once.
+fn push_name_chars(name: &mut String, typed: impl IntoIterator<Item = char>) {
+    let mut previous = None;
+    for character in typed {
+        if previous == Some(character) {
+            continue;
+        }
+        previous = Some(character);
+        
+        if !is_name_char(character) {
+            continue;
+        }
+        let upper: String = character.to_uppercase().collect();
+        if name.chars().count() + upper.chars().count() <= MAX_NAME_CHARS {
+            name.push_str(&upper);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_controls_screen_opens_from_main_menu_and_closes_back() {
+        let mut menu = MenuSystem::new();
+        assert_eq!(menu.get_main_menu_options().len(), MAIN_QUIT + 1);
+        assert!(menu.get_main_menu_options()[MAIN_CONTROLS].contains("CONTROLS"));
+        
+        assert_eq!(menu.select_main_option(MAIN_CONTROLS), MenuAction::None);
+        assert_eq!(menu.state, MenuState::Controls);
+        
+        // Escape lands back on the Controls row
+        menu.close_controls();
+        assert_eq!(menu.state, MenuState::Main);
+        assert_eq!(menu.selected_option, MAIN_CONTROLS);
+        
+        // Every binding has something to show
+        assert!(control_bindings().iter().flat_map(|(_, bindings)| bindings).all(|binding| !binding.keys.is_empty() && !binding.action.is_empty()));
+    }
+    
+    #[test]
+    fn test_grid_style_setting_round_trips() {
+        let path = std::env::temp_dir().join(format!("tetris_grid_settings_{}.json", std::process::id()));
+        
+        // Defaults to the full grid in the modern display
+        let settings = GameSettings::default();
+        assert_eq!(settings.effective_grid_style(false), GridStyle::Lines);
+        
+        let mut settings = settings;
+        settings.grid_style = Some(GridStyle::Minimal);
+        settings.save_to_file(&path).unwrap();
+        let loaded = GameSettings::load_from_file(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(loaded.grid_style, Some(GridStyle::Minimal));
+        
+        // Settings files from before the grid option fall back to the full grid
+        let mut json: serde_json::Value = serde_json::to_value(&settings).unwrap();
+        json.as_object_mut().unwrap().remove("grid_style");
+        let old: GameSettings = serde_json::from_value(json).unwrap();
+        assert_eq!(old.effective_grid_style(false), GridStyle::Lines);
+    }
+    
+    #[test]
+    fn test_new_game_carries_the_chosen_setup() {
+        let mut menu = MenuSystem::new();
+        menu.settings.game_mode = GameMode::Sprint;
+        menu.settings.difficulty = Difficulty::all()[0];
+        menu.settings.start_level = 5;
+        
+        let expected = MenuAction::StartGame { mode: GameMode::Sprint, difficulty: Difficulty::all()[0], start_level: 5 };
+        assert_eq!(menu.select_main_option(MAIN_NEW_GAME), expected);
+        assert_eq!(menu.select_main_option(MAIN_DIFFICULTY), expected);
+        
+        let MenuAction::StartGame { mode, difficulty, start_level } = expected else { unreachable!() };
+        let game = menu.settings.create_game_with(mode, difficulty, start_level);
+        assert_eq!(game.mode, GameMode::Sprint);
+        assert_eq!(game.difficulty, difficulty);
+        assert_eq!(game.start_level, 5);
+        assert_eq!(game.line_goal, Some(SPRINT_LINES));
+    }
+
+    #[test]
+    fn test_name_characters() {
+        for allowed in ['A', 'z', '7', ' ', '-', '_', '.', 'é', 'Ж', 'ß'] {
+            assert!(is_name_char(allowed), "{:?} should be allowed", allowed);
+        }
+        for rejected in ['\n', '\t', '\u{8}', '\u{7f}', '\0', '!', '/', '🎮'] {
+            assert!(!is_name_char(rejected), "{:?} should be rejected", rejected);
+        }
+        
+        // Same-frame duplicates collapse, but a letter typed again next frame is kept
+        let mut name = String::new();
+        push_name_chars(&mut name, ['a', 'a', 'a', '\n', 'é']);
+        push_name_chars(&mut name, ['é']);
+        assert_eq!(name, "AÉÉ");
+        
+        // The limit counts characters, not bytes
+        push_name_chars(&mut name, "ЖабвгдежзийклмнопрстуфхцЧ".chars());
+        assert_eq!(name.chars().count(), MAX_NAME_CHARS);
+    }
+
+    #[test]
+    fn test_sound_test_wraps_and_plays_the_selected_sound() {
+        let mut menu = MenuSystem::new();
+        menu.open_sound_test();
+        assert_eq!(menu.state, MenuState::SoundTest);
+        assert_eq!(menu.select_sound_test_entry(), MenuAction::PlaySound(SoundType::UiClick));
+        
+        // Up from the first sound wraps to the last, and down wraps back
+        let last = SoundType::all().len() - 1;
+        menu.move_sound_test_selection(false);
+        assert_eq!(menu.selected_option, last);
+        assert_eq!(menu.select_sound_test_entry(), MenuAction::PlaySound(SoundType::BackgroundMusic));
+        menu.move_sound_test_selection(true);
+        assert_eq!(menu.selected_option, 0);
+        
+        menu.move_sound_test_selection(true);
+        menu.move_sound_test_selection(true);
+        assert_eq!(menu.select_sound_test_entry(), MenuAction::PlaySound(SoundType::all()[2]));
+        
+        // Leaving lands back on the settings row that opened it
+        menu.close_sound_test();
+        assert_eq!(menu.state, MenuState::Settings);
+        assert_eq!(SettingsOption::all()[menu.selected_option], SettingsOption::SoundTest);
+    }
+
+    #[test]
+    fn test_leaderboard_selection_scrolls_and_deletions_wait_for_confirmation() {
+        let mut menu = MenuSystem::new();
+        menu.leaderboard = Leaderboard::new();
+        for i in 0..10u32 {
+            menu.leaderboard.entries.push(crate::leaderboard::LeaderboardEntry::new(format!("Player{}", i), 1000 - i * 10, 1, 1, 60.0));
+        }
+        menu.select_main_option(MAIN_LEADERBOARD);
+        
+        // Moving past the bottom of the page scrolls it
+        for _ in 0..LEADERBOARD_PAGE_SIZE {
+            menu.move_leaderboard_selection(true);
+        }
+        assert_eq!(menu.selected_option, LEADERBOARD_PAGE_SIZE);
+        assert_eq!(menu.leaderboard_scroll, 1);
+        // Scrolled mid-page still counts as the page the top row is on
+        assert_eq!(menu.leaderboard_page(), 0);
+        menu.leaderboard_scroll = LEADERBOARD_PAGE_SIZE;
+        assert_eq!(menu.leaderboard_page() + 1, menu.leaderboard.page_count(LEADERBOARD_PAGE_SIZE));
+        menu.leaderboard_scroll = 1;
+        
+        menu.leaderboard_confirm = Some(LeaderboardConfirm::RemoveEntry(menu.selected_option));
+        assert!(menu.confirm_leaderboard_change());
+        assert_eq!(menu.leaderboard.entries.len(), 9);
+        assert!(menu.leaderboard.entries.iter().all(|entry| entry.name != format!("Player{}", LEADERBOARD_PAGE_SIZE)));
+        assert_eq!(menu.leaderboard_confirm, None);
+        
+        // Nothing pending changes nothing
+        assert!(!menu.confirm_leaderboard_change());
+        
+        menu.leaderboard_confirm = Some(LeaderboardConfirm::ClearAll);
+        assert!(menu.confirm_leaderboard_change());
+        assert!(menu.leaderboard.entries.is_empty());
+        assert_eq!((menu.selected_option, menu.leaderboard_scroll), (0, 0));
+    }
+
+    #[test]
+    fn test_practice_pieces_keep_at_least_one_and_filter_new_games() {
+        let mut settings = GameSettings::default();
+        assert_eq!(settings.label(SettingsOption::PracticePieces), "🧩 PRACTICE PIECES: ALL");
+        
+        for piece in TetrominoType::all() {
+            if piece != TetrominoType::T {
+                assert!(settings.toggle_practice_piece(piece));
+            }
+        }
+        assert_eq!(settings.practice_pieces, vec![TetrominoType::T]);
+        assert!(!settings.toggle_practice_piece(TetrominoType::T));
+        assert_eq!(settings.label(SettingsOption::PracticePieces), "🧩 PRACTICE PIECES: T");
+        
+        let mut menu = MenuSystem::new();
+        menu.settings = settings;
+        let mut game = menu.settings.create_game();
+        assert!(matches!(game.randomizer, Randomizer::Filtered(_)));
+        assert!((0..20).all(|_| game.randomizer.next_piece() == TetrominoType::T));
+        
+        menu.settings.toggle_practice_piece(TetrominoType::S);
+        assert_eq!(menu.settings.practice_pieces, vec![TetrominoType::T, TetrominoType::S]);
+        
+        // Practice runs stay off the leaderboard, however well they score
+        menu.leaderboard = Leaderboard::new();
+        game.score = 999_999;
+        assert!(!menu.check_high_score(&game));
+        assert_eq!(menu.state, MenuState::Main);
+    }
+
+    #[test]
+    fn test_high_score_entry_records_the_games_seed_and_mode() {
+        let mut menu = MenuSystem::new();
+        menu.leaderboard = Leaderboard::new();
+        let mut game = menu.settings.create_seeded_game(GameMode::Sprint, Difficulty::default(), 1, 4242);
+        game.score = 1234;
+        assert_eq!(game.initial_seed, 4242);
+        
+        assert!(menu.check_high_score(&game));
+        menu.name_input = "SEEDY".to_string();
+        assert!(menu.submit_name_entry());
+        assert_eq!(menu.state, MenuState::Main);
+        assert!(!menu.submit_name_entry());
+        
+        let entry = &menu.leaderboard.entries[0];
+        assert_eq!((entry.name.as_str(), entry.score, entry.seed, entry.mode), ("SEEDY", 1234, 4242, GameMode::Sprint));
+        
+        // Playing the entry's seed deals the same pieces again
+        menu.selected_option = 0;
+        let MenuAction::StartRecordedGame(record) = menu.play_leaderboard_seed() else {
+            panic!("expected a seeded game");
+        };
+        let mut replayed = menu.settings.create_recorded_game(&record);
+        let mut original = menu.settings.create_seeded_game(GameMode::Sprint, Difficulty::default(), 1, 4242);
+        assert_eq!(replayed.mode, GameMode::Sprint);
+        for _ in 0..14 {
+            assert_eq!(replayed.randomizer.next_piece(), original.randomizer.next_piece());
+        }
+    }
+
+    #[test]
+    fn test_recorded_game_deals_with_the_records_settings_not_the_current_ones() {
+        let mut menu = MenuSystem::new();
+        menu.leaderboard = Leaderboard::new();
+        menu.settings.randomizer = RandomizerKind::History;
+        menu.settings.first_piece_no_sz = true;
+        let mut game = menu.settings.create_seeded_game(GameMode::Marathon, Difficulty::default(), 5, 777);
+        game.score = 500;
+        assert!(menu.check_high_score(&game));
+        assert!(menu.submit_name_entry());
+        let record = menu.leaderboard.entries[0].clone();
+        assert_eq!((record.randomizer, record.first_piece_no_sz, record.start_level), (RandomizerKind::History, true, 5));
+        
+        // The player has since changed their settings
+        menu.settings.randomizer = RandomizerKind::SevenBag;
+        menu.settings.first_piece_no_sz = false;
+        menu.settings.start_level = 1;
+        
+        let mut replayed = menu.settings.create_recorded_game(&record);
+        let mut original = {
+            let mut settings = menu.settings.clone();
+            settings.randomizer = RandomizerKind::History;
+            settings.first_piece_no_sz = true;
+            settings.create_seeded_game(GameMode::Marathon, Difficulty::default(), 5, 777)
+        };
+        assert_eq!(replayed.start_level, 5);
+        assert_eq!(replayed.current_piece.as_ref().map(|piece| piece.piece_type), original.current_piece.as_ref().map(|piece| piece.piece_type));
+        for _ in 0..20 {
+            assert_eq!(replayed.randomizer.next_piece(), original.randomizer.next_piece());
+        }
+    }
+
+    #[test]
+    fn test_gravity_setting_reaches_new_games_unless_the_mode_sets_its_own() {
+        let mut settings = GameSettings::default();
+        assert_eq!(settings.gravity_curve, GravityCurve::Table);
+        settings.adjust(SettingsOption::Gravity, true);
+        assert_eq!(settings.gravity_curve, GravityCurve::Guideline);
+        settings.adjust(SettingsOption::Gravity, false);
+        settings.adjust(SettingsOption::Gravity, false);
+        assert_eq!(settings.gravity_curve, GravityCurve::linear());
+        
+        let marathon = settings.create_game_with(GameMode::Marathon, Difficulty::default(), 1);
+        assert_eq!(marathon.gravity_curve, GravityCurve::linear());
+        let classic = settings.create_game_with(GameMode::Classic, Difficulty::default(), 1);
+        assert_eq!(classic.gravity_curve, GravityCurve::Nes);
+        assert_ne!(marathon.target_drop_interval, classic.target_drop_interval);
+    }
+
+    #[test]
+    fn test_lines_per_level_setting_reaches_new_games() {
+        let mut settings = GameSettings::default();
+        assert_eq!(settings.lines_per_level, LINES_PER_LEVEL);
+        settings.adjust(SettingsOption::LinesPerLevel, false);
+        assert_eq!(settings.lines_per_level, 5);
+        
+        let mut game = settings.create_game_with(GameMode::Marathon, Difficulty::default(), 3);
+        let bottom = BOARD_HEIGHT + BUFFER_HEIGHT - 1;
+        game.board.clear_lines(&[bottom]);
+        assert_eq!(game.lines_until_next_level(), Some(4));
+        game.board.clear_lines(&[bottom, bottom - 1, bottom - 2, bottom - 3]);
+        assert_eq!(game.level(), 4);
+    }
+
+    #[test]
+    fn test_cycle_wraps_and_steps_from_the_default_for_unknown_values() {
+        let choices = [5, 10, 15, 20];
+        assert_eq!(cycle(&choices, 10, 0, true), 15);
+        assert_eq!(cycle(&choices, 20, 0, true), 5);
+        assert_eq!(cycle(&choices, 5, 0, false), 20);
+
+        // 12 isn't offered, so it steps from choices[1]
+        assert_eq!(cycle(&choices, 12, 1, true), 15);
+        assert_eq!(cycle(&choices, 12, 1, false), 5);
+    }
+}
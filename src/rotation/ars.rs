@@ -0,0 +1,184 @@
+//! Arika Rotation System (ARS) implementation
+//!
+//! The classic arcade rotation rules: pieces spawn pointing down, resting on the floor of
+//! their 3x3 box, and a blocked rotation only tries one cell right, then one cell left.
+//! The I-piece never kicks and there are no T-spins.
+
+use crate::tetromino::{Tetromino, TetrominoType};
+use crate::board::Board;
+use super::kick_tables::KickOffset;
+use super::kind::RotationSystemKind;
+use super::srs::{RotationResult, RotationState, RotationSystem};
+use super::srs::RotationState::{Right, Spawn, Two};
+use serde::{Serialize, Deserialize};
+
+/// Kicks tried after the basic rotation, clockwise-biased (right before left)
+const ARS_KICKS: [KickOffset; 2] = [(1, 0), (-1, 0)];
+
+/// Get ARS block offsets for a piece type and rotation, relative to the rotation center
+///
+/// `Spawn` is rotation 0. The 3x3 pieces keep their lowest row on the bottom of
+/// the box in states 0 and 2, so they rotate around a lower pivot than under SRS.
+pub fn get_ars_blocks(piece_type: TetrominoType, rotation: RotationState) -> Vec<(i32, i32)> {
+    match piece_type {
+        TetrominoType::I => match rotation {
+            Spawn | Two => vec![(-1, 0), (0, 0), (1, 0), (2, 0)],
+            _ => vec![(1, -1), (1, 0), (1, 1), (1, 2)],
+        },
+        TetrominoType::O => vec![(0, 0), (1, 0), (0, 1), (1, 1)],
+        TetrominoType::T => match rotation {
+            Spawn => vec![(-1, 0), (0, 0), (1, 0), (0, 1)],  // Pointing down
+            Right => vec![(0, -1), (-1, 0), (0, 0), (0, 1)], // Pointing left
+            Two => vec![(0, 0), (-1, 1), (0, 1), (1, 1)],  // Pointing up
+            _ => vec![(0, -1), (0, 0), (1, 0), (0, 1)],  // Pointing right
+        },
+        TetrominoType::J => match rotation {
+            Spawn => vec![(-1, 0), (0, 0), (1, 0), (1, 1)],
+            Right => vec![(0, -1), (0, 0), (-1, 1), (0, 1)],
+            Two => vec![(-1, 0), (-1, 1), (0, 1), (1, 1)],
+            _ => vec![(0, -1), (1, -1), (0, 0), (0, 1)],
+        },
+        TetrominoType::L => match rotation {
+            Spawn => vec![(-1, 0), (0, 0), (1, 0), (-1, 1)],
+            Right => vec![(-1, -1), (0, -1), (0, 0), (0, 1)],
+            Two => vec![(1, 0), (-1, 1), (0, 1), (1, 1)],
+            _ => vec![(0, -1), (0, 0), (0, 1), (1, 1)],
+        },
+        TetrominoType::S => match rotation {
+            Spawn | Two => vec![(0, 0), (1, 0), (-1, 1), (0, 1)],
+            _ => vec![(-1, -1), (-1, 0), (0, 0), (0, 1)],
+        },
+        TetrominoType::Z => match rotation {
+            Spawn | Two => vec![(-1, 0), (0, 0), (0, 1), (1, 1)],
+            _ => vec![(1, -1), (0, 0), (1, 0), (0, 1)],
+        },
+    }
+}
+
+/// Arika/Sega-style rotation system
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ArsRotationSystem;
+
+impl ArsRotationSystem {
+    /// Create a new ARS rotation system
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Rotate to a target state, trying the basic rotation and then the kicks
+    fn try_rotation(&self, piece: &Tetromino, board: &Board, target_rotation: RotationState) -> RotationResult {
+        let mut rotated = piece.clone();
+        rotated.rotation = target_rotation;
+        rotated.update_blocks();
+
+        if Self::is_position_valid(&rotated, board) {
+            return RotationResult::Success { new_piece: rotated };
+        }
+
+        if piece.piece_type == TetrominoType::I {
+            return RotationResult::Failed;
+        }
+
+        for kick in ARS_KICKS {
+            let mut kicked = rotated.clone();
+            kicked.move_by(kick.0, kick.1);
+            if Self::is_position_valid(&kicked, board) {
+                return RotationResult::SuccessWithKick { new_piece: kicked, kick_used: kick };
+            }
+        }
+
+        RotationResult::Failed
+    }
+
+    /// Check if a piece position is valid on the board
+    fn is_position_valid(piece: &Tetromino, board: &Board) -> bool {
+        piece.absolute_blocks().into_iter().all(|(x, y)| board.is_position_valid(x, y))
+    }
+}
+
+impl RotationSystem for ArsRotationSystem {
+    fn rotate_clockwise(&self, piece: &Tetromino, board: &Board) -> RotationResult {
+        self.try_rotation(piece, board, piece.rotation.clockwise())
+    }
+
+    fn rotate_counterclockwise(&self, piece: &Tetromino, board: &Board) -> RotationResult {
+        self.try_rotation(piece, board, piece.rotation.counterclockwise())
+    }
+
+    fn is_t_spin_position(&self, _piece: &Tetromino, _board: &Board, _kick_used: Option<KickOffset>) -> bool {
+        // The arcade rules never award T-spins
+        false
+    }
+
+    fn kind(&self) -> RotationSystemKind {
+        RotationSystemKind::Ars
+    }
+
+    fn clone_box(&self) -> Box<dyn RotationSystem> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rotation::SRSRotationSystem;
+
+    #[test]
+    fn test_t_spawns_pointing_down_on_box_floor() {
+        let piece = ArsRotationSystem::new().spawn_piece(TetrominoType::T);
+        assert_eq!(piece.rotation_system, RotationSystemKind::Ars);
+
+        // Flat three-wide row on top, stem underneath, and nothing above the pivot row
+        let mut blocks = piece.blocks.clone();
+        blocks.sort();
+        assert_eq!(blocks, vec![(-1, 0), (0, 0), (0, 1), (1, 0)]);
+        assert!(blocks.iter().all(|&(_, dy)| dy >= 0));
+
+        // SRS spawns the same piece pointing up
+        let srs_piece = SRSRotationSystem::new().spawn_piece(TetrominoType::T);
+        assert!(srs_piece.blocks.contains(&(0, -1)));
+    }
+
+    #[test]
+    fn test_t_rotates_around_a_different_pivot_than_srs() {
+        let board = Board::new();
+        let ars = ArsRotationSystem::new();
+        let srs = SRSRotationSystem::new();
+
+        // A half turn brings the ARS T to pointing up, which is how SRS spawns it
+        let mut ars_up = ars.spawn_piece(TetrominoType::T);
+        for _ in 0..2 {
+            ars_up = match ars.rotate_clockwise(&ars_up, &board) {
+                RotationResult::Success { new_piece } => new_piece,
+                other => panic!("open-field rotation failed: {:?}", other),
+            };
+        }
+        let srs_up = srs.spawn_piece(TetrominoType::T);
+        assert_eq!(ars_up.position, srs_up.position);
+
+        // Same shape around the same center, but ARS keeps it one row lower in the box
+        let mut shifted: Vec<(i32, i32)> = srs_up.absolute_blocks().into_iter().map(|(x, y)| (x, y + 1)).collect();
+        let mut ars_blocks = ars_up.absolute_blocks();
+        shifted.sort();
+        ars_blocks.sort();
+        assert_eq!(ars_blocks, shifted);
+    }
+
+    #[test]
+    fn test_kicks_right_before_left() {
+        let board = Board::new();
+        let ars = ArsRotationSystem::new();
+
+        // A T pointing right against the left wall can only turn by kicking right
+        let mut piece = ars.spawn_piece(TetrominoType::T);
+        piece.rotation = RotationState::Left;
+        piece.update_blocks();
+        piece.position = (0, 10);
+
+        match ars.rotate_clockwise(&piece, &board) {
+            RotationResult::SuccessWithKick { kick_used, .. } => assert_eq!(kick_used, (1, 0)),
+            other => panic!("expected a right kick, got {:?}", other),
+        }
+    }
+}
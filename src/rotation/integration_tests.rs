@@ -16,8 +16,8 @@ mod tests {
         
         // Add walls on left and right sides for testing wall kicks
         for y in 15..24 {  // Bottom portion of visible area
-            board.set_cell(0, y, Cell::Filled(Color::GRAY));
-            board.set_cell(9, y, Cell::Filled(Color::GRAY));
+            board.set_cell(0, y, Cell::block(Color::GRAY));
+            board.set_cell(9, y, Cell::block(Color::GRAY));
         }
         
         board
@@ -29,7 +29,7 @@ mod tests {
         
         // Add floor at the bottom
         for x in 0..10 {
-            board.set_cell(x, 23, Cell::Filled(Color::GRAY));
+            board.set_cell(x, 23, Cell::block(Color::GRAY));
         }
         
         board
@@ -48,7 +48,7 @@ mod tests {
         assert!(matches!(result, RotationResult::Success { .. }));
         
         if let RotationResult::Success { new_piece } = result {
-            assert_eq!(new_piece.rotation, 1);
+            assert_eq!(new_piece.rotation, RotationState::Right);
             assert_eq!(new_piece.position, (5, 10)); // Position unchanged
         }
     }
@@ -61,7 +61,7 @@ mod tests {
         // Position I-piece against left wall
         let mut piece = Tetromino::new(TetrominoType::I);
         piece.position = (1, 18); // Near left wall
-        piece.rotation = 1; // Vertical
+        piece.rotation = RotationState::Right; // Vertical
         
         // Try rotating to horizontal - should kick right
         let result = srs.rotate_clockwise(&piece, &board);
@@ -69,11 +69,11 @@ mod tests {
         match result {
             RotationResult::Success { new_piece } => {
                 // Basic rotation worked without kick
-                assert_eq!(new_piece.rotation, 2);
+                assert_eq!(new_piece.rotation, RotationState::Two);
             },
             RotationResult::SuccessWithKick { new_piece, kick_used } => {
                 // Wall kick was used
-                assert_eq!(new_piece.rotation, 2);
+                assert_eq!(new_piece.rotation, RotationState::Two);
                 println!("Kick used: {:?}", kick_used);
             },
             RotationResult::Failed => {
@@ -90,7 +90,7 @@ mod tests {
         // Position I-piece against right wall
         let mut piece = Tetromino::new(TetrominoType::I);
         piece.position = (8, 18); // Near right wall
-        piece.rotation = 1; // Vertical
+        piece.rotation = RotationState::Right; // Vertical
         
         // Try rotating to horizontal - should kick left
         let result = srs.rotate_clockwise(&piece, &board);
@@ -98,11 +98,11 @@ mod tests {
         match result {
             RotationResult::Success { new_piece } => {
                 // Basic rotation worked without kick
-                assert_eq!(new_piece.rotation, 2);
+                assert_eq!(new_piece.rotation, RotationState::Two);
             },
             RotationResult::SuccessWithKick { new_piece, kick_used } => {
                 // Wall kick was used
-                assert_eq!(new_piece.rotation, 2);
+                assert_eq!(new_piece.rotation, RotationState::Two);
                 println!("Kick used: {:?}", kick_used);
             },
             RotationResult::Failed => {
@@ -118,13 +118,13 @@ mod tests {
         
         let mut piece = Tetromino::new(TetrominoType::T);
         piece.position = (5, 10);
-        piece.rotation = 1; // Start at 90 degrees
+        piece.rotation = RotationState::Right; // Start at 90 degrees
         
         let result = srs.rotate_counterclockwise(&piece, &board);
         assert!(matches!(result, RotationResult::Success { .. }));
         
         if let RotationResult::Success { new_piece } = result {
-            assert_eq!(new_piece.rotation, 0); // Back to 0 degrees
+            assert_eq!(new_piece.rotation, RotationState::Spawn); // Back to 0 degrees
         }
     }
 
@@ -135,19 +135,19 @@ mod tests {
         
         let mut piece = Tetromino::new(TetrominoType::J);
         piece.position = (5, 10);
-        piece.rotation = 3; // Start at 270 degrees
+        piece.rotation = RotationState::Left; // Start at 270 degrees
         
         // Rotate clockwise should wrap to 0
         let result = srs.rotate_clockwise(&piece, &board);
         if let RotationResult::Success { new_piece } = result {
-            assert_eq!(new_piece.rotation, 0);
+            assert_eq!(new_piece.rotation, RotationState::Spawn);
         }
         
         // Rotate counterclockwise from 0 should wrap to 3
-        piece.rotation = 0;
+        piece.rotation = RotationState::Spawn;
         let result = srs.rotate_counterclockwise(&piece, &board);
         if let RotationResult::Success { new_piece } = result {
-            assert_eq!(new_piece.rotation, 3);
+            assert_eq!(new_piece.rotation, RotationState::Left);
         }
     }
 
@@ -184,7 +184,7 @@ mod tests {
         for x in (center_x - 2)..(center_x + 3) {
             for y in (center_y - 2)..(center_y + 3) {
                 if x != center_x || y != center_y {
-                    board.set_cell(x, y, Cell::Filled(Color::GRAY));
+                    board.set_cell(x, y, Cell::block(Color::GRAY));
                 }
             }
         }
@@ -206,9 +206,9 @@ mod tests {
         let t_y = 20;
         
         // Fill 3 corners around the T-piece to create T-spin condition
-        board.set_cell(t_x - 1, t_y - 1, Cell::Filled(Color::GRAY)); // Top-left
-        board.set_cell(t_x + 1, t_y - 1, Cell::Filled(Color::GRAY)); // Top-right
-        board.set_cell(t_x - 1, t_y + 1, Cell::Filled(Color::GRAY)); // Bottom-left
+        board.set_cell(t_x - 1, t_y - 1, Cell::block(Color::GRAY)); // Top-left
+        board.set_cell(t_x + 1, t_y - 1, Cell::block(Color::GRAY)); // Top-right
+        board.set_cell(t_x - 1, t_y + 1, Cell::block(Color::GRAY)); // Bottom-left
         // Leave bottom-right corner open
         
         let mut piece = Tetromino::new(TetrominoType::T);
@@ -234,9 +234,9 @@ mod tests {
         let t_x = 5;
         let t_y = 20;
         
-        board.set_cell(t_x - 1, t_y - 1, Cell::Filled(Color::GRAY));
-        board.set_cell(t_x + 1, t_y - 1, Cell::Filled(Color::GRAY));
-        board.set_cell(t_x - 1, t_y + 1, Cell::Filled(Color::GRAY));
+        board.set_cell(t_x - 1, t_y - 1, Cell::block(Color::GRAY));
+        board.set_cell(t_x + 1, t_y - 1, Cell::block(Color::GRAY));
+        board.set_cell(t_x - 1, t_y + 1, Cell::block(Color::GRAY));
         
         let mut piece = Tetromino::new(TetrominoType::T);
         piece.position = (t_x, t_y);
@@ -292,7 +292,7 @@ mod tests {
             match result {
                 RotationResult::Success { new_piece } | RotationResult::SuccessWithKick { new_piece, .. } => {
                     piece = new_piece;
-                    assert_eq!(piece.rotation, expected_rotation % 4);
+                    assert_eq!(piece.rotation, RotationState::from(expected_rotation % 4));
                 },
                 RotationResult::Failed => {
                     panic!("Rotation {} failed", expected_rotation);
@@ -301,6 +301,6 @@ mod tests {
         }
         
         // Should be back to original rotation
-        assert_eq!(piece.rotation, 0);
+        assert_eq!(piece.rotation, RotationState::Spawn);
     }
 }
\ No newline at end of file
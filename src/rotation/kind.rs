@@ -0,0 +1,47 @@
+//! Selectable rotation systems
+
+use super::ars::{self, ArsRotationSystem};
+use super::srs::{RotationState, RotationSystem, SRSRotationSystem};
+use crate::tetromino::TetrominoType;
+use serde::{Serialize, Deserialize};
+
+/// Which rotation system a game or piece uses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum RotationSystemKind {
+    /// Super Rotation System (modern guideline)
+    #[default]
+    Srs,
+    /// Arika/Sega-style rotation (classic arcade)
+    Ars,
+}
+
+impl RotationSystemKind {
+    /// All rotation systems in settings order
+    pub fn all() -> Vec<RotationSystemKind> {
+        vec![RotationSystemKind::Srs, RotationSystemKind::Ars]
+    }
+
+    /// Display name for menus
+    pub fn name(self) -> &'static str {
+        match self {
+            RotationSystemKind::Srs => "SRS",
+            RotationSystemKind::Ars => "ARS",
+        }
+    }
+
+    /// Create a rotation system of this kind
+    pub fn create(self) -> Box<dyn RotationSystem> {
+        match self {
+            RotationSystemKind::Srs => Box::new(SRSRotationSystem::new()),
+            RotationSystemKind::Ars => Box::new(ArsRotationSystem::new()),
+        }
+    }
+
+    /// Block offsets for a piece in a rotation state, relative to its rotation center
+    pub fn piece_blocks(self, piece_type: TetrominoType, rotation: RotationState) -> Vec<(i32, i32)> {
+        match self {
+            RotationSystemKind::Srs => crate::tetromino::data::get_tetromino_blocks(piece_type, rotation),
+            RotationSystemKind::Ars => ars::get_ars_blocks(piece_type, rotation),
+        }
+    }
+}
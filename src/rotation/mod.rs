@@ -5,10 +5,14 @@
 //! multiple offset positions when the basic rotation would collide.
 
 pub mod srs;
+pub mod ars;
 pub mod kick_tables;
+pub mod kind;
 
 #[cfg(test)]
 mod integration_tests;
 
 pub use srs::{RotationSystem, SRSRotationSystem, RotationState, RotationResult};
+pub use ars::ArsRotationSystem;
+pub use kind::RotationSystemKind;
 pub use kick_tables::{WallKickData, get_wall_kick_offsets};
@@ -6,10 +6,72 @@
 use crate::tetromino::{Tetromino, TetrominoType};
 use crate::board::Board;
 use super::kick_tables::{get_wall_kick_offsets, KickOffset};
-use serde::{Serialize, Deserialize};
+use super::kind::RotationSystemKind;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-/// Rotation state representation (0°, 90° CW, 180°, 270° CW)
-pub type RotationState = u8;
+/// Orientation of a piece, named as in the SRS guideline
+///
+/// Saved as its index (0-3), the plain number saves held before this was an enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum RotationState {
+    /// Spawn orientation (0)
+    #[default]
+    Spawn,
+    /// One turn clockwise from spawn (R)
+    Right,
+    /// Half turn from spawn (2)
+    Two,
+    /// One turn counterclockwise from spawn (L)
+    Left,
+}
+
+impl RotationState {
+    /// All states in clockwise order from spawn
+    pub fn all() -> [RotationState; 4] {
+        [RotationState::Spawn, RotationState::Right, RotationState::Two, RotationState::Left]
+    }
+    
+    /// The state one turn clockwise
+    pub fn clockwise(self) -> Self {
+        Self::from(self.index() + 1)
+    }
+    
+    /// The state one turn counterclockwise
+    pub fn counterclockwise(self) -> Self {
+        Self::from(self.index() + 3)
+    }
+    
+    /// Quarter turns clockwise from spawn (0-3)
+    pub fn index(self) -> u8 {
+        match self {
+            RotationState::Spawn => 0,
+            RotationState::Right => 1,
+            RotationState::Two => 2,
+            RotationState::Left => 3,
+        }
+    }
+}
+
+impl From<u8> for RotationState {
+    /// Quarter turns clockwise from spawn, wrapping past a full turn
+    fn from(index: u8) -> Self {
+        Self::all()[(index % 4) as usize]
+    }
+}
+
+impl From<RotationState> for u8 {
+    fn from(state: RotationState) -> Self {
+        state.index()
+    }
+}
+
+impl std::fmt::Display for RotationState {
+    /// Shown as its number (0-3), as rotations were before they had names
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.index())
+    }
+}
 
 /// Result of a rotation attempt
 #[derive(Debug, Clone, PartialEq)]
@@ -23,7 +85,7 @@ pub enum RotationResult {
 }
 
 /// Trait for rotation systems - allows for different rotation behaviors
-pub trait RotationSystem {
+pub trait RotationSystem: std::fmt::Debug {
     /// Attempt to rotate a piece clockwise
     fn rotate_clockwise(&self, piece: &Tetromino, board: &Board) -> RotationResult;
     
@@ -32,6 +94,42 @@ pub trait RotationSystem {
     
     /// Check if the last rotation could result in a T-spin
     fn is_t_spin_position(&self, piece: &Tetromino, board: &Board, kick_used: Option<KickOffset>) -> bool;
+    
+    /// Which rotation system this is (also decides piece shapes and rotation centers)
+    fn kind(&self) -> RotationSystemKind;
+    
+    /// Copy this rotation system into a new box
+    fn clone_box(&self) -> Box<dyn RotationSystem>;
+    
+    /// Create a piece in this system's spawn orientation at the spawn position
+    fn spawn_piece(&self, piece_type: TetrominoType) -> Tetromino {
+        Tetromino::new_with_rotation_system(piece_type, self.kind())
+    }
+}
+
+impl Clone for Box<dyn RotationSystem> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+impl Default for Box<dyn RotationSystem> {
+    fn default() -> Self {
+        RotationSystemKind::default().create()
+    }
+}
+
+// Saves store only which system was in use
+impl Serialize for Box<dyn RotationSystem> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.kind().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Box<dyn RotationSystem> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        RotationSystemKind::deserialize(deserializer).map(RotationSystemKind::create)
+    }
 }
 
 /// Super Rotation System implementation
@@ -137,12 +235,12 @@ impl SRSRotationSystem {
     
     /// Get the next rotation state clockwise
     fn next_rotation_cw(current: RotationState) -> RotationState {
-        (current + 1) % 4
+        current.clockwise()
     }
     
     /// Get the next rotation state counterclockwise  
     fn next_rotation_ccw(current: RotationState) -> RotationState {
-        if current == 0 { 3 } else { current - 1 }
+        current.counterclockwise()
     }
 }
 
@@ -190,6 +288,14 @@ impl RotationSystem for SRSRotationSystem {
         // For "proper" T-spins, we could add additional checks here
         occupied_corners >= 3
     }
+    
+    fn kind(&self) -> RotationSystemKind {
+        RotationSystemKind::Srs
+    }
+    
+    fn clone_box(&self) -> Box<dyn RotationSystem> {
+        Box::new(self.clone())
+    }
 }
 
 /// Helper function to create the default SRS rotation system
@@ -235,15 +341,15 @@ mod tests {
     
     #[test]
     fn test_rotation_state_transitions() {
-        assert_eq!(SRSRotationSystem::next_rotation_cw(0), 1);
-        assert_eq!(SRSRotationSystem::next_rotation_cw(1), 2);
-        assert_eq!(SRSRotationSystem::next_rotation_cw(2), 3);
-        assert_eq!(SRSRotationSystem::next_rotation_cw(3), 0);
+        assert_eq!(SRSRotationSystem::next_rotation_cw(RotationState::Spawn), RotationState::Right);
+        assert_eq!(SRSRotationSystem::next_rotation_cw(RotationState::Right), RotationState::Two);
+        assert_eq!(SRSRotationSystem::next_rotation_cw(RotationState::Two), RotationState::Left);
+        assert_eq!(SRSRotationSystem::next_rotation_cw(RotationState::Left), RotationState::Spawn);
         
-        assert_eq!(SRSRotationSystem::next_rotation_ccw(0), 3);
-        assert_eq!(SRSRotationSystem::next_rotation_ccw(1), 0);
-        assert_eq!(SRSRotationSystem::next_rotation_ccw(2), 1);
-        assert_eq!(SRSRotationSystem::next_rotation_ccw(3), 2);
+        assert_eq!(SRSRotationSystem::next_rotation_ccw(RotationState::Spawn), RotationState::Left);
+        assert_eq!(SRSRotationSystem::next_rotation_ccw(RotationState::Right), RotationState::Spawn);
+        assert_eq!(SRSRotationSystem::next_rotation_ccw(RotationState::Two), RotationState::Right);
+        assert_eq!(SRSRotationSystem::next_rotation_ccw(RotationState::Left), RotationState::Two);
     }
     
     #[test]
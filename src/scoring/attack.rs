@@ -0,0 +1,51 @@
+//! Guideline attack (outgoing garbage) values for versus play
+
+use super::ScoringAction;
+
+/// Extra garbage sent for consecutive clears, indexed by combo (0 = first clear of a chain)
+const COMBO_ATTACK: [u32; 12] = [0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 4, 5];
+
+/// Garbage sent for emptying the board
+pub const PERFECT_CLEAR_ATTACK: u32 = 10;
+
+/// Garbage added for a continued combo chain
+pub fn combo_attack(combo: u32) -> u32 {
+    COMBO_ATTACK[(combo as usize).min(COMBO_ATTACK.len() - 1)]
+}
+
+/// Garbage lines a scored line clear sends to the opponent
+pub fn attack_for(action: &ScoringAction) -> u32 {
+    let mut attack = action.line_clear_type.attack_lines() + combo_attack(action.combo);
+    if action.back_to_back {
+        attack += 1;
+    }
+    if action.perfect_clear.is_some() {
+        attack += PERFECT_CLEAR_ATTACK;
+    }
+    attack
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scoring::LineClearType;
+
+    #[test]
+    fn test_attack_table() {
+        let action = |line_clear_type, combo, back_to_back| ScoringAction {
+            line_clear_type,
+            perfect_clear: None,
+            level: 1,
+            combo,
+            back_to_back,
+        };
+
+        assert_eq!(attack_for(&action(LineClearType::Single, 0, false)), 0);
+        assert_eq!(attack_for(&action(LineClearType::Tetris, 0, false)), 4);
+        assert_eq!(attack_for(&action(LineClearType::Tetris, 0, true)), 5);
+        assert_eq!(attack_for(&action(LineClearType::TSpinDouble, 0, false)), 4);
+        assert_eq!(attack_for(&action(LineClearType::Double, 4, false)), 3);
+        // Long combos cap at the end of the table
+        assert_eq!(combo_attack(50), 5);
+    }
+}
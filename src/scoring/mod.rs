@@ -10,8 +10,10 @@
 
 use serde::{Serialize, Deserialize};
 
+pub mod attack;
 pub mod perfect_clear;
 
+pub use attack::attack_for;
 pub use perfect_clear::PerfectClearDetector;
 
 /// Types of line clear actions that can occur
@@ -53,6 +55,21 @@ impl LineClearType {
         }
     }
     
+    /// Get the garbage lines this clear sends in versus play (before combo and back-to-back)
+    pub fn attack_lines(self) -> u32 {
+        match self {
+            LineClearType::Single => 0,
+            LineClearType::Double => 1,
+            LineClearType::Triple => 2,
+            LineClearType::Tetris => 4,
+            LineClearType::TSpinMiniSingle => 0,
+            LineClearType::TSpinSingle => 2,
+            LineClearType::TSpinMiniDouble => 1,
+            LineClearType::TSpinDouble => 4,
+            LineClearType::TSpinTriple => 6,
+        }
+    }
+    
     /// Get the number of lines cleared by this action
     pub fn lines_cleared(self) -> u32 {
         match self {
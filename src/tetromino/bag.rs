@@ -0,0 +1,140 @@
+//! Seeded 7-bag piece randomizer
+
+use super::types::TetrominoType;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use serde::{Serialize, Deserialize};
+
+/// 7-bag randomizer: every run of seven pieces contains each tetromino exactly once.
+///
+/// Each bag is shuffled from its own RNG derived from `(seed, bag index)`, so the
+/// sequence can be peeked arbitrarily far ahead without touching any shared RNG state.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SevenBag {
+    /// Seed the whole piece sequence is derived from
+    pub seed: u64,
+    /// Number of bags generated so far
+    bags_drawn: u64,
+    /// Pieces left in the current bag, next piece first
+    queue: Vec<TetrominoType>,
+}
+
+impl SevenBag {
+    /// Create a new bag sequence from a seed
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            bags_drawn: 0,
+            queue: Vec::new(),
+        }
+    }
+
+    /// Create a bag sequence whose first piece is never S or Z
+    ///
+    /// The first bag is dealt up front with its first non-S/Z piece moved to the front,
+    /// so it stays a full permutation and the preview stays honest.
+    pub fn new_no_sz_start(seed: u64) -> Self {
+        let mut queue = Self::generate_bag(seed, 0);
+        if let Some(index) = queue.iter().position(|piece| !matches!(piece, TetrominoType::S | TetrominoType::Z)) {
+            let piece = queue.remove(index);
+            queue.insert(0, piece);
+        }
+
+        Self {
+            seed,
+            bags_drawn: 1,
+            queue,
+        }
+    }
+
+    /// Create a new bag sequence with a random seed
+    pub fn from_entropy() -> Self {
+        Self::new(rand::random())
+    }
+
+    /// Shuffle the bag with the given index for a seed
+    fn generate_bag(seed: u64, index: u64) -> Vec<TetrominoType> {
+        // Mix the index into the seed so consecutive bags get unrelated RNG streams
+        let mut rng = StdRng::seed_from_u64(seed ^ index.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+        let mut bag = TetrominoType::all().to_vec();
+        bag.shuffle(&mut rng);
+        bag
+    }
+
+    /// Take the next piece, refilling the bag when it runs out
+    pub fn next_piece(&mut self) -> TetrominoType {
+        if self.queue.is_empty() {
+            self.queue = Self::generate_bag(self.seed, self.bags_drawn);
+            self.bags_drawn += 1;
+        }
+        self.queue.remove(0)
+    }
+
+    /// Look at the next `n` pieces without consuming them
+    pub fn peek(&self, n: usize) -> Vec<TetrominoType> {
+        let mut pieces: Vec<TetrominoType> = self.queue.iter().copied().take(n).collect();
+        let mut index = self.bags_drawn;
+        while pieces.len() < n {
+            let bag = Self::generate_bag(self.seed, index);
+            let needed = n - pieces.len();
+            pieces.extend(bag.into_iter().take(needed));
+            index += 1;
+        }
+        pieces
+    }
+
+    /// Number of pieces left in the current bag
+    pub fn remaining_in_bag(&self) -> usize {
+        self.queue.len()
+    }
+}
+
+impl Default for SevenBag {
+    fn default() -> Self {
+        Self::from_entropy()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted(mut pieces: Vec<TetrominoType>) -> Vec<TetrominoType> {
+        pieces.sort_by_key(|p| *p as u8);
+        pieces
+    }
+
+    #[test]
+    fn test_each_bag_is_a_permutation() {
+        let mut bag = SevenBag::new(42);
+        let expected = sorted(TetrominoType::all().to_vec());
+
+        for _ in 0..5 {
+            let pieces: Vec<TetrominoType> = (0..7).map(|_| bag.next_piece()).collect();
+            assert_eq!(sorted(pieces), expected);
+        }
+    }
+
+    #[test]
+    fn test_peek_matches_draws() {
+        let mut bag = SevenBag::new(7);
+        bag.next_piece();
+        bag.next_piece();
+
+        let peeked = bag.peek(20);
+        assert_eq!(peeked, bag.peek(20));
+
+        let drawn: Vec<TetrominoType> = (0..20).map(|_| bag.next_piece()).collect();
+        assert_eq!(peeked, drawn);
+    }
+
+    #[test]
+    fn test_same_seed_same_sequence() {
+        let mut a = SevenBag::new(1234);
+        let mut b = SevenBag::new(1234);
+        for _ in 0..21 {
+            assert_eq!(a.next_piece(), b.next_piece());
+        }
+    }
+}
@@ -1,12 +1,12 @@
 //! Tetromino shape data and definitions
 
 use super::types::TetrominoType;
+use crate::rotation::RotationState;
+use crate::rotation::RotationState::{Left, Right, Spawn, Two};
 
 /// Get the block positions for a tetromino type and rotation
 /// Returns relative positions from the piece center
-pub fn get_tetromino_blocks(piece_type: TetrominoType, rotation: u8) -> Vec<(i32, i32)> {
-    let rotation = rotation % 4; // Ensure rotation is 0-3
-    
+pub fn get_tetromino_blocks(piece_type: TetrominoType, rotation: RotationState) -> Vec<(i32, i32)> {
     match piece_type {
         TetrominoType::I => get_i_piece_blocks(rotation),
         TetrominoType::O => get_o_piece_blocks(rotation),
@@ -19,67 +19,61 @@ pub fn get_tetromino_blocks(piece_type: TetrominoType, rotation: u8) -> Vec<(i32
 }
 
 /// I-piece (line) - 4 blocks in a line
-fn get_i_piece_blocks(rotation: u8) -> Vec<(i32, i32)> {
+fn get_i_piece_blocks(rotation: RotationState) -> Vec<(i32, i32)> {
     match rotation {
-        0 | 2 => vec![(-1, 0), (0, 0), (1, 0), (2, 0)], // Horizontal
-        1 | 3 => vec![(0, -1), (0, 0), (0, 1), (0, 2)], // Vertical
-        _ => vec![],
+        Spawn | Two => vec![(-1, 0), (0, 0), (1, 0), (2, 0)], // Horizontal
+        Right | Left => vec![(0, -1), (0, 0), (0, 1), (0, 2)], // Vertical
     }
 }
 
 /// O-piece (square) - 2x2 square, no rotation
-fn get_o_piece_blocks(_rotation: u8) -> Vec<(i32, i32)> {
+fn get_o_piece_blocks(_rotation: RotationState) -> Vec<(i32, i32)> {
     vec![(0, 0), (1, 0), (0, 1), (1, 1)]
 }
 
 /// T-piece - T-shaped piece
-fn get_t_piece_blocks(rotation: u8) -> Vec<(i32, i32)> {
+fn get_t_piece_blocks(rotation: RotationState) -> Vec<(i32, i32)> {
     match rotation {
-        0 => vec![(-1, 0), (0, 0), (1, 0), (0, -1)], // T pointing up
-        1 => vec![(0, -1), (0, 0), (0, 1), (1, 0)],  // T pointing right
-        2 => vec![(-1, 0), (0, 0), (1, 0), (0, 1)],  // T pointing down
-        3 => vec![(0, -1), (0, 0), (0, 1), (-1, 0)], // T pointing left
-        _ => vec![],
+        Spawn => vec![(-1, 0), (0, 0), (1, 0), (0, -1)], // T pointing up
+        Right => vec![(0, -1), (0, 0), (0, 1), (1, 0)],  // T pointing right
+        Two => vec![(-1, 0), (0, 0), (1, 0), (0, 1)],  // T pointing down
+        Left => vec![(0, -1), (0, 0), (0, 1), (-1, 0)], // T pointing left
     }
 }
 
 /// S-piece - S-shaped piece
-fn get_s_piece_blocks(rotation: u8) -> Vec<(i32, i32)> {
+fn get_s_piece_blocks(rotation: RotationState) -> Vec<(i32, i32)> {
     match rotation {
-        0 | 2 => vec![(0, 0), (1, 0), (-1, 1), (0, 1)], // Horizontal S
-        1 | 3 => vec![(0, -1), (0, 0), (1, 0), (1, 1)], // Vertical S
-        _ => vec![],
+        Spawn | Two => vec![(0, 0), (1, 0), (-1, 1), (0, 1)], // Horizontal S
+        Right | Left => vec![(0, -1), (0, 0), (1, 0), (1, 1)], // Vertical S
     }
 }
 
 /// Z-piece - Z-shaped piece
-fn get_z_piece_blocks(rotation: u8) -> Vec<(i32, i32)> {
+fn get_z_piece_blocks(rotation: RotationState) -> Vec<(i32, i32)> {
     match rotation {
-        0 | 2 => vec![(-1, 0), (0, 0), (0, 1), (1, 1)], // Horizontal Z
-        1 | 3 => vec![(0, 0), (0, 1), (-1, 1), (-1, 2)], // Vertical Z
-        _ => vec![],
+        Spawn | Two => vec![(-1, 0), (0, 0), (0, 1), (1, 1)], // Horizontal Z
+        Right | Left => vec![(0, 0), (0, 1), (-1, 1), (-1, 2)], // Vertical Z
     }
 }
 
 /// J-piece - J-shaped piece
-fn get_j_piece_blocks(rotation: u8) -> Vec<(i32, i32)> {
+fn get_j_piece_blocks(rotation: RotationState) -> Vec<(i32, i32)> {
     match rotation {
-        0 => vec![(-1, -1), (-1, 0), (0, 0), (1, 0)], // J pointing right
-        1 => vec![(0, -1), (0, 0), (0, 1), (1, 1)],  // J pointing down
-        2 => vec![(-1, 0), (0, 0), (1, 0), (1, 1)],  // J pointing left
-        3 => vec![(-1, -1), (0, -1), (0, 0), (0, 1)], // J pointing up
-        _ => vec![],
+        Spawn => vec![(-1, -1), (-1, 0), (0, 0), (1, 0)], // J pointing right
+        Right => vec![(0, -1), (0, 0), (0, 1), (1, 1)],  // J pointing down
+        Two => vec![(-1, 0), (0, 0), (1, 0), (1, 1)],  // J pointing left
+        Left => vec![(-1, -1), (0, -1), (0, 0), (0, 1)], // J pointing up
     }
 }
 
 /// L-piece - L-shaped piece
-fn get_l_piece_blocks(rotation: u8) -> Vec<(i32, i32)> {
+fn get_l_piece_blocks(rotation: RotationState) -> Vec<(i32, i32)> {
     match rotation {
-        0 => vec![(1, -1), (-1, 0), (0, 0), (1, 0)], // L pointing right
-        1 => vec![(0, -1), (0, 0), (0, 1), (1, -1)], // L pointing down
-        2 => vec![(-1, 0), (0, 0), (1, 0), (-1, 1)], // L pointing left
-        3 => vec![(0, -1), (0, 0), (0, 1), (-1, 1)], // L pointing up
-        _ => vec![],
+        Spawn => vec![(1, -1), (-1, 0), (0, 0), (1, 0)], // L pointing right
+        Right => vec![(0, -1), (0, 0), (0, 1), (1, -1)], // L pointing down
+        Two => vec![(-1, 0), (0, 0), (1, 0), (-1, 1)], // L pointing left
+        Left => vec![(0, -1), (0, 0), (0, 1), (-1, 1)], // L pointing up
     }
 }
 
@@ -91,7 +85,7 @@ mod tests {
     fn test_all_pieces_have_four_blocks() {
         for piece_type in TetrominoType::all() {
             for rotation in 0..4 {
-                let blocks = get_tetromino_blocks(piece_type, rotation);
+                let blocks = get_tetromino_blocks(piece_type, RotationState::from(rotation));
                 assert_eq!(blocks.len(), 4, 
                     "Piece {:?} rotation {} should have 4 blocks, got {}", 
                     piece_type, rotation, blocks.len());
@@ -101,9 +95,9 @@ mod tests {
 
     #[test]
     fn test_o_piece_same_all_rotations() {
-        let blocks_0 = get_tetromino_blocks(TetrominoType::O, 0);
+        let blocks_0 = get_tetromino_blocks(TetrominoType::O, RotationState::from(0));
         for rotation in 1..4 {
-            let blocks = get_tetromino_blocks(TetrominoType::O, rotation);
+            let blocks = get_tetromino_blocks(TetrominoType::O, RotationState::from(rotation));
             assert_eq!(blocks_0, blocks, 
                 "O-piece should be same for all rotations");
         }
@@ -111,25 +105,25 @@ mod tests {
 
     #[test]
     fn test_i_piece_rotations() {
-        let horizontal = get_tetromino_blocks(TetrominoType::I, 0);
-        let vertical = get_tetromino_blocks(TetrominoType::I, 1);
+        let horizontal = get_tetromino_blocks(TetrominoType::I, RotationState::from(0));
+        let vertical = get_tetromino_blocks(TetrominoType::I, RotationState::from(1));
         
         // Should be different
         assert_ne!(horizontal, vertical);
         
         // Rotation 2 should match rotation 0
-        assert_eq!(horizontal, get_tetromino_blocks(TetrominoType::I, 2));
+        assert_eq!(horizontal, get_tetromino_blocks(TetrominoType::I, RotationState::from(2)));
         
         // Rotation 3 should match rotation 1
-        assert_eq!(vertical, get_tetromino_blocks(TetrominoType::I, 3));
+        assert_eq!(vertical, get_tetromino_blocks(TetrominoType::I, RotationState::from(3)));
     }
 
     #[test]
     fn test_rotation_bounds() {
         // Test that rotation values > 3 are handled correctly
-        let blocks_0 = get_tetromino_blocks(TetrominoType::T, 0);
-        let blocks_4 = get_tetromino_blocks(TetrominoType::T, 4);
-        let blocks_8 = get_tetromino_blocks(TetrominoType::T, 8);
+        let blocks_0 = get_tetromino_blocks(TetrominoType::T, RotationState::from(0));
+        let blocks_4 = get_tetromino_blocks(TetrominoType::T, RotationState::from(4));
+        let blocks_8 = get_tetromino_blocks(TetrominoType::T, RotationState::from(8));
         
         assert_eq!(blocks_0, blocks_4);
         assert_eq!(blocks_0, blocks_8);
@@ -0,0 +1,156 @@
+//! Common interface for anything that deals pieces, plus a fixed sequence for tests and a
+//! filtered bag for practice
+
+use super::bag::SevenBag;
+use super::history::HistoryRandomizer;
+use super::types::TetrominoType;
+use serde::{Serialize, Deserialize};
+
+/// A source of pieces that can show what it will deal before dealing it
+pub trait PieceGenerator {
+    /// Take the next piece
+    fn next(&mut self) -> TetrominoType;
+
+    /// Look at the next `n` pieces without consuming them
+    fn peek(&self, n: usize) -> Vec<TetrominoType>;
+}
+
+impl PieceGenerator for SevenBag {
+    fn next(&mut self) -> TetrominoType {
+        self.next_piece()
+    }
+
+    fn peek(&self, n: usize) -> Vec<TetrominoType> {
+        SevenBag::peek(self, n)
+    }
+}
+
+impl PieceGenerator for HistoryRandomizer {
+    fn next(&mut self) -> TetrominoType {
+        self.next_piece()
+    }
+
+    fn peek(&self, n: usize) -> Vec<TetrominoType> {
+        HistoryRandomizer::peek(self, n)
+    }
+}
+
+/// Deals a fixed list of pieces in order, starting over once it runs out
+///
+/// Lets tests and AI experiments set up an exact scenario instead of searching for a seed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SequenceGenerator {
+    /// Pieces dealt, in order
+    pieces: Vec<TetrominoType>,
+    /// Index of the next piece to deal
+    position: usize,
+}
+
+impl SequenceGenerator {
+    /// Deal `pieces` in order, looping forever
+    ///
+    /// # Panics
+    /// If `pieces` is empty.
+    pub fn new(pieces: Vec<TetrominoType>) -> Self {
+        assert!(!pieces.is_empty(), "a piece sequence needs at least one piece");
+        Self { pieces, position: 0 }
+    }
+
+    /// The same sequence, rewound to its first piece
+    pub fn rewound(&self) -> Self {
+        Self::new(self.pieces.clone())
+    }
+}
+
+impl PieceGenerator for SequenceGenerator {
+    fn next(&mut self) -> TetrominoType {
+        let piece = self.pieces[self.position];
+        self.position = (self.position + 1) % self.pieces.len();
+        piece
+    }
+
+    fn peek(&self, n: usize) -> Vec<TetrominoType> {
+        self.pieces.iter().cycle().skip(self.position).take(n).copied().collect()
+    }
+}
+
+/// Deals from a 7-bag but only emits the allowed piece types, for drilling particular pieces
+///
+/// Each bag holds every type once, so the allowed pieces keep the bag's even spread.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FilteredGenerator {
+    /// Bag the pieces are drawn from
+    bag: SevenBag,
+    /// Piece types that may be dealt
+    allowed: Vec<TetrominoType>,
+}
+
+impl FilteredGenerator {
+    /// Deal only `allowed` pieces from a bag sequence seeded with `seed`
+    ///
+    /// # Panics
+    /// If `allowed` is empty.
+    pub fn new(seed: u64, allowed: Vec<TetrominoType>) -> Self {
+        assert!(!allowed.is_empty(), "a filtered generator needs at least one piece type");
+        Self { bag: SevenBag::new(seed), allowed }
+    }
+
+    /// Seed the underlying bag sequence started from
+    pub fn seed(&self) -> u64 {
+        self.bag.seed
+    }
+
+    /// Piece types this generator deals
+    pub fn allowed(&self) -> &[TetrominoType] {
+        &self.allowed
+    }
+}
+
+impl PieceGenerator for FilteredGenerator {
+    fn next(&mut self) -> TetrominoType {
+        loop {
+            let piece = self.bag.next_piece();
+            if self.allowed.contains(&piece) {
+                return piece;
+            }
+        }
+    }
+
+    fn peek(&self, n: usize) -> Vec<TetrominoType> {
+        // Every whole bag holds at least one allowed piece, and the current bag may be partly used
+        self.bag.peek((n + 1) * TetrominoType::all().len())
+            .into_iter()
+            .filter(|piece| self.allowed.contains(piece))
+            .take(n)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequence_deals_in_order_and_loops() {
+        let mut sequence = SequenceGenerator::new(vec![TetrominoType::I, TetrominoType::O, TetrominoType::T]);
+        assert_eq!(sequence.next(), TetrominoType::I);
+        assert_eq!(sequence.peek(4), vec![TetrominoType::O, TetrominoType::T, TetrominoType::I, TetrominoType::O]);
+
+        let dealt: Vec<TetrominoType> = (0..4).map(|_| sequence.next()).collect();
+        assert_eq!(dealt, vec![TetrominoType::O, TetrominoType::T, TetrominoType::I, TetrominoType::O]);
+        assert_eq!(sequence.rewound().next(), TetrominoType::I);
+    }
+
+    #[test]
+    fn test_filtered_generator_only_deals_allowed_pieces() {
+        let mut only_t = FilteredGenerator::new(7, vec![TetrominoType::T]);
+        assert!((0..50).all(|_| only_t.next() == TetrominoType::T));
+
+        let mut s_and_z = FilteredGenerator::new(11, vec![TetrominoType::S, TetrominoType::Z]);
+        let preview = s_and_z.peek(20);
+        let dealt: Vec<TetrominoType> = (0..20).map(|_| s_and_z.next()).collect();
+        assert_eq!(preview, dealt);
+        assert!(dealt.iter().all(|piece| matches!(piece, TetrominoType::S | TetrominoType::Z)));
+        assert!(dealt.contains(&TetrominoType::S) && dealt.contains(&TetrominoType::Z));
+    }
+}
@@ -0,0 +1,95 @@
+//! Seeded history-based piece randomizer
+
+use super::types::TetrominoType;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Serialize, Deserialize};
+
+/// Number of recent pieces a new piece tries to avoid
+const HISTORY_LENGTH: usize = 4;
+/// Rolls made before accepting a piece that is still in the history
+const HISTORY_ROLLS: usize = 4;
+
+/// Arcade-style randomizer: each piece is rolled up to a few times to avoid repeating
+/// the last four pieces, making droughts and repeats rare without a fixed bag.
+///
+/// Every draw uses its own RNG derived from `(seed, draw index)`, so lookahead is a pure
+/// simulation of the real draws.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryRandomizer {
+    /// Seed the whole piece sequence is derived from
+    pub seed: u64,
+    /// Number of pieces drawn so far
+    draws: u64,
+    /// Most recent pieces, oldest first
+    history: Vec<TetrominoType>,
+    /// Keep rolling the first piece until it isn't S or Z
+    #[serde(default)]
+    first_piece_no_sz: bool,
+}
+
+impl HistoryRandomizer {
+    /// Create a new history randomizer from a seed
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            draws: 0,
+            // Start as if S and Z were just dealt, so the opening avoids them
+            history: vec![TetrominoType::Z, TetrominoType::S, TetrominoType::Z, TetrominoType::S],
+            first_piece_no_sz: false,
+        }
+    }
+
+    /// Create a history randomizer whose first piece is never S or Z
+    pub fn new_no_sz_start(seed: u64) -> Self {
+        Self {
+            first_piece_no_sz: true,
+            ..Self::new(seed)
+        }
+    }
+
+    /// Create a new history randomizer with a random seed
+    pub fn from_entropy() -> Self {
+        Self::new(rand::random())
+    }
+
+    /// Take the next piece
+    pub fn next_piece(&mut self) -> TetrominoType {
+        let mut rng = StdRng::seed_from_u64(self.seed ^ self.draws.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+        let types = TetrominoType::all();
+
+        let mut piece = types[rng.gen_range(0..types.len())];
+        for _ in 1..HISTORY_ROLLS {
+            if !self.history.contains(&piece) {
+                break;
+            }
+            piece = types[rng.gen_range(0..types.len())];
+        }
+
+        // The seeded history only makes an S/Z opening unlikely; this rules it out
+        if self.draws == 0 && self.first_piece_no_sz {
+            while matches!(piece, TetrominoType::S | TetrominoType::Z) {
+                piece = types[rng.gen_range(0..types.len())];
+            }
+        }
+
+        self.draws += 1;
+        self.history.push(piece);
+        if self.history.len() > HISTORY_LENGTH {
+            self.history.remove(0);
+        }
+        piece
+    }
+
+    /// Look at the next `n` pieces without consuming them
+    pub fn peek(&self, n: usize) -> Vec<TetrominoType> {
+        let mut lookahead = self.clone();
+        (0..n).map(|_| lookahead.next_piece()).collect()
+    }
+}
+
+impl Default for HistoryRandomizer {
+    fn default() -> Self {
+        Self::from_entropy()
+    }
+}
@@ -1,6 +1,14 @@
 //! Tetromino module containing piece definitions and data
 
+pub mod bag;
 pub mod data;
+pub mod generator;
+pub mod history;
+pub mod randomizer;
 pub mod types;
 
+pub use bag::SevenBag;
+pub use generator::{FilteredGenerator, PieceGenerator, SequenceGenerator};
+pub use history::HistoryRandomizer;
+pub use randomizer::{Randomizer, RandomizerKind};
 pub use types::{Tetromino, TetrominoType};
\ No newline at end of file
@@ -0,0 +1,205 @@
+//! Selectable piece randomizers
+
+use super::bag::SevenBag;
+use super::generator::{FilteredGenerator, PieceGenerator, SequenceGenerator};
+use super::history::HistoryRandomizer;
+use super::types::TetrominoType;
+use serde::{Serialize, Deserialize};
+
+/// Which randomizer algorithm a game uses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum RandomizerKind {
+    /// Every seven pieces contain each tetromino once
+    #[default]
+    SevenBag,
+    /// Re-roll pieces that appeared recently
+    History,
+}
+
+impl RandomizerKind {
+    /// All randomizers in settings order
+    pub fn all() -> Vec<RandomizerKind> {
+        vec![RandomizerKind::SevenBag, RandomizerKind::History]
+    }
+
+    /// Display name for menus
+    pub fn name(self) -> &'static str {
+        match self {
+            RandomizerKind::SevenBag => "7-BAG",
+            RandomizerKind::History => "HISTORY",
+        }
+    }
+
+    /// Create a randomizer of this kind from a seed, never opening with S or Z
+    pub fn create(self, seed: u64) -> Randomizer {
+        self.create_with(seed, true)
+    }
+
+    /// Create a randomizer of this kind from a seed, optionally allowing an S/Z first piece
+    pub fn create_with(self, seed: u64, first_piece_no_sz: bool) -> Randomizer {
+        match (self, first_piece_no_sz) {
+            (RandomizerKind::SevenBag, true) => Randomizer::SevenBag(SevenBag::new_no_sz_start(seed)),
+            (RandomizerKind::SevenBag, false) => Randomizer::SevenBag(SevenBag::new(seed)),
+            (RandomizerKind::History, true) => Randomizer::History(HistoryRandomizer::new_no_sz_start(seed)),
+            (RandomizerKind::History, false) => Randomizer::History(HistoryRandomizer::new(seed)),
+        }
+    }
+}
+
+/// The active randomizer and its state
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Randomizer {
+    SevenBag(SevenBag),
+    History(HistoryRandomizer),
+    /// A fixed list of pieces, for tests and scripted scenarios
+    Sequence(SequenceGenerator),
+    /// A 7-bag limited to some piece types, for practice
+    Filtered(FilteredGenerator),
+}
+
+impl Randomizer {
+    /// Which algorithm this is, or `None` for a fixed sequence or filtered practice bag
+    pub fn kind(&self) -> Option<RandomizerKind> {
+        match self {
+            Randomizer::SevenBag(_) => Some(RandomizerKind::SevenBag),
+            Randomizer::History(_) => Some(RandomizerKind::History),
+            Randomizer::Sequence(_) | Randomizer::Filtered(_) => None,
+        }
+    }
+
+    /// Seed the piece sequence is derived from (0 for a fixed sequence)
+    pub fn seed(&self) -> u64 {
+        match self {
+            Randomizer::SevenBag(bag) => bag.seed,
+            Randomizer::History(history) => history.seed,
+            Randomizer::Sequence(_) => 0,
+            Randomizer::Filtered(filtered) => filtered.seed(),
+        }
+    }
+
+    /// A fresh randomizer of the same kind dealing from `seed`
+    ///
+    /// A fixed sequence has no seed, so it starts over from its first piece instead. A filtered
+    /// bag keeps its piece types and may open with S or Z if those are all it deals.
+    pub fn reseeded(&self, seed: u64, first_piece_no_sz: bool) -> Randomizer {
+        match self {
+            Randomizer::SevenBag(_) => RandomizerKind::SevenBag.create_with(seed, first_piece_no_sz),
+            Randomizer::History(_) => RandomizerKind::History.create_with(seed, first_piece_no_sz),
+            Randomizer::Sequence(sequence) => Randomizer::Sequence(sequence.rewound()),
+            Randomizer::Filtered(filtered) => Randomizer::Filtered(FilteredGenerator::new(seed, filtered.allowed().to_vec())),
+        }
+    }
+
+    /// Take the next piece
+    pub fn next_piece(&mut self) -> TetrominoType {
+        // The preview must never lie: whatever was shown next is what gets dealt
+        #[cfg(debug_assertions)]
+        let previewed = self.peek(1)[0];
+
+        let piece = match self {
+            Randomizer::SevenBag(bag) => bag.next_piece(),
+            Randomizer::History(history) => history.next_piece(),
+            Randomizer::Sequence(sequence) => sequence.next(),
+            Randomizer::Filtered(filtered) => filtered.next(),
+        };
+
+        #[cfg(debug_assertions)]
+        debug_assert_eq!(piece, previewed, "randomizer dealt a different piece than it previewed");
+
+        piece
+    }
+
+    /// Look at the next `n` pieces without consuming them
+    pub fn peek(&self, n: usize) -> Vec<TetrominoType> {
+        match self {
+            Randomizer::SevenBag(bag) => bag.peek(n),
+            Randomizer::History(history) => history.peek(n),
+            Randomizer::Sequence(sequence) => sequence.peek(n),
+            Randomizer::Filtered(filtered) => filtered.peek(n),
+        }
+    }
+}
+
+impl PieceGenerator for Randomizer {
+    fn next(&mut self) -> TetrominoType {
+        self.next_piece()
+    }
+
+    fn peek(&self, n: usize) -> Vec<TetrominoType> {
+        Randomizer::peek(self, n)
+    }
+}
+
+impl From<SevenBag> for Randomizer {
+    fn from(bag: SevenBag) -> Self {
+        Randomizer::SevenBag(bag)
+    }
+}
+
+impl From<HistoryRandomizer> for Randomizer {
+    fn from(history: HistoryRandomizer) -> Self {
+        Randomizer::History(history)
+    }
+}
+
+impl From<SequenceGenerator> for Randomizer {
+    fn from(sequence: SequenceGenerator) -> Self {
+        Randomizer::Sequence(sequence)
+    }
+}
+
+impl From<FilteredGenerator> for Randomizer {
+    fn from(filtered: FilteredGenerator) -> Self {
+        Randomizer::Filtered(filtered)
+    }
+}
+
+impl Default for Randomizer {
+    fn default() -> Self {
+        RandomizerKind::default().create(rand::random())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preview_matches_spawns_for_every_randomizer() {
+        for kind in RandomizerKind::all() {
+            let mut randomizer = kind.create(99);
+            randomizer.next_piece();
+
+            let preview = randomizer.peek(20);
+            let dealt: Vec<TetrominoType> = (0..20).map(|_| randomizer.next_piece()).collect();
+            assert_eq!(preview, dealt, "{:?} preview was dishonest", kind);
+        }
+    }
+
+    #[test]
+    fn test_first_piece_is_never_s_or_z() {
+        for kind in RandomizerKind::all() {
+            for seed in 0..500 {
+                let first = kind.create(seed).next_piece();
+                assert!(
+                    !matches!(first, TetrominoType::S | TetrominoType::Z),
+                    "{:?} with seed {} opened with {:?}", kind, seed, first
+                );
+            }
+        }
+
+        // Turning the rule off lets S and Z open games again
+        let openers: Vec<TetrominoType> = (0..500).map(|seed| RandomizerKind::SevenBag.create_with(seed, false).next_piece()).collect();
+        assert!(openers.iter().any(|piece| matches!(piece, TetrominoType::S | TetrominoType::Z)));
+    }
+
+    #[test]
+    fn test_history_avoids_recent_repeats() {
+        let mut randomizer = RandomizerKind::History.create(5);
+        let pieces: Vec<TetrominoType> = (0..200).map(|_| randomizer.next_piece()).collect();
+
+        // With four rolls against a four-piece history, back-to-back repeats are rare
+        let repeats = pieces.windows(2).filter(|pair| pair[0] == pair[1]).count();
+        assert!(repeats < 10, "{} immediate repeats", repeats);
+    }
+}
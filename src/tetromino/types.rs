@@ -1,6 +1,7 @@
 //! Tetromino type definitions
 
 use crate::graphics::colors::*;
+use crate::rotation::{RotationState, RotationSystemKind};
 use macroquad::prelude::Color;
 use rand::Rng;
 use serde::{Serialize, Deserialize};
@@ -45,6 +46,17 @@ impl TetrominoType {
         }
     }
     
+    /// The type with the left-right mirrored shape (S and Z swap, J and L swap, the rest are symmetric)
+    pub fn mirrored(self) -> TetrominoType {
+        match self {
+            TetrominoType::S => TetrominoType::Z,
+            TetrominoType::Z => TetrominoType::S,
+            TetrominoType::J => TetrominoType::L,
+            TetrominoType::L => TetrominoType::J,
+            other => other,
+        }
+    }
+    
     /// Get the name of the tetromino
     pub fn name(self) -> &'static str {
         match self {
@@ -66,20 +78,29 @@ pub struct Tetromino {
     pub piece_type: TetrominoType,
     /// Current position (x, y) of the piece center
     pub position: (i32, i32),
-    /// Current rotation state (0-3)
-    pub rotation: u8,
+    /// Current rotation state
+    pub rotation: RotationState,
     /// The blocks that make up this piece (relative to position)
     pub blocks: Vec<(i32, i32)>,
+    /// Rotation system whose shapes and rotation centers this piece uses
+    #[serde(default)]
+    pub rotation_system: RotationSystemKind,
 }
 
 impl Tetromino {
     /// Create a new tetromino at the spawn position
     pub fn new(piece_type: TetrominoType) -> Self {
+        Self::new_with_rotation_system(piece_type, RotationSystemKind::Srs)
+    }
+    
+    /// Create a new tetromino at the spawn position using a rotation system's shapes
+    pub fn new_with_rotation_system(piece_type: TetrominoType, rotation_system: RotationSystemKind) -> Self {
         let mut tetromino = Self {
             piece_type,
             position: (4, 2), // Start lower in buffer area for visibility
-            rotation: 0,
+            rotation: RotationState::Spawn,
             blocks: Vec::new(),
+            rotation_system,
         };
         tetromino.update_blocks();
         tetromino
@@ -92,7 +113,7 @@ impl Tetromino {
     
     /// Update the blocks array based on current type and rotation
     pub fn update_blocks(&mut self) {
-        self.blocks = crate::tetromino::data::get_tetromino_blocks(self.piece_type, self.rotation);
+        self.blocks = self.rotation_system.piece_blocks(self.piece_type, self.rotation);
     }
     
     /// Get the absolute positions of all blocks
@@ -110,16 +131,34 @@ impl Tetromino {
     
     /// Rotate the tetromino clockwise
     pub fn rotate_clockwise(&mut self) {
-        self.rotation = (self.rotation + 1) % 4;
+        self.rotation = self.rotation.clockwise();
         self.update_blocks();
     }
     
     /// Rotate the tetromino counterclockwise
     pub fn rotate_counterclockwise(&mut self) {
-        self.rotation = if self.rotation == 0 { 3 } else { self.rotation - 1 };
+        self.rotation = self.rotation.counterclockwise();
         self.update_blocks();
     }
     
+    /// A copy of this piece flipped left to right
+    ///
+    /// The flip of a piece's shape is always another piece's shape, so the result takes the
+    /// mirrored type (and the mirrored rotation state) and keeps rotating as that piece would.
+    /// It is shifted to cover the same columns as the original, so it stays in bounds.
+    pub fn mirrored(&self) -> Self {
+        let mut mirrored = self.clone();
+        mirrored.piece_type = self.piece_type.mirrored();
+        mirrored.rotation = match self.rotation {
+            RotationState::Right => RotationState::Left,
+            RotationState::Left => RotationState::Right,
+            other => other,
+        };
+        mirrored.update_blocks();
+        mirrored.position.0 += self.occupied_columns().0 - mirrored.occupied_columns().0;
+        mirrored
+    }
+    
     /// Get the color of this tetromino
     pub fn color(&self) -> Color {
         self.piece_type.color()
@@ -132,18 +171,54 @@ impl Tetromino {
     
     /// Get the bounding box of the tetromino (min_x, min_y, max_x, max_y)
     pub fn bounding_box(&self) -> (i32, i32, i32, i32) {
-        let abs_blocks = self.absolute_blocks();
-        if abs_blocks.is_empty() {
-            return (0, 0, 0, 0);
-        }
-        
-        let min_x = abs_blocks.iter().map(|(x, _)| *x).min().unwrap();
-        let min_y = abs_blocks.iter().map(|(_, y)| *y).min().unwrap();
-        let max_x = abs_blocks.iter().map(|(x, _)| *x).max().unwrap();
-        let max_y = abs_blocks.iter().map(|(_, y)| *y).max().unwrap();
-        
+        let (min_x, max_x) = self.occupied_columns();
+        let (min_y, max_y) = self.occupied_rows();
         (min_x, min_y, max_x, max_y)
     }
+    
+    /// Leftmost and rightmost absolute columns the piece covers
+    pub fn occupied_columns(&self) -> (i32, i32) {
+        Self::span(self.blocks.iter().map(|(dx, _)| self.position.0 + dx))
+    }
+    
+    /// Topmost and bottommost absolute rows the piece covers
+    pub fn occupied_rows(&self) -> (i32, i32) {
+        Self::span(self.blocks.iter().map(|(_, dy)| self.position.1 + dy))
+    }
+    
+    /// Number of columns the piece covers in its current rotation
+    pub fn width(&self) -> i32 {
+        let (left, right) = self.occupied_columns();
+        if self.blocks.is_empty() { 0 } else { right - left + 1 }
+    }
+    
+    /// Number of rows the piece covers in its current rotation
+    pub fn height(&self) -> i32 {
+        let (top, bottom) = self.occupied_rows();
+        if self.blocks.is_empty() { 0 } else { bottom - top + 1 }
+    }
+    
+    /// Each block's center relative to the middle of the piece's bounding box, in cells
+    ///
+    /// Scaling these by a block size and adding a panel's center draws the piece centered in the
+    /// panel whatever its shape, rather than around its rotation origin.
+    pub fn centered_preview_offsets(&self) -> Vec<(f32, f32)> {
+        let (min_x, max_x) = Self::span(self.blocks.iter().map(|(dx, _)| *dx));
+        let (min_y, max_y) = Self::span(self.blocks.iter().map(|(_, dy)| *dy));
+        let mid_x = (min_x + max_x) as f32 / 2.0;
+        let mid_y = (min_y + max_y) as f32 / 2.0;
+        self.blocks.iter()
+            .map(|(dx, dy)| (*dx as f32 - mid_x, *dy as f32 - mid_y))
+            .collect()
+    }
+    
+    /// Smallest and largest of some coordinates, or (0, 0) if there are none
+    fn span(coordinates: impl Iterator<Item = i32>) -> (i32, i32) {
+        coordinates.fold(None, |span, value| match span {
+            None => Some((value, value)),
+            Some((low, high)) => Some((value.min(low), value.max(high))),
+        }).unwrap_or((0, 0))
+    }
 }
 
 impl Default for Tetromino {
@@ -151,3 +226,130 @@ impl Default for Tetromino {
         Self::new(TetrominoType::T)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_occupied_columns_and_rows_in_every_rotation() {
+        // (columns, rows) for each rotation of a piece spawned at (4, 2)
+        type Span = ((i32, i32), (i32, i32));
+        let expected: [(TetrominoType, [Span; 4]); 7] = [
+            (TetrominoType::I, [((3, 6), (2, 2)), ((4, 4), (1, 4)), ((3, 6), (2, 2)), ((4, 4), (1, 4))]),
+            (TetrominoType::O, [((4, 5), (2, 3)); 4]),
+            (TetrominoType::T, [((3, 5), (1, 2)), ((4, 5), (1, 3)), ((3, 5), (2, 3)), ((3, 4), (1, 3))]),
+            (TetrominoType::S, [((3, 5), (2, 3)), ((4, 5), (1, 3)), ((3, 5), (2, 3)), ((4, 5), (1, 3))]),
+            (TetrominoType::Z, [((3, 5), (2, 3)), ((3, 4), (2, 4)), ((3, 5), (2, 3)), ((3, 4), (2, 4))]),
+            (TetrominoType::J, [((3, 5), (1, 2)), ((4, 5), (1, 3)), ((3, 5), (2, 3)), ((3, 4), (1, 3))]),
+            (TetrominoType::L, [((3, 5), (1, 2)), ((4, 5), (1, 3)), ((3, 5), (2, 3)), ((3, 4), (1, 3))]),
+        ];
+
+        for (piece_type, rotations) in expected {
+            let mut piece = Tetromino::new(piece_type);
+            for (rotation, (columns, rows)) in rotations.into_iter().enumerate() {
+                assert_eq!(piece.occupied_columns(), columns, "{:?} rotation {} columns", piece_type, rotation);
+                assert_eq!(piece.occupied_rows(), rows, "{:?} rotation {} rows", piece_type, rotation);
+                assert_eq!(piece.width(), columns.1 - columns.0 + 1);
+                assert_eq!(piece.height(), rows.1 - rows.0 + 1);
+                assert_eq!(piece.bounding_box(), (columns.0, rows.0, columns.1, rows.1));
+                piece.rotate_clockwise();
+            }
+        }
+
+        // A flat I spans four columns, a standing one four rows
+        let mut i_piece = Tetromino::new(TetrominoType::I);
+        assert_eq!((i_piece.width(), i_piece.height()), (4, 1));
+        i_piece.rotate_clockwise();
+        assert_eq!((i_piece.width(), i_piece.height()), (1, 4));
+    }
+
+    #[test]
+    fn test_rotation_state_cycles_and_drives_block_layout() {
+        let mut piece = Tetromino::new(TetrominoType::T);
+        let expected = [
+            (RotationState::Spawn, vec![(-1, 0), (0, 0), (1, 0), (0, -1)]),
+            (RotationState::Right, vec![(0, -1), (0, 0), (0, 1), (1, 0)]),
+            (RotationState::Two, vec![(-1, 0), (0, 0), (1, 0), (0, 1)]),
+            (RotationState::Left, vec![(0, -1), (0, 0), (0, 1), (-1, 0)]),
+        ];
+        for (state, blocks) in expected {
+            assert_eq!(piece.rotation, state);
+            assert_eq!(piece.blocks, blocks, "{:?} layout", state);
+            piece.rotate_clockwise();
+        }
+        // Four clockwise turns come back around to spawn
+        assert_eq!(piece.rotation, RotationState::Spawn);
+
+        piece.rotate_counterclockwise();
+        assert_eq!(piece.rotation, RotationState::Left);
+    }
+
+    #[test]
+    fn test_rotation_saves_as_its_old_number() {
+        let mut piece = Tetromino::new(TetrominoType::L);
+        piece.rotate_clockwise();
+        let json = serde_json::to_value(&piece).unwrap();
+        assert_eq!(json["rotation"], 1);
+
+        // Saves from before the enum stored the same number
+        let mut old = json.clone();
+        old["rotation"] = serde_json::json!(3);
+        let loaded: Tetromino = serde_json::from_value(old).unwrap();
+        assert_eq!(loaded.rotation, RotationState::Left);
+    }
+
+    #[test]
+    fn test_mirrored_pieces_swap_handedness() {
+        let s_piece = Tetromino::new(TetrominoType::S);
+        let mirrored = s_piece.mirrored();
+        assert_eq!(mirrored.piece_type, TetrominoType::Z);
+        assert_eq!(mirrored.blocks, Tetromino::new(TetrominoType::Z).blocks);
+        
+        let mut j_piece = Tetromino::new(TetrominoType::J);
+        j_piece.rotate_clockwise();
+        let flipped: Vec<(i32, i32)> = j_piece.absolute_blocks().iter().map(|&(x, y)| (-x, y)).collect();
+        let mirrored = j_piece.mirrored();
+        assert_eq!(mirrored.piece_type, TetrominoType::L);
+        let shift = mirrored.occupied_columns().0 - flipped.iter().map(|&(x, _)| x).min().unwrap();
+        let mut expected: Vec<(i32, i32)> = flipped.iter().map(|&(x, y)| (x + shift, y)).collect();
+        let mut actual = mirrored.absolute_blocks();
+        expected.sort();
+        actual.sort();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_symmetric_pieces_are_unchanged_by_mirroring() {
+        for piece_type in [TetrominoType::O, TetrominoType::I, TetrominoType::T] {
+            let piece = Tetromino::new(piece_type);
+            let mirrored = piece.mirrored();
+            assert_eq!(mirrored.piece_type, piece_type);
+            let (mut before, mut after) = (piece.absolute_blocks(), mirrored.absolute_blocks());
+            before.sort();
+            after.sort();
+            assert_eq!(before, after, "{:?} should look the same mirrored", piece_type);
+        }
+    }
+
+    #[test]
+    fn test_centered_preview_offsets_center_the_bounding_box() {
+        // The I-piece is four wide and one tall, so its blocks straddle the middle
+        let mut i_offsets = Tetromino::new(TetrominoType::I).centered_preview_offsets();
+        i_offsets.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(i_offsets, vec![(-1.5, 0.0), (-0.5, 0.0), (0.5, 0.0), (1.5, 0.0)]);
+
+        let mut o_offsets = Tetromino::new(TetrominoType::O).centered_preview_offsets();
+        o_offsets.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(o_offsets, vec![(-0.5, -0.5), (-0.5, 0.5), (0.5, -0.5), (0.5, 0.5)]);
+
+        // Every piece's offsets are balanced about the middle
+        for piece_type in TetrominoType::all() {
+            let offsets = Tetromino::new(piece_type).centered_preview_offsets();
+            let (left, right) = offsets.iter().fold((f32::MAX, f32::MIN), |(lo, hi), (x, _)| (lo.min(*x), hi.max(*x)));
+            let (top, bottom) = offsets.iter().fold((f32::MAX, f32::MIN), |(lo, hi), (_, y)| (lo.min(*y), hi.max(*y)));
+            assert_eq!(left + right, 0.0, "{:?}", piece_type);
+            assert_eq!(top + bottom, 0.0, "{:?}", piece_type);
+        }
+    }
+}